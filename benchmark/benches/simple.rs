@@ -1,9 +1,12 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use graphql_benchmark::{parse, run, serialize};
-use simple::{Q, S};
+use simple::{Q, S, S_ONLY_PARSE};
 
 pub fn bench(c: &mut Criterion) {
     c.bench_function("simple run", |b| b.iter(|| run(&S, black_box(Q))));
+    c.bench_function("simple run (only parse)", |b| {
+        b.iter(|| run(&S_ONLY_PARSE, black_box(Q)))
+    });
     c.bench_function("simple parse", |b| b.iter(|| parse(black_box(Q))));
     let res = run(&S, Q);
     c.bench_function("simple serialize", |b| {
@@ -37,6 +37,9 @@ impl MyObj {
 
 lazy_static::lazy_static! {
     pub static ref S: Schema<QueryRoot, EmptyMutation, EmptySubscription> = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    pub static ref S_ONLY_PARSE: Schema<QueryRoot, EmptyMutation, EmptySubscription> = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .validation_mode(ValidationMode::OnlyParse)
+        .finish();
     // static ref D: Document = parse_query(Q).unwrap();
 }
 
@@ -1,6 +1,6 @@
 use crate::utils::{
-    get_rustdoc, parse_default, parse_default_with, parse_guards, parse_post_guards,
-    parse_validator,
+    chain_guards, chain_post_guards, get_rustdoc, parse_default, parse_default_with,
+    parse_duration, parse_guards, parse_post_guards, parse_validator,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -9,6 +9,7 @@ use syn::{Attribute, AttributeArgs, Error, Lit, Meta, MetaList, NestedMeta, Resu
 pub struct CacheControl {
     pub public: bool,
     pub max_age: usize,
+    pub inherit_max_age: bool,
 }
 
 impl Default for CacheControl {
@@ -16,6 +17,29 @@ impl Default for CacheControl {
         Self {
             public: true,
             max_age: 0,
+            inherit_max_age: false,
+        }
+    }
+}
+
+pub enum Deprecation {
+    NoDeprecated,
+    Deprecated { reason: Option<String> },
+}
+
+impl Deprecation {
+    pub fn value(&self, crate_name: &TokenStream) -> TokenStream {
+        match self {
+            Deprecation::NoDeprecated => {
+                quote! { #crate_name::registry::Deprecation::NoDeprecated }
+            }
+            Deprecation::Deprecated { reason } => {
+                let reason = reason
+                    .as_ref()
+                    .map(|s| quote! { Some(#s) })
+                    .unwrap_or_else(|| quote! { None });
+                quote! { #crate_name::registry::Deprecation::Deprecated { reason: #reason } }
+            }
         }
     }
 }
@@ -25,6 +49,7 @@ impl CacheControl {
         let mut cache_control = Self {
             public: true,
             max_age: 0,
+            inherit_max_age: false,
         };
 
         for meta in &ls.nested {
@@ -51,6 +76,8 @@ impl CacheControl {
                         cache_control.public = true;
                     } else if p.is_ident("private") {
                         cache_control.public = false;
+                    } else if p.is_ident("inherit_max_age") {
+                        cache_control.inherit_max_age = true;
                     }
                 }
                 _ => {}
@@ -129,8 +156,11 @@ pub struct Argument {
     pub name: Option<String>,
     pub desc: Option<String>,
     pub default: Option<TokenStream>,
+    pub default_ctx: Option<TokenStream>,
     pub validator: TokenStream,
     pub key: bool, // for entity
+    pub flatten: bool,
+    pub secret: bool,
 }
 
 impl Argument {
@@ -138,8 +168,11 @@ impl Argument {
         let mut name = None;
         let mut desc = None;
         let mut default = None;
+        let mut default_ctx = None;
         let mut validator = quote! { None };
         let mut key = false;
+        let mut flatten = false;
+        let mut secret = false;
 
         for attr in attrs {
             match attr.parse_meta()? {
@@ -150,6 +183,10 @@ impl Argument {
                                 default = Some(quote! { Default::default() });
                             } else if p.is_ident("key") {
                                 key = true;
+                            } else if p.is_ident("flatten") {
+                                flatten = true;
+                            } else if p.is_ident("secret") {
+                                secret = true;
                             }
                         } else if let NestedMeta::Meta(Meta::NameValue(nv)) = meta {
                             if nv.path.is_ident("name") {
@@ -174,10 +211,28 @@ impl Argument {
                                 default = Some(parse_default(&nv.lit)?);
                             } else if nv.path.is_ident("default_with") {
                                 default = Some(parse_default_with(&nv.lit)?);
+                            } else if nv.path.is_ident("default_ctx") {
+                                default_ctx = Some(parse_default_with(&nv.lit)?);
                             }
                         }
                     }
 
+                    if default.is_some() && default_ctx.is_some() {
+                        return Err(Error::new_spanned(
+                            &ls,
+                            "Attribute 'default_ctx' cannot be used together with 'default' or 'default_with'.",
+                        ));
+                    }
+
+                    if flatten
+                        && (name.is_some() || default.is_some() || default_ctx.is_some() || secret)
+                    {
+                        return Err(Error::new_spanned(
+                            &ls,
+                            "Attribute 'flatten' cannot be used together with 'name', 'default', 'default_with', 'default_ctx' or 'secret'.",
+                        ));
+                    }
+
                     validator = parse_validator(crate_name, &ls)?;
                 }
                 _ => {}
@@ -188,8 +243,11 @@ impl Argument {
             name,
             desc,
             default,
+            default_ctx,
             validator,
             key,
+            flatten,
+            secret,
         })
     }
 }
@@ -197,8 +255,8 @@ impl Argument {
 pub struct Field {
     pub name: Option<String>,
     pub desc: Option<String>,
-    pub deprecation: Option<String>,
-    pub cache_control: CacheControl,
+    pub deprecation: Deprecation,
+    pub cache_control: Option<CacheControl>,
     pub external: bool,
     pub provides: Option<String>,
     pub requires: Option<String>,
@@ -206,14 +264,18 @@ pub struct Field {
     pub guard: Option<TokenStream>,
     pub post_guard: Option<TokenStream>,
     pub features: Vec<String>,
+    pub filter: Option<syn::Path>,
+    pub timeout: Option<TokenStream>,
+    pub flatten: bool,
+    pub complexity: usize,
 }
 
 impl Field {
     pub fn parse(crate_name: &TokenStream, attrs: &[Attribute]) -> Result<Option<Self>> {
         let mut name = None;
         let mut desc = None;
-        let mut deprecation = None;
-        let mut cache_control = CacheControl::default();
+        let mut deprecation = Deprecation::NoDeprecated;
+        let mut cache_control = None;
         let mut external = false;
         let mut provides = None;
         let mut requires = None;
@@ -221,12 +283,20 @@ impl Field {
         let mut owned = false;
         let mut guard = None;
         let mut post_guard = None;
+        let mut filter = None;
+        let mut timeout = None;
+        let mut flatten = false;
+        let mut complexity = 1;
 
         for attr in attrs {
             match attr.parse_meta()? {
                 Meta::List(ls) if ls.path.is_ident("field") => {
-                    guard = parse_guards(crate_name, &ls)?;
-                    post_guard = parse_post_guards(crate_name, &ls)?;
+                    if let Some(guards) = parse_guards(crate_name, &ls)? {
+                        guard = Some(chain_guards(crate_name, guard, guards));
+                    }
+                    if let Some(guards) = parse_post_guards(crate_name, &ls)? {
+                        post_guard = Some(chain_post_guards(crate_name, post_guard, guards));
+                    }
                     for meta in &ls.nested {
                         match meta {
                             NestedMeta::Meta(Meta::Path(p)) if p.is_ident("skip") => {
@@ -238,6 +308,12 @@ impl Field {
                             NestedMeta::Meta(Meta::Path(p)) if p.is_ident("owned") => {
                                 owned = true;
                             }
+                            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("flatten") => {
+                                flatten = true;
+                            }
+                            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("deprecation") => {
+                                deprecation = Deprecation::Deprecated { reason: None };
+                            }
                             NestedMeta::Meta(Meta::Path(p)) if p.is_ident("ref") => {
                                 return Err(Error::new_spanned(
                                     &p,
@@ -265,7 +341,9 @@ impl Field {
                                     }
                                 } else if nv.path.is_ident("deprecation") {
                                     if let syn::Lit::Str(lit) = &nv.lit {
-                                        deprecation = Some(lit.value());
+                                        deprecation = Deprecation::Deprecated {
+                                            reason: Some(lit.value()),
+                                        };
                                     } else {
                                         return Err(Error::new_spanned(
                                             &nv.lit,
@@ -304,17 +382,65 @@ impl Field {
                                             "Attribute 'feature' should be a string.",
                                         ));
                                     }
+                                } else if nv.path.is_ident("filter") {
+                                    if let syn::Lit::Str(lit) = &nv.lit {
+                                        filter = Some(syn::parse_str::<syn::Path>(&lit.value())?);
+                                    } else {
+                                        return Err(Error::new_spanned(
+                                            &nv.lit,
+                                            "Attribute 'filter' should be a string.",
+                                        ));
+                                    }
+                                } else if nv.path.is_ident("timeout") {
+                                    if let syn::Lit::Str(lit) = &nv.lit {
+                                        timeout = Some(parse_duration(lit)?);
+                                    } else {
+                                        return Err(Error::new_spanned(
+                                            &nv.lit,
+                                            "Attribute 'timeout' should be a string.",
+                                        ));
+                                    }
+                                } else if nv.path.is_ident("complexity") {
+                                    if let syn::Lit::Int(n) = &nv.lit {
+                                        complexity = n.base10_parse::<usize>()?;
+                                    } else {
+                                        return Err(Error::new_spanned(
+                                            &nv.lit,
+                                            "Attribute 'complexity' should be an integer.",
+                                        ));
+                                    }
                                 }
                             }
                             NestedMeta::Meta(Meta::List(ls)) => {
                                 if ls.path.is_ident("cache_control") {
-                                    cache_control = CacheControl::parse(ls)?;
+                                    if cache_control.is_some() {
+                                        return Err(Error::new_spanned(
+                                            ls,
+                                            "Attribute 'cache_control' is defined more than once.",
+                                        ));
+                                    }
+                                    cache_control = Some(CacheControl::parse(ls)?);
                                 }
                             }
                             _ => {}
                         }
                     }
                 }
+                Meta::List(ls) if ls.path.is_ident("graphql") => {
+                    for meta in &ls.nested {
+                        match meta {
+                            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("skip") => {
+                                return Ok(None);
+                            }
+                            _ => {
+                                return Err(Error::new_spanned(
+                                    meta,
+                                    "Only `skip` is supported by the `graphql` attribute of a field, use the `field` attribute instead.",
+                                ));
+                            }
+                        }
+                    }
+                }
                 _ => {}
             }
         }
@@ -335,6 +461,10 @@ impl Field {
             guard,
             post_guard,
             features,
+            filter,
+            timeout,
+            flatten,
+            complexity,
         }))
     }
 }
@@ -343,6 +473,8 @@ pub struct Enum {
     pub internal: bool,
     pub name: Option<String>,
     pub desc: Option<String>,
+    pub as_int: bool,
+    pub case_insensitive: bool,
 }
 
 impl Enum {
@@ -350,12 +482,20 @@ impl Enum {
         let mut internal = false;
         let mut name = None;
         let mut desc = None;
+        let mut as_int = false;
+        let mut case_insensitive = false;
 
         for arg in args {
             match arg {
                 NestedMeta::Meta(Meta::Path(p)) if p.is_ident("internal") => {
                     internal = true;
                 }
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("as_int") => {
+                    as_int = true;
+                }
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("case_insensitive") => {
+                    case_insensitive = true;
+                }
                 NestedMeta::Meta(Meta::NameValue(nv)) => {
                     if nv.path.is_ident("name") {
                         if let syn::Lit::Str(lit) = nv.lit {
@@ -385,6 +525,8 @@ impl Enum {
             internal,
             name,
             desc,
+            as_int,
+            case_insensitive,
         })
     }
 }
@@ -392,19 +534,24 @@ impl Enum {
 pub struct EnumItem {
     pub name: Option<String>,
     pub desc: Option<String>,
-    pub deprecation: Option<String>,
+    pub deprecation: Deprecation,
 }
 
 impl EnumItem {
     pub fn parse(attrs: &[Attribute]) -> Result<Self> {
         let mut name = None;
         let mut desc = None;
-        let mut deprecation = None;
+        let mut deprecation = Deprecation::NoDeprecated;
 
         for attr in attrs {
             if attr.path.is_ident("item") {
                 if let Meta::List(args) = attr.parse_meta()? {
                     for meta in args.nested {
+                        if let NestedMeta::Meta(Meta::Path(p)) = &meta {
+                            if p.is_ident("deprecation") {
+                                deprecation = Deprecation::Deprecated { reason: None };
+                            }
+                        }
                         if let NestedMeta::Meta(Meta::NameValue(nv)) = meta {
                             if nv.path.is_ident("name") {
                                 if let syn::Lit::Str(lit) = nv.lit {
@@ -426,7 +573,9 @@ impl EnumItem {
                                 }
                             } else if nv.path.is_ident("deprecation") {
                                 if let syn::Lit::Str(lit) = nv.lit {
-                                    deprecation = Some(lit.value());
+                                    deprecation = Deprecation::Deprecated {
+                                        reason: Some(lit.value()),
+                                    };
                                 } else {
                                     return Err(Error::new_spanned(
                                         &nv.lit,
@@ -452,6 +601,39 @@ impl EnumItem {
     }
 }
 
+pub struct UnionItem {
+    pub typename: Option<String>,
+}
+
+impl UnionItem {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut typename = None;
+
+        for attr in attrs {
+            if attr.path.is_ident("item") {
+                if let Meta::List(args) = attr.parse_meta()? {
+                    for meta in args.nested {
+                        if let NestedMeta::Meta(Meta::NameValue(nv)) = meta {
+                            if nv.path.is_ident("typename") {
+                                if let syn::Lit::Str(lit) = nv.lit {
+                                    typename = Some(lit.value());
+                                } else {
+                                    return Err(Error::new_spanned(
+                                        &nv.lit,
+                                        "Attribute 'typename' should be a string.",
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Self { typename })
+    }
+}
+
 pub struct InputField {
     pub name: Option<String>,
     pub desc: Option<String>,
@@ -537,6 +719,8 @@ pub struct InputObject {
     pub internal: bool,
     pub name: Option<String>,
     pub desc: Option<String>,
+    pub one_of: bool,
+    pub deny_unknown_fields: bool,
 }
 
 impl InputObject {
@@ -544,12 +728,17 @@ impl InputObject {
         let mut internal = false;
         let mut name = None;
         let mut desc = None;
+        let mut one_of = false;
+        let mut deny_unknown_fields = true;
 
         for arg in args {
             match arg {
                 NestedMeta::Meta(Meta::Path(p)) if p.is_ident("internal") => {
                     internal = true;
                 }
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("one_of") => {
+                    one_of = true;
+                }
                 NestedMeta::Meta(Meta::NameValue(nv)) => {
                     if nv.path.is_ident("name") {
                         if let syn::Lit::Str(lit) = nv.lit {
@@ -569,6 +758,15 @@ impl InputObject {
                                 "Attribute 'desc' should be a string.",
                             ));
                         }
+                    } else if nv.path.is_ident("deny_unknown_fields") {
+                        if let syn::Lit::Bool(lit) = nv.lit {
+                            deny_unknown_fields = lit.value;
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'deny_unknown_fields' should be a boolean.",
+                            ));
+                        }
                     }
                 }
                 _ => {}
@@ -579,6 +777,8 @@ impl InputObject {
             internal,
             name,
             desc,
+            one_of,
+            deny_unknown_fields,
         })
     }
 }
@@ -588,6 +788,7 @@ pub struct InterfaceFieldArgument {
     pub desc: Option<String>,
     pub ty: Type,
     pub default: Option<TokenStream>,
+    pub secret: bool,
 }
 
 impl InterfaceFieldArgument {
@@ -596,11 +797,14 @@ impl InterfaceFieldArgument {
         let mut desc = None;
         let mut ty = None;
         let mut default = None;
+        let mut secret = false;
 
         for meta in &ls.nested {
             if let NestedMeta::Meta(Meta::Path(p)) = meta {
                 if p.is_ident("default") {
                     default = Some(quote! { Default::default() });
+                } else if p.is_ident("secret") {
+                    secret = true;
                 }
             } else if let NestedMeta::Meta(Meta::NameValue(nv)) = meta {
                 if nv.path.is_ident("name") {
@@ -655,6 +859,7 @@ impl InterfaceFieldArgument {
             desc,
             ty: ty.unwrap(),
             default,
+            secret,
         })
     }
 }
@@ -665,10 +870,11 @@ pub struct InterfaceField {
     pub desc: Option<String>,
     pub ty: Type,
     pub args: Vec<InterfaceFieldArgument>,
-    pub deprecation: Option<String>,
+    pub deprecation: Deprecation,
     pub external: bool,
     pub provides: Option<String>,
     pub requires: Option<String>,
+    pub complexity: usize,
 }
 
 impl InterfaceField {
@@ -678,16 +884,20 @@ impl InterfaceField {
         let mut desc = None;
         let mut ty = None;
         let mut args = Vec::new();
-        let mut deprecation = None;
+        let mut deprecation = Deprecation::NoDeprecated;
         let mut external = false;
         let mut provides = None;
         let mut requires = None;
+        let mut complexity = 1;
 
         for meta in &ls.nested {
             match meta {
                 NestedMeta::Meta(Meta::Path(p)) if p.is_ident("external") => {
                     external = true;
                 }
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("deprecation") => {
+                    deprecation = Deprecation::Deprecated { reason: None };
+                }
                 NestedMeta::Meta(Meta::NameValue(nv)) => {
                     if nv.path.is_ident("name") {
                         if let syn::Lit::Str(lit) = &nv.lit {
@@ -731,7 +941,9 @@ impl InterfaceField {
                         }
                     } else if nv.path.is_ident("deprecation") {
                         if let syn::Lit::Str(lit) = &nv.lit {
-                            deprecation = Some(lit.value());
+                            deprecation = Deprecation::Deprecated {
+                                reason: Some(lit.value()),
+                            };
                         } else {
                             return Err(Error::new_spanned(
                                 &nv.lit,
@@ -756,6 +968,15 @@ impl InterfaceField {
                                 "Attribute 'requires' should be a string.",
                             ));
                         }
+                    } else if nv.path.is_ident("complexity") {
+                        if let syn::Lit::Int(n) = &nv.lit {
+                            complexity = n.base10_parse::<usize>()?;
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'complexity' should be an integer.",
+                            ));
+                        }
                     }
                 }
                 NestedMeta::Meta(Meta::List(ls)) if ls.path.is_ident("arg") => {
@@ -783,6 +1004,7 @@ impl InterfaceField {
             external,
             requires,
             provides,
+            complexity,
         })
     }
 }
@@ -901,6 +1123,70 @@ impl Scalar {
     }
 }
 
+pub struct NewType {
+    pub internal: bool,
+    pub name: Option<String>,
+    pub desc: Option<String>,
+    pub validate: Option<syn::Path>,
+}
+
+impl NewType {
+    pub fn parse(args: AttributeArgs) -> Result<Self> {
+        let mut internal = false;
+        let mut name = None;
+        let mut desc = None;
+        let mut validate = None;
+
+        for arg in args {
+            match arg {
+                NestedMeta::Meta(Meta::Path(p)) => {
+                    if p.is_ident("internal") {
+                        internal = true;
+                    }
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) => {
+                    if nv.path.is_ident("name") {
+                        if let syn::Lit::Str(lit) = nv.lit {
+                            name = Some(lit.value());
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'name' should be a string.",
+                            ));
+                        }
+                    } else if nv.path.is_ident("desc") {
+                        if let syn::Lit::Str(lit) = nv.lit {
+                            desc = Some(lit.value());
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'desc' should be a string.",
+                            ));
+                        }
+                    } else if nv.path.is_ident("validate") {
+                        if let syn::Lit::Str(lit) = nv.lit {
+                            validate = Some(syn::parse_str::<syn::Path>(&lit.value())?);
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'validate' should be a string.",
+                            ));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            internal,
+            name,
+            desc,
+            validate,
+        })
+    }
+}
+
 pub struct Entity {}
 
 impl Entity {
@@ -26,6 +26,7 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
     let mut enum_items = Vec::new();
     let mut items = Vec::new();
     let mut schema_enum_items = Vec::new();
+    let mut int_items = Vec::new();
 
     for variant in &e.variants {
         if !variant.fields.is_empty() {
@@ -49,11 +50,7 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
             .name
             .take()
             .unwrap_or_else(|| variant.ident.unraw().to_string().to_screaming_snake_case());
-        let item_deprecation = item_args
-            .deprecation
-            .as_ref()
-            .map(|s| quote! { Some(#s) })
-            .unwrap_or_else(|| quote! {None});
+        let item_deprecation = item_args.deprecation.value(&crate_name);
         let item_desc = item_args
             .desc
             .as_ref()
@@ -66,15 +63,69 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
                 value: #ident::#item_ident,
             }
         });
+        let item_int_value = if enum_args.as_int {
+            quote! { Some(#ident::#item_ident as i64) }
+        } else {
+            quote! { None }
+        };
         schema_enum_items.push(quote! {
             enum_items.insert(#gql_item_name, #crate_name::registry::MetaEnumValue {
                 name: #gql_item_name,
                 description: #item_desc,
                 deprecation: #item_deprecation,
+                int_value: #item_int_value,
             });
         });
+        int_items.push(quote! {
+            if n == #ident::#item_ident as i64 {
+                return Ok(#ident::#item_ident);
+            }
+        });
     }
 
+    let case_insensitive = enum_args.case_insensitive;
+    let parse_enum = if case_insensitive {
+        quote! { #crate_name::resolver_utils::parse_enum_case_insensitive }
+    } else {
+        quote! { #crate_name::resolver_utils::parse_enum }
+    };
+
+    let (parse, to_value, resolve) = if enum_args.as_int {
+        (
+            quote! {
+                let value = value.unwrap_or_default();
+                if let #crate_name::Value::Number(n) = &value {
+                    if let Some(n) = n.as_i64() {
+                        #(#int_items)*
+                    }
+                    return Err(#crate_name::InputValueError::InvalidEnumValue {
+                        ty: #gql_typename.to_string(),
+                        value: n.to_string(),
+                    });
+                }
+                #parse_enum(value)
+            },
+            quote! {
+                #crate_name::Value::Number((*self as i64).into())
+            },
+            quote! {
+                Ok((*self as i64).into())
+            },
+        )
+    } else {
+        (
+            quote! {
+                #parse_enum(value.unwrap_or_default())
+            },
+            quote! {
+                #crate_name::resolver_utils::enum_value(*self)
+            },
+            quote! {
+                Ok(#crate_name::resolver_utils::enum_value(*self).into_json().unwrap())
+            },
+        )
+    };
+
     let expanded = quote! {
         #[allow(clippy::all, clippy::pedantic)]
         impl #crate_name::resolver_utils::EnumType for #ident {
@@ -99,6 +150,7 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
                             #(#schema_enum_items)*
                             enum_items
                         },
+                        case_insensitive: #case_insensitive,
                     }
                 })
             }
@@ -107,18 +159,18 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
         #[allow(clippy::all, clippy::pedantic)]
         impl #crate_name::InputValueType for #ident {
             fn parse(value: Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> {
-                #crate_name::resolver_utils::parse_enum(value.unwrap_or_default())
+                #parse
             }
 
             fn to_value(&self) -> #crate_name::Value {
-                #crate_name::resolver_utils::enum_value(*self)
+                #to_value
             }
         }
 
         #[#crate_name::async_trait::async_trait]
         impl #crate_name::OutputValueType for #ident {
             async fn resolve(&self, _: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::types::Field>) -> #crate_name::Result<#crate_name::serde_json::Value> {
-                Ok(#crate_name::resolver_utils::enum_value(*self).into_json().unwrap())
+                #resolve
             }
         }
     };
@@ -4,7 +4,7 @@ use inflector::Inflector;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::ext::IdentExt;
-use syn::{Data, DeriveInput, Error, Result};
+use syn::{Data, DeriveInput, Error, Result, Type};
 
 pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<TokenStream> {
     let crate_name = get_crate_name(object_args.internal);
@@ -47,6 +47,7 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
     let mut fields = Vec::new();
     let mut schema_fields = Vec::new();
     let mut flatten_fields = Vec::new();
+    let mut field_names = Vec::new();
 
     for field in &s.fields {
         let field_args = args::InputField::parse(&crate_name, &field.attrs)?;
@@ -57,6 +58,13 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
             .unwrap_or_else(|| ident.unraw().to_string().to_camel_case());
 
         if field_args.flatten {
+            if object_args.one_of {
+                return Err(Error::new_spanned(
+                    field,
+                    "Fields of a oneOf input object can not be flattened.",
+                ));
+            }
+
             flatten_fields.push((ident, ty));
 
             schema_fields.push(quote! {
@@ -81,6 +89,24 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
             continue;
         }
 
+        if object_args.one_of {
+            let is_option = match ty {
+                Type::Path(p) => p
+                    .path
+                    .segments
+                    .last()
+                    .map_or(false, |segment| segment.ident == "Option"),
+                _ => false,
+            };
+            if !is_option || field_args.default.is_some() {
+                return Err(Error::new_spanned(
+                    field,
+                    "All fields of a oneOf input object must be `Option` without a default value.",
+                ));
+            }
+        }
+        field_names.push(name.clone());
+
         let validator = &field_args.validator;
         let desc = field_args
             .desc
@@ -110,12 +136,24 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
             });
         }
 
-        put_fields.push(quote! {
-            map.insert(
-                #crate_name::parser::types::Name::new_unchecked(#name.to_owned()),
-                #crate_name::InputValueType::to_value(&self.#ident)
-            );
-        });
+        if object_args.one_of {
+            put_fields.push(quote! {
+                let value = #crate_name::InputValueType::to_value(&self.#ident);
+                if value != #crate_name::Value::Null {
+                    map.insert(
+                        #crate_name::parser::types::Name::new_unchecked(#name.to_owned()),
+                        value
+                    );
+                }
+            });
+        } else {
+            put_fields.push(quote! {
+                map.insert(
+                    #crate_name::parser::types::Name::new_unchecked(#name.to_owned()),
+                    #crate_name::InputValueType::to_value(&self.#ident)
+                );
+            });
+        }
 
         fields.push(ident);
         schema_fields.push(quote! {
@@ -125,10 +163,36 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
                 ty: <#ty as #crate_name::Type>::create_type_info(registry),
                 default_value: #schema_default,
                 validator: #validator,
+                is_secret: false,
             });
         })
     }
 
+    let one_of = object_args.one_of;
+    let deny_unknown_fields = object_args.deny_unknown_fields;
+    let check_one_of = if one_of {
+        Some(quote! {
+            let provided = obj
+                .iter()
+                .filter(|(name, _)| [#(#field_names),*].contains(&name.as_str()))
+                .collect::<Vec<_>>();
+            if provided.len() != 1 {
+                return Err(#crate_name::InputValueError::Custom(format!(
+                    "Exactly one field of oneOf input object \"{}\" must be provided, but {} were provided",
+                    #gql_typename, provided.len()
+                )));
+            }
+            if *provided[0].1 == #crate_name::Value::Null {
+                return Err(#crate_name::InputValueError::Custom(format!(
+                    "Field \"{}\" of oneOf input object \"{}\" must not be null",
+                    provided[0].0, #gql_typename
+                )));
+            }
+        })
+    } else {
+        None
+    };
+
     let expanded = quote! {
         #[allow(clippy::all, clippy::pedantic)]
         impl #crate_name::Type for #ident {
@@ -144,7 +208,9 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
                         let mut fields = #crate_name::indexmap::IndexMap::new();
                         #(#schema_fields)*
                         fields
-                    }
+                    },
+                    one_of: #one_of,
+                    deny_unknown_fields: #deny_unknown_fields,
                 })
             }
         }
@@ -153,6 +219,7 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
         impl #crate_name::InputValueType for #ident {
             fn parse(value: Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> {
                 if let Some(#crate_name::Value::Object(obj)) = value {
+                    #check_one_of
                     #(#get_fields)*
                     Ok(Self { #(#fields),* })
                 } else {
@@ -38,6 +38,14 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
         .map(|s| quote! { Some(#s) })
         .unwrap_or_else(|| quote! {None});
 
+    // An interface without variants only has implementors wrapped in `DynInterface`, and an
+    // empty match is only exhaustive on the uninhabited enum itself, not on a reference to it.
+    let (match_self, allow_unused) = if s.variants.is_empty() {
+        (quote! { *self }, quote! { #[allow(unused_variables)] })
+    } else {
+        (quote! { self }, quote! {})
+    };
+
     let mut registry_types = Vec::new();
     let mut possible_types = Vec::new();
     let mut get_introspection_typename = Vec::new();
@@ -120,6 +128,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
         external,
         provides,
         requires,
+        complexity,
     } in &interface_args.fields
     {
         let (name, method_name) = if let Some(method) = method {
@@ -150,6 +159,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
             desc,
             ty,
             default,
+            secret,
         } in args
         {
             let ident = Ident::new(name, Span::call_site());
@@ -161,8 +171,16 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
                 Some(default) => quote! { Some(|| -> #ty { #default }) },
                 None => quote! { None },
             };
+            let param_value = if *secret {
+                quote! {
+                    ctx.param_value(#name, #get_default)
+                        .map_err(#crate_name::Error::redact_secrets)
+                }
+            } else {
+                quote! { ctx.param_value(#name, #get_default) }
+            };
             get_params.push(quote! {
-                let #ident: #ty = ctx.param_value(#name, #get_default)?;
+                let #ident: #ty = #param_value?;
             });
 
             let desc = desc
@@ -182,6 +200,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
                     ty: <#ty as #crate_name::Type>::create_type_info(registry),
                     default_value: #schema_default,
                     validator: None,
+                    is_secret: #secret,
                 });
             });
         }
@@ -196,10 +215,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
             .as_ref()
             .map(|s| quote! {Some(#s)})
             .unwrap_or_else(|| quote! {None});
-        let deprecation = deprecation
-            .as_ref()
-            .map(|s| quote! {Some(#s)})
-            .unwrap_or_else(|| quote! {None});
+        let deprecation = deprecation.value(&crate_name);
 
         let oty = OutputType::parse(ty)?;
         let ty = match oty {
@@ -211,7 +227,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
         methods.push(quote! {
             #[inline]
             async fn #method_name <'ctx>(&self, #(#decl_params),*) -> #crate_name::FieldResult<#ty> {
-                match self {
+                match #match_self {
                     #(#calls,)*
                 }
             }
@@ -232,6 +248,8 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
                 external: #external,
                 provides: #provides,
                 requires: #requires,
+                inherit_max_age: false,
+                complexity: #complexity,
             });
         });
 
@@ -263,6 +281,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
         #(#type_into_impls)*
 
         #[allow(clippy::all, clippy::pedantic)]
+        #allow_unused
         impl #generics #ident #generics {
             #(#methods)*
         }
@@ -302,6 +321,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
         }
 
         #[allow(clippy::all, clippy::pedantic)]
+        #allow_unused
         #[#crate_name::async_trait::async_trait]
         impl #generics #crate_name::resolver_utils::ObjectType for #ident #generics {
             async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#crate_name::serde_json::Value> {
@@ -313,7 +333,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
             }
 
             fn collect_all_fields<'a>(&'a self, ctx: &#crate_name::ContextSelectionSet<'a>, fields: &mut #crate_name::resolver_utils::Fields<'a>) -> #crate_name::Result<()> {
-                match self {
+                match #match_self {
                     #(#collect_all_fields),*
                 }
             }
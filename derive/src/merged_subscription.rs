@@ -86,7 +86,7 @@ pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<Token
             fn create_field_stream<'a>(
                 &'a self,
                 ctx: &'a #crate_name::Context<'a>
-            ) -> ::std::pin::Pin<::std::boxed::Box<dyn #crate_name::futures::Stream<Item = #crate_name::Result<#crate_name::serde_json::Value>> + Send + 'a>> {
+            ) -> ::std::pin::Pin<::std::boxed::Box<dyn #crate_name::futures::Stream<Item = #crate_name::Result<#crate_name::Response>> + Send + 'a>> {
                 ::std::boxed::Box::pin(#crate_name::async_stream::stream! {
                     let obj = #create_merged_obj;
                     let mut stream = obj.create_field_stream(ctx);
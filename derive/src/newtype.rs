@@ -0,0 +1,73 @@
+use crate::args;
+use crate::utils::{get_crate_name, get_rustdoc};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Result};
+
+pub fn generate(newtype_args: &args::NewType, input: &DeriveInput) -> Result<TokenStream> {
+    let crate_name = get_crate_name(newtype_args.internal);
+    let ident = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "GQLNewType does not support generic types.",
+        ));
+    }
+    let inner_ty = match &input.data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            _ => {
+                return Err(Error::new_spanned(
+                    ident,
+                    "GQLNewType can only be applied to a tuple struct with exactly one field.",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                ident,
+                "GQLNewType can only be applied to a tuple struct with exactly one field.",
+            ))
+        }
+    };
+    let gql_typename = newtype_args
+        .name
+        .clone()
+        .unwrap_or_else(|| ident.to_string());
+    let desc = newtype_args
+        .desc
+        .clone()
+        .or_else(|| get_rustdoc(&input.attrs).ok().flatten())
+        .map(|desc| quote! { desc = #desc, })
+        .unwrap_or_default();
+    let internal = if newtype_args.internal {
+        quote! { internal, }
+    } else {
+        quote! {}
+    };
+    let validate = newtype_args
+        .validate
+        .as_ref()
+        .map(|validate| quote! { #validate(&value)?; });
+
+    let expanded = quote! {
+        #[allow(clippy::all, clippy::pedantic)]
+        #[#crate_name::GQLScalar(#internal name = #gql_typename, #desc)]
+        impl #crate_name::ScalarType for #ident {
+            fn parse(value: #crate_name::Value) -> #crate_name::InputValueResult<Self> {
+                let value = <#inner_ty as #crate_name::ScalarType>::parse(value)?;
+                #validate
+                Ok(#ident(value))
+            }
+
+            fn is_valid(value: &#crate_name::Value) -> bool {
+                <#inner_ty as #crate_name::ScalarType>::is_valid(value)
+            }
+
+            fn to_value(&self) -> #crate_name::Value {
+                <#inner_ty as #crate_name::ScalarType>::to_value(&self.0)
+            }
+        }
+    };
+    Ok(expanded.into())
+}
@@ -37,7 +37,9 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
         .unwrap_or_else(|| quote! {None});
 
     let mut resolvers = Vec::new();
+    let mut flatten_resolvers = Vec::new();
     let mut schema_fields = Vec::new();
+    let mut flatten_schema_fields = Vec::new();
     let mut find_entities = Vec::new();
     let mut add_keys = Vec::new();
     let mut create_entity_types = Vec::new();
@@ -76,11 +78,14 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
 
                         match (&*pat.pat, &*pat.ty) {
                             (Pat::Ident(arg_ident), Type::Path(arg_ty)) => {
-                                args.push((
-                                    arg_ident.clone(),
-                                    arg_ty.clone(),
-                                    args::Argument::parse(&crate_name, &pat.attrs)?,
-                                ));
+                                let arg = args::Argument::parse(&crate_name, &pat.attrs)?;
+                                if arg.flatten {
+                                    return Err(Error::new_spanned(
+                                        pat,
+                                        "Entity functions cannot have flattened arguments.",
+                                    ));
+                                }
+                                args.push((arg_ident.clone(), arg_ty.clone(), arg));
                                 pat.attrs.clear();
                             }
                             (arg, Type::Reference(TypeReference { elem, .. })) => {
@@ -177,7 +182,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                     )
                     .expect("invalid result type");
                 }
-                let do_find = quote! { self.#field_ident(ctx, #(#use_keys),*).await.map_err(|err| err.into_error(ctx.item.pos))? };
+                let do_find = quote! { self.#field_ident(ctx, #(#use_keys),*).await.map_err(|err| err.into_error_with_path(ctx.item.pos, ctx.path_node.as_ref()))? };
 
                 find_entities.push((
                     args.len(),
@@ -215,11 +220,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                     .as_ref()
                     .map(|s| quote! {Some(#s)})
                     .unwrap_or_else(|| quote! {None});
-                let field_deprecation = field
-                    .deprecation
-                    .as_ref()
-                    .map(|s| quote! {Some(#s)})
-                    .unwrap_or_else(|| quote! {None});
+                let field_deprecation = field.deprecation.value(&crate_name);
                 let external = field.external;
                 let features = field.features;
                 let requires = match &field.requires {
@@ -236,16 +237,25 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         return Err(Error::new_spanned(&method.sig.output, "Missing type"))
                     }
                 };
-                let cache_control = {
-                    let public = field.cache_control.public;
-                    let max_age = field.cache_control.max_age;
-                    quote! {
-                        #crate_name::CacheControl {
-                            public: #public,
-                            max_age: #max_age,
+                let cache_control = match &field.cache_control {
+                    Some(cache_control) => {
+                        let public = cache_control.public;
+                        let max_age = cache_control.max_age;
+                        quote! {
+                            Some(#crate_name::CacheControl {
+                                public: #public,
+                                max_age: #max_age,
+                                private_key: None,
+                            })
                         }
                     }
+                    None => quote! { None },
                 };
+                let inherit_max_age = field
+                    .cache_control
+                    .as_ref()
+                    .map_or(false, |cache_control| cache_control.inherit_max_age);
+                let complexity = field.complexity;
 
                 let mut create_ctx = true;
                 let mut args = Vec::new();
@@ -311,11 +321,46 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         name,
                         desc,
                         default,
+                        default_ctx,
                         validator,
+                        flatten,
+                        secret,
                         ..
                     },
                 ) in args
                 {
+                    if flatten {
+                        // The fields of a flattened input object are the arguments of the field.
+                        // The input object is removed when the schema is built, unless it is also
+                        // used as the type of an input value.
+                        schema_args.push(quote! {
+                            <#ty as #crate_name::Type>::create_type_info(registry);
+                            let type_name = <#ty as #crate_name::Type>::type_name();
+                            registry.flattened_input_objects.insert(type_name.to_string());
+                            if let Some(#crate_name::registry::MetaType::InputObject { input_fields, .. }) =
+                                registry.types.get(&*type_name) {
+                                for value in input_fields.values() {
+                                    if args.insert(value.name, value.clone()).is_some() {
+                                        panic!(
+                                            "Argument \"{}\" of field \"{}\" is defined more than once.",
+                                            value.name, #field_name
+                                        );
+                                    }
+                                }
+                            }
+                        });
+
+                        let param_ident = &ident.ident;
+                        use_params.push(quote! { #param_ident });
+
+                        let param_getter_name = get_param_getter_ident(&ident.ident.to_string());
+                        get_params.push(quote! {
+                            let #param_getter_name = || -> #crate_name::Result<#ty> { ctx.flattened_param_value() };
+                            let #ident: #ty = #param_getter_name()?;
+                        });
+                        continue;
+                    }
+
                     let name = name
                         .clone()
                         .unwrap_or_else(|| ident.ident.unraw().to_string().to_camel_case());
@@ -330,14 +375,26 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         })
                         .unwrap_or_else(|| quote! {None});
 
+                    // Arguments with a default computed from the context are optional, so they
+                    // are registered as nullable.
+                    let arg_ty = match default_ctx {
+                        Some(_) => {
+                            quote! { <::std::option::Option<#ty> as #crate_name::Type>::create_type_info(registry) }
+                        }
+                        None => quote! { <#ty as #crate_name::Type>::create_type_info(registry) },
+                    };
+
                     schema_args.push(quote! {
-                        args.insert(#name, #crate_name::registry::MetaInputValue {
+                        if args.insert(#name, #crate_name::registry::MetaInputValue {
                             name: #name,
                             description: #desc,
-                            ty: <#ty as #crate_name::Type>::create_type_info(registry),
+                            ty: #arg_ty,
                             default_value: #schema_default,
                             validator: #validator,
-                        });
+                            is_secret: #secret,
+                        }).is_some() {
+                            panic!("Argument \"{}\" of field \"{}\" is defined more than once.", #name, #field_name);
+                        }
                     });
 
                     let param_ident = &ident.ident;
@@ -348,31 +405,80 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         None => quote! { None },
                     };
                     let param_getter_name = get_param_getter_ident(&ident.ident.to_string());
+                    let param_value = match default_ctx {
+                        Some(default_ctx) => {
+                            quote! { ctx.param_value_or_else(#name, #default_ctx) }
+                        }
+                        None => quote! { ctx.param_value(#name, #default) },
+                    };
+                    let param_value = if secret {
+                        quote! { #param_value.map_err(#crate_name::Error::redact_secrets) }
+                    } else {
+                        param_value
+                    };
                     get_params.push(quote! {
-                        let #param_getter_name = || -> #crate_name::Result<#ty> { ctx.param_value(#name, #default) };
+                        let #param_getter_name = || -> #crate_name::Result<#ty> { #param_value };
                         let #ident: #ty = #param_getter_name()?;
                     });
                 }
 
                 let schema_ty = ty.value_type();
 
-                schema_fields.push(quote! {
-                    fields.insert(#field_name.to_string(), #crate_name::registry::MetaField {
-                        name: #field_name.to_string(),
-                        description: #field_desc,
-                        args: {
-                            let mut args = #crate_name::indexmap::IndexMap::new();
-                            #(#schema_args)*
-                            args
-                        },
-                        ty: <#schema_ty as #crate_name::Type>::create_type_info(registry),
-                        deprecation: #field_deprecation,
-                        cache_control: #cache_control,
-                        external: #external,
-                        provides: #provides,
-                        requires: #requires,
+                let flatten_index = flatten_resolvers.len();
+                if field.flatten {
+                    // The fields of a flattened object are merged into this object, after its own
+                    // fields, and they are resolved after them too.
+                    flatten_schema_fields.push(quote! {
+                        <#schema_ty as #crate_name::Type>::create_type_info(registry);
+                        let flattened_type_name = <#schema_ty as #crate_name::Type>::type_name();
+                        // The flattened object is only removed once the schema is built, if no
+                        // other type refers to it.
+                        registry.flattened_objects.insert(flattened_type_name.to_string());
+                        if let Some(#crate_name::registry::MetaType::Object { fields: obj_fields, .. }) =
+                            registry.types.get(&*flattened_type_name) {
+                            for (name, mut field) in obj_fields.clone() {
+                                if fields.contains_key(&name) {
+                                    panic!(
+                                        "Field \"{}\" of object \"{}\" is defined more than once.",
+                                        name, #gql_typename
+                                    );
+                                }
+                                if let Some(cache_control) = #cache_control {
+                                    field
+                                        .cache_control
+                                        .get_or_insert_with(Default::default)
+                                        .merge(&cache_control);
+                                }
+                                registry
+                                    .flattened_fields
+                                    .entry(#gql_typename.to_string())
+                                    .or_default()
+                                    .insert(name.clone(), #flatten_index);
+                                fields.insert(name, field);
+                            }
+                        }
                     });
-                });
+                } else {
+                    schema_fields.push(quote! {
+                        fields.insert(#field_name.to_string(), #crate_name::registry::MetaField {
+                            name: #field_name.to_string(),
+                            description: #field_desc,
+                            args: {
+                                let mut args = #crate_name::indexmap::IndexMap::new();
+                                #(#schema_args)*
+                                args
+                            },
+                            ty: <#schema_ty as #crate_name::Type>::create_type_info(registry),
+                            deprecation: #field_deprecation,
+                            cache_control: #cache_control,
+                            external: #external,
+                            provides: #provides,
+                            requires: #requires,
+                            inherit_max_age: #inherit_max_age,
+                            complexity: #complexity,
+                        });
+                    });
+                }
 
                 let field_ident = &method.sig.ident;
                 if let OutputType::Value(inner_ty) = &ty {
@@ -397,9 +503,23 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                     }))
                     .expect("invalid block");
 
+                let resolve_future = quote! { self.#field_ident(ctx, #(#use_params),*) };
+                let resolve_future = match &field.timeout {
+                    Some(timeout) => quote! {
+                        match #crate_name::resolver_utils::resolve_with_timeout(#timeout, #resolve_future).await {
+                            Some(res) => res,
+                            None => return #crate_name::resolver_utils::resolve_field_timed_out(
+                                ctx,
+                                #timeout,
+                                <#schema_ty as #crate_name::Type>::qualified_type_name().ends_with('!'),
+                            ),
+                        }
+                    },
+                    None => quote! { #resolve_future.await },
+                };
                 let resolve_obj = quote! {
                     {
-                        let res = self.#field_ident(ctx, #(#use_params),*).await;
+                        let res = #resolve_future;
                         res.map_err(|err| err.into_error_with_path(ctx.item.pos, ctx.path_node.as_ref()))?
                     }
                 };
@@ -417,33 +537,31 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                             .map_err(|err| err.into_error_with_path(ctx.item.pos, ctx.path_node.as_ref()))?;
                     });
 
-                resolvers.push(quote! {
-                    if ctx.item.node.name.node == #field_name {
-                        #(#get_params)*
-                        #guard
-                        let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
-                        let res = #resolve_obj;
-                        #post_guard
-                        return #crate_name::OutputValueType::resolve(&res, &ctx_obj, ctx.item).await;
-                    }
-                });
-
-                if let Some((idx, _)) = method
-                    .attrs
-                    .iter()
-                    .enumerate()
-                    .find(|(_, a)| a.path.is_ident("field"))
-                {
-                    method.attrs.remove(idx);
+                if field.flatten {
+                    flatten_resolvers.push(quote! {
+                        if flatten_index == Some(&#flatten_index) {
+                            #(#get_params)*
+                            #guard
+                            let res = #resolve_obj;
+                            #post_guard
+                            return #crate_name::resolver_utils::ObjectType::resolve_field(&res, ctx).await;
+                        }
+                    });
+                } else {
+                    resolvers.push(quote! {
+                        if ctx.item.node.name.node == #field_name {
+                            #(#get_params)*
+                            #guard
+                            let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+                            let res = #resolve_obj;
+                            #post_guard
+                            return #crate_name::OutputValueType::resolve(&res, &ctx_obj, ctx.item).await;
+                        }
+                    });
                 }
-            } else if let Some((idx, _)) = method
-                .attrs
-                .iter()
-                .enumerate()
-                .find(|(_, a)| a.path.is_ident("field"))
-            {
-                method.attrs.remove(idx);
             }
+
+            method.attrs.retain(|attr| !attr.path.is_ident("field"));
         }
     }
 
@@ -454,10 +572,26 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
             #crate_name::CacheControl {
                 public: #public,
                 max_age: #max_age,
+                private_key: None,
             }
         }
     };
 
+    let flatten_resolvers = if flatten_resolvers.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            // The index of the flattened method that provides the field, if any.
+            let flatten_index = ctx
+                .schema_env
+                .registry
+                .flattened_fields
+                .get(#gql_typename)
+                .and_then(|fields| fields.get(ctx.item.node.name.node.as_str()));
+            #(#flatten_resolvers)*
+        }
+    };
+
     find_entities.sort_by(|(a, _), (b, _)| b.cmp(a));
     let find_entities_iter = find_entities.iter().map(|(_, code)| code);
 
@@ -477,6 +611,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                     fields: {
                         let mut fields = #crate_name::indexmap::IndexMap::new();
                         #(#schema_fields)*
+                        #(#flatten_schema_fields)*
                         fields
                     },
                     cache_control: #cache_control,
@@ -495,6 +630,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
         impl#generics #crate_name::resolver_utils::ObjectType for #self_ty #where_clause {
             async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#crate_name::serde_json::Value> {
                 #(#resolvers)*
+                #flatten_resolvers
                 Err(#crate_name::QueryError::FieldNotFound {
                     field_name: ctx.item.node.name.to_string(),
                     object: #gql_typename.to_string(),
@@ -62,10 +62,13 @@ pub fn generate(scalar_args: &args::Scalar, item_impl: &mut ItemImpl) -> Result<
         impl #generic #crate_name::OutputValueType for #self_ty #where_clause {
             async fn resolve(
                 &self,
-                _: &#crate_name::ContextSelectionSet<'_>,
+                ctx: &#crate_name::ContextSelectionSet<'_>,
                 _field: &#crate_name::Positioned<#crate_name::parser::types::Field>
             ) -> #crate_name::Result<#crate_name::serde_json::Value> {
-                Ok(#crate_name::ScalarType::to_value(self).into_json().unwrap())
+                Ok(ctx.serialize_scalar(
+                    #gql_typename,
+                    #crate_name::ScalarType::to_value(self).into_json().unwrap(),
+                ))
             }
         }
     };
@@ -53,11 +53,7 @@ pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<Token
                     .as_ref()
                     .map(|s| quote! {Some(#s)})
                     .unwrap_or_else(|| quote! {None});
-                let field_deprecation = field
-                    .deprecation
-                    .as_ref()
-                    .map(|s| quote! {Some(#s)})
-                    .unwrap_or_else(|| quote! {None});
+                let field_deprecation = field.deprecation.value(&crate_name);
                 let external = field.external;
                 let requires = match &field.requires {
                     Some(requires) => quote! { Some(#requires) },
@@ -70,16 +66,25 @@ pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<Token
                 let vis = &item.vis;
                 let ty = &item.ty;
 
-                let cache_control = {
-                    let public = field.cache_control.public;
-                    let max_age = field.cache_control.max_age;
-                    quote! {
-                        #crate_name::CacheControl {
-                            public: #public,
-                            max_age: #max_age,
+                let cache_control = match &field.cache_control {
+                    Some(cache_control) => {
+                        let public = cache_control.public;
+                        let max_age = cache_control.max_age;
+                        quote! {
+                            Some(#crate_name::CacheControl {
+                                public: #public,
+                                max_age: #max_age,
+                                private_key: None,
+                            })
                         }
                     }
+                    None => quote! { None },
                 };
+                let inherit_max_age = field
+                    .cache_control
+                    .as_ref()
+                    .map_or(false, |cache_control| cache_control.inherit_max_age);
+                let complexity = field.complexity;
 
                 schema_fields.push(quote! {
                     fields.insert(#field_name.to_string(), #crate_name::registry::MetaField {
@@ -92,6 +97,8 @@ pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<Token
                         external: #external,
                         provides: #provides,
                         requires: #requires,
+                        inherit_max_age: #inherit_max_age,
+                        complexity: #complexity,
                     });
                 });
 
@@ -154,6 +161,7 @@ pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<Token
             #crate_name::CacheControl {
                 public: #public,
                 max_age: #max_age,
+                private_key: None,
             }
         }
     };
@@ -54,12 +54,9 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                     .as_ref()
                     .map(|s| quote! {Some(#s)})
                     .unwrap_or_else(|| quote! {None});
-                let field_deprecation = field
-                    .deprecation
-                    .as_ref()
-                    .map(|s| quote! {Some(#s)})
-                    .unwrap_or_else(|| quote! {None});
+                let field_deprecation = field.deprecation.value(&crate_name);
                 let features = field.features;
+                let complexity = field.complexity;
 
                 if method.sig.asyncness.is_none() {
                     return Err(Error::new_spanned(
@@ -96,11 +93,14 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
 
                         match (&*pat.pat, &*pat.ty) {
                             (Pat::Ident(arg_ident), Type::Path(arg_ty)) => {
-                                args.push((
-                                    arg_ident.clone(),
-                                    arg_ty.clone(),
-                                    args::Argument::parse(&crate_name, &pat.attrs)?,
-                                ));
+                                let arg = args::Argument::parse(&crate_name, &pat.attrs)?;
+                                if arg.flatten {
+                                    return Err(Error::new_spanned(
+                                        pat,
+                                        "Subscription fields cannot have flattened arguments.",
+                                    ));
+                                }
+                                args.push((arg_ident.clone(), arg_ty.clone(), arg));
                                 pat.attrs.clear();
                             }
                             (arg, Type::Reference(TypeReference { elem, .. })) => {
@@ -143,7 +143,9 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         name,
                         desc,
                         default,
+                        default_ctx,
                         validator,
+                        secret,
                         ..
                     },
                 ) in args
@@ -162,13 +164,23 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         })
                         .unwrap_or_else(|| quote! {None});
 
+                    // Arguments with a default computed from the context are optional, so they
+                    // are registered as nullable.
+                    let arg_ty = match default_ctx {
+                        Some(_) => {
+                            quote! { <::std::option::Option<#ty> as #crate_name::Type>::create_type_info(registry) }
+                        }
+                        None => quote! { <#ty as #crate_name::Type>::create_type_info(registry) },
+                    };
+
                     schema_args.push(quote! {
                         args.insert(#name, #crate_name::registry::MetaInputValue {
                             name: #name,
                             description: #desc,
-                            ty: <#ty as #crate_name::Type>::create_type_info(registry),
+                            ty: #arg_ty,
                             default_value: #schema_default,
                             validator: #validator,
+                            is_secret: #secret,
                         });
                     });
 
@@ -179,9 +191,20 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         None => quote! { None },
                     };
                     let param_getter_name = get_param_getter_ident(&ident.ident.to_string());
+                    let param_value = match default_ctx {
+                        Some(default_ctx) => {
+                            quote! { ctx.param_value_or_else(#name, #default_ctx) }
+                        }
+                        None => quote! { ctx.param_value(#name, #default) },
+                    };
+                    let param_value = if secret {
+                        quote! { #param_value.map_err(#crate_name::Error::redact_secrets) }
+                    } else {
+                        param_value
+                    };
                     get_params.push(quote! {
-                        let #param_getter_name = || -> #crate_name::Result<#ty> { ctx.param_value(#name, #default) };
-                        let #ident: #ty = ctx.param_value(#name, #default)?;
+                        let #param_getter_name = || -> #crate_name::Result<#ty> { #param_value };
+                        let #ident: #ty = #param_value?;
                     });
                 }
 
@@ -229,6 +252,8 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         external: false,
                         requires: None,
                         provides: None,
+                        inherit_max_age: false,
+                        complexity: #complexity,
                     });
                 });
 
@@ -250,6 +275,21 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                     ));
                 }
 
+                let filter_stream = match &field.filter {
+                    Some(filter) => quote! {
+                        #crate_name::futures::StreamExt::filter_map(#create_field_stream, move |msg| async move {
+                            match #filter(ctx, &msg).await {
+                                Ok(true) => Some(Ok(msg)),
+                                Ok(false) => None,
+                                Err(err) => Some(Err(err.into_error_with_path(ctx.item.pos, ctx.path_node.as_ref()))),
+                            }
+                        })
+                    },
+                    None => quote! {
+                        #crate_name::futures::StreamExt::map(#create_field_stream, #crate_name::Result::Ok)
+                    },
+                };
+
                 let stream_fn = quote! {
                     #(#get_params)*
                     #guard
@@ -257,9 +297,11 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                     let field = ::std::sync::Arc::new(ctx.item.clone());
 
                     let pos = ctx.item.pos;
+                    let non_null = <<#stream_ty as #crate_name::futures::stream::Stream>::Item as #crate_name::Type>::qualified_type_name().ends_with('!');
                     let schema_env = ctx.schema_env.clone();
                     let query_env = ctx.query_env.clone();
-                    let stream = #crate_name::futures::StreamExt::then(#create_field_stream, {
+                    let stream = #filter_stream;
+                    let stream = #crate_name::futures::StreamExt::then(stream, {
                         let field_name = field_name.clone();
                         move |msg| {
                             let schema_env = schema_env.clone();
@@ -267,6 +309,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                             let field = field.clone();
                             let field_name = field_name.clone();
                             async move {
+                                let msg = msg?;
                                 let resolve_id = ::std::sync::atomic::AtomicUsize::default();
                                 let ctx_selection_set = query_env.create_context(
                                     &schema_env,
@@ -277,12 +320,22 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                                     &field.node.selection_set,
                                     &resolve_id,
                                 );
+                                if let Some(err) = #crate_name::OutputValueType::field_error(&msg) {
+                                    // The error of a non-null field makes the whole data null.
+                                    let data = if non_null {
+                                        #crate_name::serde_json::Value::Null
+                                    } else {
+                                        #crate_name::serde_json::json!({ field_name.as_str(): null })
+                                    };
+                                    return Ok(#crate_name::Response::new(data)
+                                        .with_error(err.into_error_with_path(field.pos, ctx_selection_set.path_node.as_ref())));
+                                }
                                 #crate_name::OutputValueType::resolve(&msg, &ctx_selection_set, &*field)
                                     .await
                                     .map(|value| {
-                                        #crate_name::serde_json::json!({
+                                        #crate_name::Response::new(#crate_name::serde_json::json!({
                                             field_name.as_str(): value
-                                        })
+                                        }))
                                     })
                             }
                         }
@@ -313,14 +366,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                 });
             }
 
-            if let Some((idx, _)) = method
-                .attrs
-                .iter()
-                .enumerate()
-                .find(|(_, a)| a.path.is_ident("field"))
-            {
-                method.attrs.remove(idx);
-            }
+            method.attrs.retain(|attr| !attr.path.is_ident("field"));
         }
     }
 
@@ -356,7 +402,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
             fn create_field_stream<'a>(
                 &'a self,
                 ctx: &'a #crate_name::Context<'a>,
-            ) -> ::std::pin::Pin<::std::boxed::Box<dyn #crate_name::futures::Stream<Item = #crate_name::Result<#crate_name::serde_json::Value>> + Send + 'a>> {
+            ) -> ::std::pin::Pin<::std::boxed::Box<dyn #crate_name::futures::Stream<Item = #crate_name::Result<#crate_name::Response>> + Send + 'a>> {
                 #(#create_stream)*
                 let error = #crate_name::QueryError::FieldNotFound {
                     field_name: ctx.item.node.name.to_string(),
@@ -37,6 +37,7 @@ pub fn generate(union_args: &args::Interface, input: &DeriveInput) -> Result<Tok
 
     for variant in s.variants.iter() {
         let enum_name = &variant.ident;
+        let item_args = args::UnionItem::parse(&variant.attrs)?;
         let field = match &variant.fields {
             Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed.first().unwrap(),
             Fields::Unnamed(_) => {
@@ -79,12 +80,24 @@ pub fn generate(union_args: &args::Interface, input: &DeriveInput) -> Result<Tok
             registry_types.push(quote! {
                 <#p as #crate_name::Type>::create_type_info(registry);
             });
-            possible_types.push(quote! {
-                possible_types.insert(<#p as #crate_name::Type>::type_name().to_string());
-            });
-            get_introspection_typename.push(quote! {
-                #ident::#enum_name(obj) => <#p as #crate_name::Type>::type_name()
-            });
+            match &item_args.typename {
+                Some(typename) => {
+                    possible_types.push(quote! {
+                        possible_types.insert(#typename.to_string());
+                    });
+                    get_introspection_typename.push(quote! {
+                        #ident::#enum_name(_) => ::std::borrow::Cow::Borrowed(#typename)
+                    });
+                }
+                None => {
+                    possible_types.push(quote! {
+                        possible_types.insert(<#p as #crate_name::Type>::type_name().to_string());
+                    });
+                    get_introspection_typename.push(quote! {
+                        #ident::#enum_name(obj) => <#p as #crate_name::Type>::type_name()
+                    });
+                }
+            }
             collect_all_fields.push(quote! {
                 #ident::#enum_name(obj) => obj.collect_all_fields(ctx, fields)
             });
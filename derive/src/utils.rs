@@ -60,6 +60,16 @@ fn parse_nested_validator(
                         None => Some(item),
                     })
                     .unwrap())
+            } else if ls.path.is_ident("not") || ls.path.is_ident("list") {
+                if ls.nested.len() != 1 {
+                    return Err(Error::new_spanned(
+                        ls,
+                        "Only one validator can be defined, you can combine validators with `and` or `or`",
+                    ));
+                }
+                let validator = parse_nested_validator(crate_name, &ls.nested[0])?;
+                let method = &ls.path;
+                Ok(quote! { #crate_name::validators::InputValueValidatorExt::#method(#validator) })
             } else {
                 let ty = &ls.path;
                 for item in &ls.nested {
@@ -113,53 +123,85 @@ pub fn parse_validator(crate_name: &TokenStream, args: &MetaList) -> Result<Toke
     Ok(quote! {None})
 }
 
+fn parse_guard(crate_name: &TokenStream, item: &NestedMeta) -> Result<TokenStream> {
+    if let NestedMeta::Meta(Meta::List(ls)) = item {
+        if ls.path.is_ident("cached") {
+            if ls.nested.len() != 1 {
+                return Err(Error::new_spanned(ls, "Only one guard can be cached"));
+            }
+            let guard = parse_guard(crate_name, &ls.nested[0])?;
+            return Ok(quote! { #crate_name::guard::GuardExt::cached(#guard) });
+        }
+
+        let ty = &ls.path;
+        let mut params = Vec::new();
+        for attr in &ls.nested {
+            if let NestedMeta::Meta(Meta::NameValue(nv)) = attr {
+                let name = &nv.path;
+                if let Lit::Str(value) = &nv.lit {
+                    let value_str = value.value();
+                    if value_str.starts_with('@') {
+                        let getter_name = get_param_getter_ident(&value_str[1..]);
+                        params.push(quote! { #name: #getter_name()? });
+                    } else {
+                        let expr = syn::parse_str::<Expr>(&value_str)?;
+                        params.push(quote! { #name: (#expr).into() });
+                    }
+                } else {
+                    return Err(Error::new_spanned(&nv.lit, "Value must be string literal"));
+                }
+            } else {
+                return Err(Error::new_spanned(attr, "Invalid property for guard"));
+            }
+        }
+        Ok(quote! { #ty { #(#params),* } })
+    } else if let NestedMeta::Lit(Lit::Str(expr)) = item {
+        // A guard expression, for guards that can't be written as a struct literal, e.g.
+        // `guard("DataPresent::<User>::new()")`.
+        let expr = expr.parse::<Expr>()?;
+        Ok(quote! { #expr })
+    } else {
+        Err(Error::new_spanned(item, "Invalid guard"))
+    }
+}
+
 pub fn parse_guards(crate_name: &TokenStream, args: &MetaList) -> Result<Option<TokenStream>> {
+    let mut guards = None;
     for arg in &args.nested {
         if let NestedMeta::Meta(Meta::List(ls)) = arg {
             if ls.path.is_ident("guard") {
-                let mut guards = None;
                 for item in &ls.nested {
-                    if let NestedMeta::Meta(Meta::List(ls)) = item {
-                        let ty = &ls.path;
-                        let mut params = Vec::new();
-                        for attr in &ls.nested {
-                            if let NestedMeta::Meta(Meta::NameValue(nv)) = attr {
-                                let name = &nv.path;
-                                if let Lit::Str(value) = &nv.lit {
-                                    let value_str = value.value();
-                                    if value_str.starts_with('@') {
-                                        let getter_name = get_param_getter_ident(&value_str[1..]);
-                                        params.push(quote! { #name: #getter_name()? });
-                                    } else {
-                                        let expr = syn::parse_str::<Expr>(&value_str)?;
-                                        params.push(quote! { #name: (#expr).into() });
-                                    }
-                                } else {
-                                    return Err(Error::new_spanned(
-                                        &nv.lit,
-                                        "Value must be string literal",
-                                    ));
-                                }
-                            } else {
-                                return Err(Error::new_spanned(attr, "Invalid property for guard"));
-                            }
-                        }
-                        let guard = quote! { #ty { #(#params),* } };
-                        if guards.is_none() {
-                            guards = Some(guard);
-                        } else {
-                            guards =
-                                Some(quote! { #crate_name::guard::GuardExt::and(#guard, #guards) });
-                        }
-                    } else {
-                        return Err(Error::new_spanned(item, "Invalid guard"));
-                    }
+                    let guard = parse_guard(crate_name, item)?;
+                    guards = Some(chain_guards(crate_name, guards, guard));
                 }
-                return Ok(guards);
             }
         }
     }
-    Ok(None)
+    Ok(guards)
+}
+
+/// Combines guards with `GuardExt::and`, so they are checked in declaration order.
+pub fn chain_guards(
+    crate_name: &TokenStream,
+    guards: Option<TokenStream>,
+    guard: TokenStream,
+) -> TokenStream {
+    match guards {
+        Some(guards) => quote! { #crate_name::guard::GuardExt::and(#guards, #guard) },
+        None => guard,
+    }
+}
+
+/// Combines post guards from several attributes with `PostGuardExt::and`.
+pub fn chain_post_guards(
+    crate_name: &TokenStream,
+    guards: Option<TokenStream>,
+    guard: TokenStream,
+) -> TokenStream {
+    match guards {
+        Some(guards) => quote! { #crate_name::guard::PostGuardExt::and(#guards, #guard) },
+        None => guard,
+    }
 }
 
 pub fn parse_post_guards(crate_name: &TokenStream, args: &MetaList) -> Result<Option<TokenStream>> {
@@ -274,6 +316,28 @@ pub fn parse_default_with(lit: &Lit) -> Result<TokenStream> {
     }
 }
 
+pub fn parse_duration(lit: &syn::LitStr) -> Result<TokenStream> {
+    let value = lit.value();
+    let (number, unit) = value.split_at(
+        value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or_else(|| value.len()),
+    );
+    let millis = match (number.parse::<u64>(), unit) {
+        (Ok(n), "ms") => Some(n),
+        (Ok(n), "s") => n.checked_mul(1000),
+        (Ok(n), "m") => n.checked_mul(60 * 1000),
+        _ => {
+            return Err(Error::new_spanned(
+                lit,
+                "Invalid duration, expected something like \"100ms\", \"2s\" or \"1m\".",
+            ))
+        }
+    };
+    let millis = millis.ok_or_else(|| Error::new_spanned(lit, "The duration is too long."))?;
+    Ok(quote! { ::std::time::Duration::from_millis(#millis) })
+}
+
 pub fn get_param_getter_ident(name: &str) -> Ident {
     Ident::new(&format!("__{}_getter", name), Span::call_site())
 }
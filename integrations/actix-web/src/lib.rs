@@ -8,7 +8,7 @@ mod subscription;
 use actix_web::dev::{HttpResponseBuilder, Payload, PayloadStream};
 use actix_web::http::StatusCode;
 use actix_web::{http, web, Error, FromRequest, HttpRequest, HttpResponse, Responder};
-use async_graphql::http::{receive_body, MultipartOptions};
+use async_graphql::http::{receive_body, HttpHeaders, MultipartOptions};
 use async_graphql::{ParseRequestError, Request, Response};
 use futures::channel::mpsc;
 use futures::future::Ready;
@@ -23,6 +23,7 @@ pub use subscription::WSSubscription;
 ///
 /// It's a wrapper of `async_graphql::Request`, you can use `GQLRequest::into_inner` unwrap it to `async_graphql::Request`.
 /// `async_graphql::http::MultipartOptions` allows to configure extraction process.
+/// The request headers are added to the request data as `async_graphql::http::HttpHeaders`.
 pub struct GQLRequest(Request);
 
 impl GQLRequest {
@@ -39,12 +40,17 @@ impl FromRequest for GQLRequest {
 
     fn from_request(req: &HttpRequest, payload: &mut Payload<PayloadStream>) -> Self::Future {
         let config = req.app_data::<Self::Config>().cloned().unwrap_or_default();
+        let headers = req
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?)))
+            .collect::<HttpHeaders>();
 
         if req.method() == Method::GET {
             let res = web::Query::<async_graphql::Request>::from_query(req.query_string());
             Box::pin(async move {
                 let gql_request = res?;
-                Ok(GQLRequest(gql_request.into_inner()))
+                Ok(GQLRequest(gql_request.into_inner().data(headers)))
             })
         } else {
             let content_type = req
@@ -79,7 +85,8 @@ impl FromRequest for GQLRequest {
                         }
                         _ => actix_web::error::ErrorBadRequest(err),
                     })
-                    .await?,
+                    .await?
+                    .data(headers),
                 ))
             })
         }
@@ -110,7 +117,7 @@ impl Responder for GQLResponse {
 
 fn add_cache_control(builder: &mut HttpResponseBuilder, resp: &Response) {
     if resp.is_ok() {
-        if let Some(cache_control) = resp.cache_control.value() {
+        if let Some(cache_control) = resp.cache_control.header_value() {
             builder.header("cache-control", cache_control);
         }
     }
@@ -1,11 +1,16 @@
 use actix::{
     Actor, ActorContext, ActorFuture, AsyncContext, ContextFutureSpawner, StreamHandler, WrapFuture,
 };
-use actix_web_actors::ws::{Message, ProtocolError, WebsocketContext};
-use async_graphql::http::WebSocketStream;
+use actix_web::error::PayloadError;
+use actix_web::web::Bytes;
+use actix_web::{Error, HttpRequest, HttpResponse};
+use actix_web_actors::ws::{self, Message, ProtocolError, WebsocketContext};
+use async_graphql::http::{Protocols, WebSocketStream};
 use async_graphql::{resolver_utils::ObjectType, Data, FieldResult, Schema, SubscriptionType};
+use futures::future::BoxFuture;
 use futures::stream::SplitSink;
-use futures::{SinkExt, StreamExt};
+use futures::{Future, FutureExt, SinkExt, Stream, StreamExt};
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 
 const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
@@ -14,9 +19,12 @@ const CLIENT_TIMEOUT: Duration = Duration::from_secs(10);
 /// Actor for subscription via websocket
 pub struct WSSubscription<Query, Mutation, Subscription> {
     schema: Schema<Query, Mutation, Subscription>,
+    protocol: Protocols,
     hb: Instant,
     sink: Option<SplitSink<WebSocketStream, String>>,
-    initializer: Option<Box<dyn Fn(serde_json::Value) -> FieldResult<Data> + Send + Sync>>,
+    initializer: Option<
+        Box<dyn Fn(serde_json::Value) -> BoxFuture<'static, FieldResult<Data>> + Send + Sync>,
+    >,
 }
 
 impl<Query, Mutation, Subscription> WSSubscription<Query, Mutation, Subscription>
@@ -26,26 +34,58 @@ where
     Subscription: SubscriptionType + Send + Sync + 'static,
 {
     /// Create an actor for subscription connection via websocket.
+    ///
+    /// It speaks the `subscriptions-transport-ws` protocol, unless it is started with
+    /// `WSSubscription::start`.
     pub fn new(schema: &Schema<Query, Mutation, Subscription>) -> Self {
         Self {
             schema: schema.clone(),
+            protocol: Protocols::SubscriptionsTransportWS,
             hb: Instant::now(),
             sink: None,
             initializer: None,
         }
     }
 
-    /// Set a context data initialization function.
-    pub fn initializer<F>(self, f: F) -> Self
+    /// Set an async context data initialization function.
+    pub fn initializer<F, R>(self, f: F) -> Self
     where
-        F: Fn(serde_json::Value) -> FieldResult<Data> + Send + Sync + 'static,
+        F: Fn(serde_json::Value) -> R + Send + Sync + 'static,
+        R: Future<Output = FieldResult<Data>> + Send + 'static,
     {
         Self {
-            initializer: Some(Box::new(f)),
+            initializer: Some(Box::new(move |value| f(value).boxed())),
             ..self
         }
     }
 
+    /// Start the actor for a websocket connection, with the first supported protocol of the
+    /// `Sec-WebSocket-Protocol` header of the request, which the response echoes. If the request
+    /// has none, the `subscriptions-transport-ws` protocol is used.
+    pub fn start<T>(mut self, request: &HttpRequest, stream: T) -> Result<HttpResponse, Error>
+    where
+        T: Stream<Item = Result<Bytes, PayloadError>> + 'static,
+    {
+        if let Some(protocol) = request
+            .headers()
+            .get("sec-websocket-protocol")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|protocols| {
+                protocols
+                    .split(',')
+                    .find_map(|protocol| Protocols::from_str(protocol.trim()).ok())
+            })
+        {
+            self.protocol = protocol;
+        }
+        ws::start_with_protocols(
+            self,
+            &["graphql-transport-ws", "graphql-ws"],
+            request,
+            stream,
+        )
+    }
+
     fn hb(&self, ctx: &mut WebsocketContext<Self>) {
         ctx.run_interval(HEARTBEAT_INTERVAL, |act, ctx| {
             if Instant::now().duration_since(act.hb) > CLIENT_TIMEOUT {
@@ -66,19 +106,16 @@ where
 
     fn started(&mut self, ctx: &mut Self::Context) {
         self.hb(ctx);
-        if let Some(initializer) = self.initializer.take() {
-            let (sink, stream) = async_graphql::http::WebSocketStream::new_with_initializer(
-                &self.schema,
-                initializer,
-            )
-            .split();
-            ctx.add_stream(stream);
-            self.sink = Some(sink);
+        let (sink, stream) = if let Some(initializer) = self.initializer.take() {
+            WebSocketStream::new_with_protocol(&self.schema, self.protocol, initializer)
         } else {
-            let (sink, stream) = async_graphql::http::WebSocketStream::new(&self.schema).split();
-            ctx.add_stream(stream);
-            self.sink = Some(sink);
-        };
+            WebSocketStream::new_with_protocol(&self.schema, self.protocol, |_| {
+                futures::future::ready(Ok(Default::default()))
+            })
+        }
+        .split();
+        ctx.add_stream(stream);
+        self.sink = Some(sink);
     }
 }
 
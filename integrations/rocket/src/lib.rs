@@ -3,7 +3,7 @@
 #![warn(missing_docs)]
 #![forbid(unsafe_code)]
 
-use async_graphql::http::MultipartOptions;
+use async_graphql::http::{HttpHeaders, MultipartOptions};
 use async_graphql::{resolver_utils::ObjectType, Schema, SubscriptionType, Variables};
 use log::{error, info};
 use rocket::{
@@ -129,6 +129,9 @@ impl GraphQL {
 /// Implements `FromQuery` and `FromData`, so that it can be used as parameter in a
 /// Rocket route.
 ///
+/// With `FromData`, the request headers are added to the request data as
+/// `async_graphql::http::HttpHeaders`. `FromQuery` has no access to the headers.
+///
 /// # Examples
 ///
 /// ```rust,no_run,ignore
@@ -254,8 +257,13 @@ impl FromData for GQLRequest {
         )
         .await;
 
+        let headers = req
+            .headers()
+            .iter()
+            .map(|header| (header.name().as_str(), header.value()))
+            .collect::<HttpHeaders>();
         match request {
-            Ok(request) => data::Outcome::Success(GQLRequest(request)),
+            Ok(request) => data::Outcome::Success(GQLRequest(request.data(headers))),
             Err(e) => data::Outcome::Failure((Status::BadRequest, format!("{}", e))),
         }
     }
@@ -288,7 +296,7 @@ pub trait CacheControl {
 impl<'r> CacheControl for ResponseBuilder<'r> {
     fn cache_control(&mut self, resp: &async_graphql::Response) -> &mut ResponseBuilder<'r> {
         if resp.is_ok() {
-            if let Some(value) = resp.cache_control.value() {
+            if let Some(value) = resp.cache_control.header_value() {
                 self.header(Header::new("cache-control", value));
             }
         }
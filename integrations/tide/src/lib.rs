@@ -5,7 +5,7 @@
 #![allow(clippy::needless_doctest_main)]
 #![forbid(unsafe_code)]
 
-use async_graphql::http::MultipartOptions;
+use async_graphql::http::{HttpHeaders, MultipartOptions};
 use async_graphql::{resolver_utils::ObjectType, Schema, SubscriptionType};
 use async_trait::async_trait;
 use std::str::FromStr;
@@ -85,6 +85,8 @@ where
 #[async_trait]
 pub trait RequestExt<State: Clone + Send + Sync + 'static>: Sized {
     /// Convert a query to `async_graphql::Request`.
+    ///
+    /// The request headers are added to the request data as `async_graphql::http::HttpHeaders`.
     async fn body_graphql(self) -> tide::Result<async_graphql::Request> {
         self.body_graphql_opts(Default::default()).await
     }
@@ -102,16 +104,25 @@ impl<State: Clone + Send + Sync + 'static> RequestExt<State> for Request<State>
         self,
         opts: MultipartOptions,
     ) -> tide::Result<async_graphql::Request> {
-        if self.method() == Method::Get {
-            Ok(self.query::<async_graphql::Request>()?)
+        let http_headers = self
+            .iter()
+            .flat_map(|(name, values)| {
+                values
+                    .iter()
+                    .map(move |value| (name.as_str(), value.as_str()))
+            })
+            .collect::<HttpHeaders>();
+        let request = if self.method() == Method::Get {
+            self.query::<async_graphql::Request>()?
         } else {
             let content_type = self
                 .header(&headers::CONTENT_TYPE)
                 .and_then(|values| values.get(0).map(|value| value.to_string()));
             async_graphql::http::receive_body(content_type, self, opts)
                 .await
-                .map_err(|err| tide::Error::new(StatusCode::BadRequest, err))
-        }
+                .map_err(|err| tide::Error::new(StatusCode::BadRequest, err))?
+        };
+        Ok(request.data(http_headers))
     }
 }
 
@@ -132,7 +143,7 @@ impl ResponseExt for Response {
 
 fn add_cache_control(mut http_resp: Response, resp: &async_graphql::Response) -> Response {
     if resp.is_ok() {
-        if let Some(cache_control) = resp.cache_control.value() {
+        if let Some(cache_control) = resp.cache_control.header_value() {
             if let Ok(header) = tide::http::headers::HeaderName::from_str("cache-control") {
                 http_resp.insert_header(header, cache_control);
             }
@@ -5,15 +5,16 @@
 #![allow(clippy::needless_doctest_main)]
 #![forbid(unsafe_code)]
 
-use async_graphql::http::MultipartOptions;
+use async_graphql::http::{HttpHeaders, MultipartOptions, Protocols};
 use async_graphql::{
     resolver_utils::ObjectType, Data, FieldResult, Request, Schema, SubscriptionType,
 };
 use futures::io::ErrorKind;
 use futures::{select, TryStreamExt};
-use futures::{SinkExt, StreamExt};
+use futures::{Future, SinkExt, StreamExt};
 use hyper::Method;
 use std::io;
+use std::str::FromStr;
 use std::sync::Arc;
 use warp::filters::ws::Message;
 use warp::filters::BoxedFilter;
@@ -37,6 +38,7 @@ impl Reject for BadRequest {}
 /// GraphQL request filter
 ///
 /// It outputs a tuple containing the `async_graphql::Schema` and `async_graphql::Request`.
+/// The request headers are added to the request data as `async_graphql::http::HttpHeaders`.
 ///
 /// # Examples
 ///
@@ -103,6 +105,7 @@ where
         .and(warp::method())
         .and(warp::query::raw().or(warp::any().map(String::new)).unify())
         .and(warp::header::optional::<String>("content-type"))
+        .and(warp::header::headers_cloned())
         .and(warp::body::stream())
         .and(warp::any().map(move || opts.clone()))
         .and(warp::any().map(move || schema.clone()))
@@ -110,24 +113,31 @@ where
             |method,
              query: String,
              content_type,
+             headers: warp::http::HeaderMap,
              body,
              opts: Arc<MultipartOptions>,
              schema| async move {
+                let headers = headers
+                    .iter()
+                    .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?)))
+                    .collect::<HttpHeaders>();
                 if method == Method::GET {
                     let request: Request = serde_urlencoded::from_str(&query)
                         .map_err(|err| warp::reject::custom(BadRequest(err.into())))?;
-                    Ok::<_, Rejection>((schema, request))
+                    Ok::<_, Rejection>((schema, request.data(headers)))
                 } else {
                     let request = async_graphql::http::receive_body(
                         content_type,
-                        futures::TryStreamExt::map_err(body, |err| io::Error::new(ErrorKind::Other, err))
-                            .map_ok(|mut buf| Buf::to_bytes(&mut buf))
-                            .into_async_read(),
+                        futures::TryStreamExt::map_err(body, |err| {
+                            io::Error::new(ErrorKind::Other, err)
+                        })
+                        .map_ok(|mut buf| Buf::to_bytes(&mut buf))
+                        .into_async_read(),
                         MultipartOptions::clone(&opts),
                     )
                     .await
                     .map_err(|err| warp::reject::custom(BadRequest(err.into())))?;
-                    Ok::<_, Rejection>((schema, request))
+                    Ok::<_, Rejection>((schema, request.data(headers)))
                 }
             },
         )
@@ -175,13 +185,13 @@ where
     Mutation: ObjectType + Sync + Send + 'static,
     Subscription: SubscriptionType + Send + Sync + 'static,
 {
-    graphql_subscription_with_initializer(schema, |_| Ok(Default::default()))
+    graphql_subscription_with_initializer(schema, |_| async { Ok(Default::default()) })
 }
 
 /// GraphQL subscription filter
 ///
-/// Specifies that a function converts the init payload to data.
-pub fn graphql_subscription_with_initializer<Query, Mutation, Subscription, F>(
+/// Specifies that an async function converts the init payload to data.
+pub fn graphql_subscription_with_initializer<Query, Mutation, Subscription, F, R>(
     schema: Schema<Query, Mutation, Subscription>,
     initializer: F,
 ) -> BoxedFilter<(impl Reply,)>
@@ -189,22 +199,36 @@ where
     Query: ObjectType + Sync + Send + 'static,
     Mutation: ObjectType + Sync + Send + 'static,
     Subscription: SubscriptionType + Send + Sync + 'static,
-    F: Fn(serde_json::Value) -> FieldResult<Data> + Send + Sync + Clone + 'static,
+    F: Fn(serde_json::Value) -> R + Send + Sync + Clone + 'static,
+    R: Future<Output = FieldResult<Data>> + Send + 'static,
 {
     warp::any()
         .and(warp::ws())
+        .and(warp::header::optional::<String>("sec-websocket-protocol"))
         .and(warp::any().map(move || schema.clone()))
         .and(warp::any().map(move || initializer.clone()))
         .map(
-            |ws: warp::ws::Ws, schema: Schema<Query, Mutation, Subscription>, initializer: F| {
-                ws.on_upgrade(move |websocket| {
+            |ws: warp::ws::Ws,
+             protocols: Option<String>,
+             schema: Schema<Query, Mutation, Subscription>,
+             initializer: F| {
+                // The first supported protocol that the client requested, which is echoed.
+                let protocol = protocols
+                    .and_then(|protocols| {
+                        protocols
+                            .split(',')
+                            .find_map(|protocol| Protocols::from_str(protocol.trim()).ok())
+                    })
+                    .unwrap_or(Protocols::SubscriptionsTransportWS);
+
+                let reply = ws.on_upgrade(move |websocket| {
                     let (mut tx, rx) = websocket.split();
-                    let (mut stx, srx) =
-                        async_graphql::http::WebSocketStream::new_with_initializer(
-                            &schema,
-                            initializer,
-                        )
-                        .split();
+                    let (mut stx, srx) = async_graphql::http::WebSocketStream::new_with_protocol(
+                        &schema,
+                        protocol,
+                        initializer,
+                    )
+                    .split();
 
                     let mut rx = rx.fuse();
                     let srx = srx.fuse();
@@ -239,10 +263,15 @@ where
                             }
                         }
                     }
-                })
+                });
+
+                warp::reply::with_header(
+                    reply,
+                    "Sec-WebSocket-Protocol",
+                    protocol.sec_websocket_protocol(),
+                )
             },
         )
-        .map(|reply| warp::reply::with_header(reply, "Sec-WebSocket-Protocol", "graphql-ws"))
         .boxed()
 }
 
@@ -257,7 +286,7 @@ impl From<async_graphql::Response> for GQLResponse {
 
 fn add_cache_control(http_resp: &mut Response, resp: &async_graphql::Response) {
     if resp.is_ok() {
-        if let Some(cache_control) = resp.cache_control.value() {
+        if let Some(cache_control) = resp.cache_control.header_value() {
             if let Ok(value) = cache_control.parse() {
                 http_resp.headers_mut().insert("cache-control", value);
             }
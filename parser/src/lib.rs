@@ -9,11 +9,13 @@ use pest::error::LineColLocation;
 use pest::RuleType;
 use std::fmt;
 
-pub use parse::{parse_query, parse_schema};
+pub use limits::ParseLimits;
+pub use parse::{parse_query, parse_query_with_limits, parse_schema};
 pub use pos::{Pos, Positioned};
 
 pub mod types;
 
+mod limits;
 mod parse;
 mod pos;
 
@@ -0,0 +1,209 @@
+use crate::{Error, Pos, Result};
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Limits on the size of a query, checked before it is parsed.
+///
+/// Deeply nested list and object values can overflow the stack of the parser, so they have to be
+/// rejected before parsing. By default there are no limits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// The maximum nesting of list and object values, including the lists of variable types.
+    pub max_value_depth: Option<usize>,
+
+    /// The maximum number of tokens.
+    pub max_tokens: Option<usize>,
+}
+
+impl ParseLimits {
+    /// Check that the query source is within the limits.
+    ///
+    /// # Errors
+    ///
+    /// Fails at the first token that exceeds a limit.
+    pub fn check(&self, input: &str) -> Result<()> {
+        if self.max_value_depth.is_none() && self.max_tokens.is_none() {
+            return Ok(());
+        }
+
+        let mut lexer = Lexer {
+            chars: input.chars().peekable(),
+            pos: Pos { line: 1, column: 1 },
+        };
+        let mut tokens = 0;
+        let mut value_depth = 0;
+        // Values only appear in arguments and variable definitions, which can't be nested.
+        let mut in_parens = false;
+
+        while let Some((pos, c)) = lexer.next_token() {
+            tokens += 1;
+            if let Some(max_tokens) = self.max_tokens {
+                if tokens > max_tokens {
+                    return Err(Error::new(
+                        format!("The query has more than {} tokens", max_tokens),
+                        pos,
+                    ));
+                }
+            }
+
+            match c {
+                '(' => in_parens = true,
+                ')' => in_parens = false,
+                '[' | '{' if in_parens => {
+                    value_depth += 1;
+                    if let Some(max_value_depth) = self.max_value_depth {
+                        if value_depth > max_value_depth {
+                            return Err(Error::new(
+                                format!(
+                                    "The values are nested more than {} levels deep",
+                                    max_value_depth
+                                ),
+                                pos,
+                            ));
+                        }
+                    }
+                }
+                ']' | '}' if in_parens => value_depth = value_depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A lexer that only finds the start of the tokens.
+struct Lexer<'a> {
+    chars: Peekable<Chars<'a>>,
+    pos: Pos,
+}
+
+impl<'a> Lexer<'a> {
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.pos.line += 1;
+            self.pos.column = 1;
+        } else {
+            self.pos.column += 1;
+        }
+        Some(c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.chars.peek() == Some(&expected) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Skip to the next token, and return its position and first character.
+    fn next_token(&mut self) -> Option<(Pos, char)> {
+        loop {
+            let pos = self.pos;
+            let c = self.bump()?;
+            match c {
+                '#' => while !matches!(self.bump(), Some('\n') | None) {},
+                '"' => {
+                    if self.eat('"') {
+                        if self.eat('"') {
+                            self.skip_block_string();
+                        }
+                    } else {
+                        self.skip_string();
+                    }
+                    return Some((pos, c));
+                }
+                '.' => {
+                    while self.eat('.') {}
+                    return Some((pos, c));
+                }
+                c if c.is_ascii_alphanumeric() || c == '_' || c == '-' => {
+                    while matches!(
+                        self.chars.peek(),
+                        Some(&c) if c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '+' | '-')
+                    ) {
+                        self.bump();
+                    }
+                    return Some((pos, c));
+                }
+                c if c.is_whitespace() || c == ',' || c == '\u{feff}' => {}
+                c => return Some((pos, c)),
+            }
+        }
+    }
+
+    fn skip_string(&mut self) {
+        while let Some(c) = self.bump() {
+            match c {
+                '\\' => {
+                    self.bump();
+                }
+                '"' | '\n' => break,
+                _ => {}
+            }
+        }
+    }
+
+    fn skip_block_string(&mut self) {
+        while let Some(c) = self.bump() {
+            match c {
+                '\\' => {
+                    self.eat('"');
+                }
+                '"' if self.eat('"') && self.eat('"') => break,
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_depth() {
+        let limits = ParseLimits {
+            max_value_depth: Some(2),
+            ..ParseLimits::default()
+        };
+        assert!(limits
+            .check("query($a: [Int] = [1]) { a(b: {c: [1]}) { d(e: [[1]]) } }")
+            .is_ok());
+        assert!(limits
+            .check(r#"{ a(b: "[[[", c: """ {{{ """) { b { c { d } } } }"#)
+            .is_ok());
+        assert_eq!(
+            limits.check("{\n  a(b: [[[1]]])\n}"),
+            Err(Error::new(
+                "The values are nested more than 2 levels deep",
+                Pos {
+                    line: 2,
+                    column: 10
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_tokens() {
+        let limits = ParseLimits {
+            max_tokens: Some(8),
+            ..ParseLimits::default()
+        };
+        assert!(limits.check("{ a(b: 1.5e+3) } # c d e f").is_ok());
+        assert!(limits.check("{ ...A }").is_ok());
+        assert_eq!(
+            limits.check("{ a b c d e f g h }"),
+            Err(Error::new(
+                "The query has more than 8 tokens",
+                Pos {
+                    line: 1,
+                    column: 17
+                }
+            ))
+        );
+    }
+}
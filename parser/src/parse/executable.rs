@@ -16,6 +16,19 @@ pub fn parse_query<T: AsRef<str>>(input: T) -> Result<ExecutableDocument> {
     )?)
 }
 
+/// Parse a GraphQL query document, after checking that it is within the limits.
+///
+/// # Errors
+///
+/// Fails if the query exceeds the limits or is not a valid GraphQL document.
+pub fn parse_query_with_limits<T: AsRef<str>>(
+    input: T,
+    limits: &ParseLimits,
+) -> Result<ExecutableDocument> {
+    limits.check(input.as_ref())?;
+    parse_query(input)
+}
+
 fn parse_executable_document(
     pair: Pair<Rule>,
     pc: &mut PositionCalculator,
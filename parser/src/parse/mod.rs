@@ -4,7 +4,7 @@
 
 use crate::pos::{PositionCalculator, Positioned};
 use crate::types::*;
-use crate::{Error, Result};
+use crate::{Error, ParseLimits, Result};
 use pest::iterators::{Pair, Pairs};
 use pest::Parser;
 use pest_derive::Parser;
@@ -14,7 +14,7 @@ mod executable;
 mod service;
 mod utils;
 
-pub use executable::parse_query;
+pub use executable::{parse_query, parse_query_with_limits};
 pub use service::parse_schema;
 
 #[derive(Parser)]
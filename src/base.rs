@@ -1,9 +1,11 @@
 use crate::parser::types::Field;
 use crate::registry::Registry;
 use crate::{
-    registry, ContextSelectionSet, FieldResult, InputValueResult, Positioned, Result, Value,
+    registry, ContextSelectionSet, FieldError, FieldResult, InputValueResult, Positioned, Result,
+    Value,
 };
 use std::borrow::Cow;
+use std::fmt::Display;
 use std::sync::Arc;
 
 /// Represents a GraphQL type
@@ -47,6 +49,13 @@ pub trait OutputValueType: Type {
         ctx: &ContextSelectionSet<'_>,
         field: &Positioned<Field>,
     ) -> Result<serde_json::Value>;
+
+    /// The error of a `Result`, which is reported without ending a subscription when it is
+    /// yielded by its stream.
+    #[doc(hidden)]
+    fn field_error(&self) -> Option<FieldError> {
+        None
+    }
 }
 
 /// Represents a GraphQL scalar
@@ -88,6 +97,9 @@ pub trait ScalarType: Sized + Send {
     }
 
     /// Convert the scalar to `Value`.
+    ///
+    /// The output doesn't need to have the same format as the input accepted by `parse` and
+    /// `is_valid`, but this is also used to show the default values of arguments in introspection.
     fn to_value(&self) -> Value;
 }
 
@@ -186,4 +198,46 @@ impl<T: OutputValueType + Sync> OutputValueType for FieldResult<T> {
                 .into_error_with_path(field.pos, ctx.path_node.as_ref())),
         }
     }
+
+    fn field_error(&self) -> Option<FieldError> {
+        self.as_ref().err().cloned()
+    }
+}
+
+impl<T: Type, E: Display + Send + Sync + 'static> Type for std::result::Result<T, E> {
+    fn type_name() -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn qualified_type_name() -> String {
+        T::qualified_type_name()
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        T::create_type_info(registry)
+    }
+}
+
+/// Any error that implements `Display`, such as `anyhow::Error`, is reported with its message. Use
+/// `ErrorExtensions` to convert it into a `FieldResult` with extensions.
+#[async_trait::async_trait]
+impl<T: OutputValueType + Sync, E: Display + Send + Sync + 'static> OutputValueType
+    for std::result::Result<T, E>
+{
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> crate::Result<serde_json::Value> {
+        match self {
+            Ok(value) => Ok(OutputValueType::resolve(value, ctx, field).await?),
+            Err(err) => {
+                Err(FieldError::from(err).into_error_with_path(field.pos, ctx.path_node.as_ref()))
+            }
+        }
+    }
+
+    fn field_error(&self) -> Option<FieldError> {
+        self.as_ref().err().map(FieldError::from)
+    }
 }
@@ -3,8 +3,12 @@ use crate::extensions::Extensions;
 use crate::parser::types::{
     Directive, ExecutableDocumentData, Field, Name, SelectionSet, Value as InputValue,
 };
+use crate::resolver_utils::Deadline;
 use crate::schema::SchemaEnv;
-use crate::{FieldResult, InputValueType, Lookahead, Pos, Positioned, QueryError, Result, Value};
+use crate::types::PendingStream;
+use crate::{
+    Error, FieldResult, InputValueType, Lookahead, Pos, Positioned, QueryError, Result, Value,
+};
 use fnv::FnvHashMap;
 use serde::ser::{SerializeSeq, Serializer};
 use serde::{Deserialize, Serialize};
@@ -12,9 +16,11 @@ use std::any::{Any, TypeId};
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use std::fmt::{self, Debug, Display, Formatter};
+use std::future::Future;
 use std::ops::Deref;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Variables of a query.
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
@@ -54,6 +60,28 @@ impl Variables {
             .unwrap_or_default()
     }
 
+    /// Get the value of a variable, or `None` if it is not set.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.0.get(name)
+    }
+
+    /// Set the value of a variable, and return its previous value.
+    pub fn set(&mut self, name: impl Into<String>, value: Value) -> Option<Value> {
+        self.0.insert(Name::new_unchecked(name.into()), value)
+    }
+
+    /// Remove a variable, and return its value.
+    pub fn remove(&mut self, name: &str) -> Option<Value> {
+        self.0.remove(name)
+    }
+
+    /// Returns `true` if there are no variables.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     /// Get the variables as a GraphQL value.
     #[must_use]
     pub fn into_value(self) -> Value {
@@ -94,6 +122,12 @@ impl Data {
     pub fn insert<D: Any + Send + Sync>(&mut self, data: D) {
         self.0.insert(TypeId::of::<D>(), Box::new(data));
     }
+
+    pub(crate) fn get<D: Any + Send + Sync>(&self) -> Option<&D> {
+        self.0
+            .get(&TypeId::of::<D>())
+            .and_then(|d| d.downcast_ref::<D>())
+    }
 }
 
 impl Debug for Data {
@@ -109,7 +143,7 @@ pub type ContextSelectionSet<'a> = ContextBase<'a, &'a Positioned<SelectionSet>>
 pub type Context<'a> = ContextBase<'a, &'a Positioned<Field>>;
 
 /// The query path segment
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum QueryPathSegment<'a> {
     /// Index
     Index(usize),
@@ -182,7 +216,34 @@ impl<'a> QueryPathNode<'a> {
         }
     }
 
-    pub(crate) fn for_each<F: FnMut(&QueryPathSegment<'a>)>(&self, mut f: F) {
+    /// Get the path segments as strings, from the root to the current node.
+    ///
+    /// List indices are included as numeric segments, e.g. `["users", "2", "posts", "0", "title"]`.
+    pub fn to_string_path(&self) -> Vec<String> {
+        self.iter()
+            .map(|segment| match segment {
+                QueryPathSegment::Index(idx) => idx.to_string(),
+                QueryPathSegment::Name(name) => name.to_string(),
+            })
+            .collect()
+    }
+
+    /// Iterate over the path segments, from the root to the current node.
+    pub fn iter<'b>(&'b self) -> QueryPathIter<'a, 'b> {
+        let mut segments = Vec::new();
+        let mut node = Some(self);
+        while let Some(n) = node {
+            segments.push(&n.segment);
+            node = n.parent;
+        }
+        segments.reverse();
+        QueryPathIter {
+            segments: segments.into_iter(),
+        }
+    }
+
+    /// Call `f` for each path segment, from the root to the current node.
+    pub fn for_each<F: FnMut(&QueryPathSegment<'a>)>(&self, mut f: F) {
         self.for_each_ref(&mut f);
     }
 
@@ -194,6 +255,28 @@ impl<'a> QueryPathNode<'a> {
     }
 }
 
+/// An iterator over the segments of a query path, from the root to the current node.
+pub struct QueryPathIter<'a, 'b> {
+    segments: std::vec::IntoIter<&'b QueryPathSegment<'a>>,
+}
+
+impl<'a, 'b> Iterator for QueryPathIter<'a, 'b> {
+    type Item = &'b QueryPathSegment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.segments.next()
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b QueryPathNode<'a> {
+    type Item = &'b QueryPathSegment<'a>;
+    type IntoIter = QueryPathIter<'a, 'b>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 /// Represents the unique id of the resolve
 #[derive(Copy, Clone, Debug)]
 pub struct ResolveId {
@@ -236,6 +319,8 @@ pub struct ContextBase<'a, T> {
     pub schema_env: &'a SchemaEnv,
     #[doc(hidden)]
     pub query_env: &'a QueryEnv,
+    /// The directives of the fragments that the current selection is in, from the outermost.
+    pub(crate) fragment_directives: Vec<&'a Positioned<Directive>>,
 }
 
 #[doc(hidden)]
@@ -244,6 +329,17 @@ pub struct QueryEnvInner {
     pub variables: Variables,
     pub document: ExecutableDocumentData,
     pub ctx_data: Arc<Data>,
+    pub raw_body: Option<Vec<u8>>,
+    pub guard_cache: spin::Mutex<FnvHashMap<TypeId, Box<dyn Any + Send + Sync>>>,
+    pub cache: spin::Mutex<FnvHashMap<TypeId, Arc<dyn Any + Send + Sync>>>,
+    /// The lists with the `@stream` directive that are waiting to be delivered, `None` unless the
+    /// query is executed incrementally.
+    pub(crate) pending_streams: spin::Mutex<Option<Vec<PendingStream>>>,
+    /// The deadline of the execution, if it has a timeout.
+    pub(crate) deadline: Option<Deadline>,
+    /// The error of the first field that was resolved to `null` instead of failing the query,
+    /// such as a nullable field that timed out. It is returned with the data.
+    pub(crate) field_error: spin::Mutex<Option<Error>>,
 }
 
 #[doc(hidden)]
@@ -265,12 +361,20 @@ impl QueryEnv {
         variables: Variables,
         document: ExecutableDocumentData,
         ctx_data: Arc<Data>,
+        raw_body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
     ) -> QueryEnv {
         QueryEnv(Arc::new(QueryEnvInner {
             extensions,
             variables,
             document,
             ctx_data,
+            raw_body,
+            guard_cache: Default::default(),
+            cache: Default::default(),
+            pending_streams: Default::default(),
+            deadline: timeout.map(Deadline::new),
+            field_error: Default::default(),
         }))
     }
 
@@ -289,6 +393,7 @@ impl QueryEnv {
             item,
             schema_env,
             query_env: self,
+            fragment_directives: Vec::new(),
         }
     }
 }
@@ -320,6 +425,7 @@ impl<'a, T> ContextBase<'a, T> {
             inc_resolve_id: self.inc_resolve_id,
             schema_env: self.schema_env,
             query_env: self.query_env,
+            fragment_directives: self.fragment_directives.clone(),
         }
     }
 
@@ -335,12 +441,41 @@ impl<'a, T> ContextBase<'a, T> {
             inc_resolve_id: &self.inc_resolve_id,
             schema_env: self.schema_env,
             query_env: self.query_env,
+            fragment_directives: Vec::new(),
+        }
+    }
+
+    /// Create a context for the selection set of a fragment in the current selection set.
+    pub(crate) fn with_fragment(
+        &self,
+        selection_set: &'a Positioned<SelectionSet>,
+        directives: impl IntoIterator<Item = &'a Positioned<Directive>>,
+    ) -> ContextBase<'a, &'a Positioned<SelectionSet>> {
+        let mut fragment_directives = self.fragment_directives.clone();
+        fragment_directives.extend(directives);
+        ContextBase {
+            path_node: self.path_node.clone(),
+            item: selection_set,
+            resolve_id: self.resolve_id,
+            inc_resolve_id: &self.inc_resolve_id,
+            schema_env: self.schema_env,
+            query_env: self.query_env,
+            fragment_directives,
         }
     }
 
+    #[doc(hidden)]
+    pub fn serialize_scalar(&self, type_name: &str, value: serde_json::Value) -> serde_json::Value {
+        self.schema_env
+            .on_scalar_serialize
+            .iter()
+            .fold(value, |value, hook| hook(type_name, value))
+    }
+
     /// Gets the global data defined in the `Context` or `Schema`.
     ///
-    /// If both `Schema` and `Query` have the same data type, the data in the `Query` is obtained.
+    /// The data of the request is looked up first, falling back to the data of the `Schema`,
+    /// so if both have the same data type, the data in the request is obtained.
     ///
     /// # Errors
     ///
@@ -361,6 +496,8 @@ impl<'a, T> ContextBase<'a, T> {
     }
 
     /// Gets the global data defined in the `Context` or `Schema` or `None` if the specified type data does not exist.
+    ///
+    /// Like [`data`](#method.data), the data of the request shadows the data of the `Schema`.
     pub fn data_opt<D: Any + Send + Sync>(&self) -> Option<&D> {
         self.query_env
             .ctx_data
@@ -370,6 +507,41 @@ impl<'a, T> ContextBase<'a, T> {
             .and_then(|d| d.downcast_ref::<D>())
     }
 
+    /// Gets the raw body of the HTTP request or `None` if it was not set with `Request::raw_body`.
+    pub fn raw_body(&self) -> Option<&[u8]> {
+        self.query_env.raw_body.as_deref()
+    }
+
+    /// Gets a value that is cached for the duration of the request, computing it with `init` on first use.
+    ///
+    /// Values are identified by their type, so all resolvers of a request share the same value and `init` is called at most once.
+    pub async fn cache_get_or_init<D, F, Fut>(&self, init: F) -> Arc<D>
+    where
+        D: Any + Send + Sync,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = D>,
+    {
+        let cell = self
+            .query_env
+            .cache
+            .lock()
+            .entry(TypeId::of::<D>())
+            .or_insert_with(|| {
+                Arc::new(futures::lock::Mutex::new(None::<Arc<D>>)) as Arc<dyn Any + Send + Sync>
+            })
+            .clone()
+            .downcast::<futures::lock::Mutex<Option<Arc<D>>>>()
+            .unwrap();
+
+        let mut value = cell.lock().await;
+        if let Some(value) = &*value {
+            return value.clone();
+        }
+        let new_value = Arc::new(init().await);
+        *value = Some(new_value.clone());
+        new_value
+    }
+
     fn var_value(&self, name: &str, pos: Pos) -> Result<Value> {
         self.query_env
             .document
@@ -443,11 +615,37 @@ impl<'a, T> ContextBase<'a, T> {
 
         Ok(false)
     }
+
+    /// Get the `initialCount` argument of the `@stream` directive, or `None` if the field is not
+    /// streamed.
+    pub(crate) fn stream_initial_count(
+        &self,
+        directives: &[Positioned<Directive>],
+    ) -> Result<Option<usize>> {
+        let directive = match directives
+            .iter()
+            .find(|directive| directive.node.name.node == "stream")
+        {
+            Some(directive) => directive,
+            None => return Ok(None),
+        };
+
+        let initial_count = match directive.node.get_argument("initialCount") {
+            Some(initial_count) => {
+                let pos = initial_count.pos;
+                let initial_count = self.resolve_input_value(initial_count.clone())?;
+                <i32 as InputValueType>::parse(Some(initial_count))
+                    .map_err(|e| e.into_error(pos, i32::qualified_type_name()))?
+            }
+            None => 0,
+        };
+        Ok(Some(initial_count.max(0) as usize))
+    }
 }
 
-impl<'a> ContextBase<'a, &'a Positioned<SelectionSet>> {
+impl<'a, T: Copy> ContextBase<'a, T> {
     #[doc(hidden)]
-    pub fn with_index(&'a self, idx: usize) -> ContextBase<'a, &'a Positioned<SelectionSet>> {
+    pub fn with_index(&'a self, idx: usize) -> ContextBase<'a, T> {
         ContextBase {
             path_node: Some(QueryPathNode {
                 parent: self.path_node.as_ref(),
@@ -458,6 +656,7 @@ impl<'a> ContextBase<'a, &'a Positioned<SelectionSet>> {
             inc_resolve_id: self.inc_resolve_id,
             schema_env: self.schema_env,
             query_env: self.query_env,
+            fragment_directives: self.fragment_directives.clone(),
         }
     }
 }
@@ -482,6 +681,30 @@ impl<'a> ContextBase<'a, &'a Positioned<Field>> {
         InputValueType::parse(value).map_err(|e| e.into_error(pos, T::qualified_type_name()))
     }
 
+    /// Parse all the arguments of the field as the fields of an input object, which is used for
+    /// flattened arguments.
+    #[doc(hidden)]
+    pub fn flattened_param_value<T: InputValueType>(&self) -> Result<T> {
+        let mut fields = BTreeMap::new();
+        for (name, value) in &self.item.node.arguments {
+            fields.insert(name.node.clone(), self.resolve_input_value(value.clone())?);
+        }
+        InputValueType::parse(Some(Value::Object(fields)))
+            .map_err(|e| e.into_error(self.item.pos, T::qualified_type_name()))
+    }
+
+    #[doc(hidden)]
+    pub fn param_value_or_else<T: InputValueType>(
+        &self,
+        name: &str,
+        default: fn(&Context<'_>) -> T,
+    ) -> Result<T> {
+        match self.param_value::<Option<T>>(name, None)? {
+            Some(value) => Ok(value),
+            None => Ok(default(self)),
+        }
+    }
+
     /// Creates a uniform interface to inspect the forthcoming selections.
     ///
     /// # Examples
@@ -521,4 +744,116 @@ impl<'a> ContextBase<'a, &'a Positioned<Field>> {
     pub fn look_ahead(&self) -> Lookahead {
         Lookahead::new(&self.query_env.document, &self.item.node)
     }
+
+    /// Returns the field that is being resolved, with its name, alias and arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[GQLObject]
+    /// impl Query {
+    ///     async fn echo(&self, ctx: &Context<'_>, _value: i32) -> FieldResult<String> {
+    ///         let field = ctx.field();
+    ///         let (name, value) = field.arguments()?.remove(0);
+    ///         Ok(format!("{} {} {}", field.name(), name, value))
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async move {
+    ///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    ///     let res = schema.execute("{ echo(value: 10) }").await.into_result().unwrap().data;
+    ///     assert_eq!(res, serde_json::json!({ "echo": "echo value 10" }));
+    /// });
+    /// ```
+    pub fn field(&self) -> SelectionField<'_> {
+        SelectionField { ctx: self }
+    }
+}
+
+/// A field that is being resolved, returned by `Context::field`.
+pub struct SelectionField<'a> {
+    ctx: &'a Context<'a>,
+}
+
+impl<'a> SelectionField<'a> {
+    /// The name of the field.
+    pub fn name(&self) -> &'a str {
+        &self.ctx.item.node.name.node
+    }
+
+    /// The alias of the field, or `None` if it has no alias.
+    pub fn alias(&self) -> Option<&'a str> {
+        self.ctx.item.node.alias.as_ref().map(|alias| &*alias.node)
+    }
+
+    /// The arguments of the field in the order of the query, with variables replaced by their
+    /// values.
+    ///
+    /// Only the arguments given in the query are returned, default values are not included.
+    pub fn arguments(&self) -> Result<Vec<(&'a str, Value)>> {
+        self.ctx
+            .item
+            .node
+            .arguments
+            .iter()
+            .map(|(name, value)| Ok((&*name.node, self.ctx.resolve_input_value(value.clone())?)))
+            .collect()
+    }
+
+    /// The directives of the field in the order of the query.
+    pub fn directives(&self) -> impl Iterator<Item = SelectionDirective<'a>> + 'a {
+        let ctx = self.ctx;
+        ctx.item
+            .node
+            .directives
+            .iter()
+            .map(move |directive| SelectionDirective { ctx, directive })
+    }
+
+    /// The directives of the fragments that the field was selected through, from the outermost
+    /// fragment to the innermost one.
+    ///
+    /// For a fragment spread, the directives of the spread come before the directives of the
+    /// fragment definition. Only the fragments in the selection set of the parent field are
+    /// included.
+    pub fn fragment_directives(&self) -> impl Iterator<Item = SelectionDirective<'a>> + 'a {
+        let ctx = self.ctx;
+        ctx.fragment_directives
+            .iter()
+            .map(move |&directive| SelectionDirective { ctx, directive })
+    }
+}
+
+/// A directive in the query, returned by `SelectionField::directives` and
+/// `SelectionField::fragment_directives`.
+pub struct SelectionDirective<'a> {
+    ctx: &'a Context<'a>,
+    directive: &'a Positioned<Directive>,
+}
+
+impl<'a> SelectionDirective<'a> {
+    /// The name of the directive.
+    pub fn name(&self) -> &'a str {
+        &self.directive.node.name.node
+    }
+
+    /// The position of the directive in the query.
+    pub fn position(&self) -> Pos {
+        self.directive.pos
+    }
+
+    /// The arguments of the directive in the order of the query, with variables replaced by their
+    /// values.
+    pub fn arguments(&self) -> Result<Vec<(&'a str, Value)>> {
+        self.directive
+            .node
+            .arguments
+            .iter()
+            .map(|(name, value)| Ok((&*name.node, self.ctx.resolve_input_value(value.clone())?)))
+            .collect()
+    }
 }
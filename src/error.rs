@@ -10,6 +10,15 @@ pub enum InputValueError {
 
     /// The type of input value does not match the expectation. Contains the value that was found.
     ExpectedType(Value),
+
+    /// The value is not an item of the enum.
+    InvalidEnumValue {
+        /// Enum type name
+        ty: String,
+
+        /// Enum value
+        value: String,
+    },
 }
 
 impl<T: Display> From<T> for InputValueError {
@@ -35,6 +44,11 @@ impl InputValueError {
                     actual: value,
                 },
             },
+            InputValueError::InvalidEnumValue { ty, value } => Error::Query {
+                pos,
+                path: None,
+                err: QueryError::InvalidEnumValue { ty, value },
+            },
         }
     }
 }
@@ -107,6 +121,14 @@ pub trait ErrorExtensions: Sized {
 
         FieldError(name, Some(cb(&self)))
     }
+
+    /// Mark the error as retriable, by adding the `retriable: true` extension.
+    ///
+    /// This is a convention for transient errors, such as a lost database connection, so that
+    /// clients know the request can be retried.
+    fn retriable(self) -> FieldError {
+        self.extend_with(|_| serde_json::json!({ "retriable": true }))
+    }
 }
 
 impl ErrorExtensions for FieldError {
@@ -192,10 +214,24 @@ pub enum QueryError {
         object: String,
     },
 
+    /// A field of a non-null type resolved to null.
+    #[error("Cannot return null for non-nullable field {object}.{field_name}.")]
+    NullValueForNonNullField {
+        /// Object name
+        object: String,
+
+        /// Field name
+        field_name: String,
+    },
+
     /// An operation was missing from the query.
     #[error("Missing operation")]
     MissingOperation,
 
+    /// The document contains multiple operations, but no operation name was given.
+    #[error("Must provide operation name if query contains multiple operations")]
+    OperationNameRequired,
+
     /// The operation name was unknown.
     #[error("Unknown operation named \"{name}\"")]
     UnknownOperationNamed {
@@ -219,7 +255,9 @@ pub enum QueryError {
     NotConfiguredSubscriptions,
 
     /// The value does not exist in the enum.
-    #[error("Invalid value for enum \"{ty}\".")]
+    #[error(
+        "Failed to parse input value: Enumeration type \"{ty}\" does not contain the value \"{value}\""
+    )]
     InvalidEnumValue {
         /// Enum type name
         ty: String,
@@ -283,6 +321,21 @@ pub enum QueryError {
     #[error("Too deep")]
     TooDeep,
 
+    /// The query source was too large.
+    #[error("Too large")]
+    TooLarge,
+
+    /// The caller has sent too many requests.
+    #[error("Too many requests")]
+    TooManyRequests,
+
+    /// The connection has too many active subscriptions.
+    #[error("Too many subscriptions, the limit is {max}")]
+    TooManySubscriptions {
+        /// The maximum number of active subscriptions per connection.
+        max: usize,
+    },
+
     /// A field handler errored.
     #[error("Failed to resolve field: {err}")]
     FieldError {
@@ -300,6 +353,17 @@ pub enum QueryError {
     /// "__typename" must be an existing string.
     #[error("\"__typename\" must be an existing string")]
     TypeNameNotExists,
+
+    /// No schema key could be extracted from the request.
+    #[error("Missing schema key")]
+    MissingSchemaKey,
+
+    /// No schema was registered with the key.
+    #[error("Unknown schema \"{key}\"")]
+    UnknownSchema {
+        /// Schema key for request.
+        key: String,
+    },
 }
 
 impl QueryError {
@@ -328,19 +392,36 @@ pub enum ParseRequestError {
     #[error("Invalid files map: {0}")]
     InvalidFilesMap(serde_json::Error),
 
+    /// The query string of a GET request was invalid.
+    #[error("Invalid query string: {0}")]
+    InvalidQueryString(serde_urlencoded::de::Error),
+
+    /// A GET request tried to execute a mutation.
+    #[error("Mutations are not allowed in GET requests")]
+    MutationInGetRequest,
+
+    /// The query of a GET request exceeded the limits that are checked before it is parsed.
+    #[error("Invalid query: {0}")]
+    QueryLimitsExceeded(crate::parser::Error),
+
     /// The request's multipart data was invalid.
     #[error("Invalid multipart data")]
     #[cfg(feature = "multipart")]
     InvalidMultipart(multer::Error),
 
-    /// Missing "operators" part for multipart request.
-    #[error("Missing \"operators\" part")]
-    MissingOperatorsPart,
+    /// Missing "operations" part for multipart request.
+    #[error("Missing \"operations\" part")]
+    MissingOperationsPart,
 
     /// Missing "map" part for multipart request.
     #[error("Missing \"map\" part")]
     MissingMapPart,
 
+    /// A path in the files map of a multipart request doesn't point to a variable, for example
+    /// because a file index is out of range.
+    #[error("Invalid path in the files map: {0}")]
+    InvalidFilesMapPath(String),
+
     /// It's not an upload operation
     #[error("It's not an upload operation")]
     NotUpload,
@@ -374,6 +455,64 @@ pub struct RuleError {
 
     /// A description of this error.
     pub message: String,
+
+    /// Extensions to the error, such as an error code.
+    pub extensions: Option<serde_json::Value>,
+
+    /// The kind of this error, or `None` if it is not one of the kinds of `RuleErrorKind`.
+    pub kind: Option<RuleErrorKind>,
+}
+
+impl RuleError {
+    /// Create a validation error with the `GRAPHQL_VALIDATION_FAILED` code.
+    pub(crate) fn new(locations: Vec<Pos>, message: impl Into<String>) -> Self {
+        Self {
+            locations,
+            message: message.into(),
+            extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+            kind: None,
+        }
+    }
+
+    pub(crate) fn with_kind(self, kind: RuleErrorKind) -> Self {
+        Self {
+            kind: Some(kind),
+            ..self
+        }
+    }
+}
+
+/// The kind of a verification error, which can be matched on instead of its message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RuleErrorKind {
+    /// A field that is not defined on a type was selected.
+    UnknownField {
+        /// Type name
+        object: String,
+
+        /// Field name
+        field_name: String,
+    },
+
+    /// An argument that is not defined on a field was given.
+    UnknownArgument {
+        /// Type name
+        object: String,
+
+        /// Field name
+        field_name: String,
+
+        /// Argument name
+        arg_name: String,
+    },
+
+    /// The value of an argument does not have the expected type, or is rejected by the validator
+    /// of the argument.
+    InvalidArgumentValue {
+        /// Argument name
+        arg_name: String,
+    },
 }
 
 /// An error serving a GraphQL query.
@@ -403,3 +542,32 @@ pub enum Error {
         errors: Vec<RuleError>,
     },
 }
+
+impl Error {
+    /// Replace the parts of the error that are taken from the value of a secret argument with
+    /// `***`.
+    #[doc(hidden)]
+    pub fn redact_secrets(self) -> Self {
+        match self {
+            Error::Query { pos, path, err } => Error::Query {
+                pos,
+                path,
+                err: match err {
+                    QueryError::ExpectedInputType { expect, .. } => QueryError::ExpectedInputType {
+                        expect,
+                        actual: Value::String("***".to_string()),
+                    },
+                    QueryError::ParseInputValue { .. } => QueryError::ParseInputValue {
+                        reason: "***".to_string(),
+                    },
+                    QueryError::InvalidEnumValue { ty, .. } => QueryError::InvalidEnumValue {
+                        ty,
+                        value: "***".to_string(),
+                    },
+                    err => err,
+                },
+            },
+            err => err,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::extensions::Extension;
+use serde_json::Value;
+
+/// Reports the complexity and depth of the query in the response extensions.
+///
+/// It is added by `SchemaBuilder::with_complexity_report` after the query has been validated.
+pub(crate) struct ComplexityReport {
+    pub(crate) complexity: usize,
+    pub(crate) depth: usize,
+}
+
+impl Extension for ComplexityReport {
+    fn name(&self) -> Option<&'static str> {
+        Some("complexityReport")
+    }
+
+    fn result(&mut self) -> Option<Value> {
+        Some(serde_json::json!({
+            "complexity": self.complexity,
+            "depth": self.depth,
+        }))
+    }
+}
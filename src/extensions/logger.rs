@@ -7,11 +7,16 @@ use std::borrow::Cow;
 use uuid::Uuid;
 
 /// Logger extension
+///
+/// The values of secret arguments are replaced with `***` in the logged queries and variables.
+/// Errors are logged as they are returned, so resolvers must not put secret values in them.
+/// The secret values are only known after the parse, so parse errors are logged without the query
+/// and the variables.
 pub struct Logger {
     id: Uuid,
     enabled: bool,
     query: String,
-    variables: Variables,
+    variables: String,
 }
 
 impl Default for Logger {
@@ -20,7 +25,7 @@ impl Default for Logger {
             id: Uuid::new_v4(),
             enabled: true,
             query: String::new(),
-            variables: Default::default(),
+            variables: String::new(),
         }
     }
 }
@@ -28,7 +33,12 @@ impl Default for Logger {
 impl Extension for Logger {
     fn parse_start(&mut self, query_source: &str, variables: &Variables) {
         self.query = query_source.replace(char::is_whitespace, "");
-        self.variables = variables.clone();
+        self.variables = variables.to_string();
+    }
+
+    fn redacted_query(&mut self, query_source: &str, variables: &Variables) {
+        self.query = query_source.replace(char::is_whitespace, "");
+        self.variables = variables.to_string();
     }
 
     fn parse_end(&mut self, document: &ExecutableDocument) {
@@ -66,7 +76,7 @@ impl Extension for Logger {
     fn error(&mut self, err: &Error) {
         match err {
             Error::Parse(err) => {
-                error!(target: "async-graphql", "[ParseError] id: \"{}\", pos: [{}:{}], query: \"{}\", variables: {}, {}", self.id, err.pos.line, err.pos.column, self.query, self.variables, err)
+                error!(target: "async-graphql", "[ParseError] id: \"{}\", pos: [{}:{}], {}", self.id, err.pos.line, err.pos.column, err)
             }
             Error::Query { pos, path, err } => {
                 if let Some(path) = path {
@@ -2,23 +2,28 @@
 
 #[cfg(feature = "apollo_tracing")]
 mod apollo_tracing;
+mod complexity_report;
 #[cfg(feature = "log")]
 mod logger;
+mod rate_limit;
 #[cfg(feature = "tracing")]
 mod tracing;
 
 use crate::context::{QueryPathNode, ResolveId};
-use crate::{Context, Result, Variables};
+use crate::{Context, Data, Request, Response, Result, Variables};
 
 #[cfg(feature = "apollo_tracing")]
 pub use self::apollo_tracing::ApolloTracing;
+pub(crate) use self::complexity_report::ComplexityReport;
 #[cfg(feature = "log")]
 pub use self::logger::Logger;
+pub use self::rate_limit::{RateLimit, RateLimiter};
 #[cfg(feature = "tracing")]
 pub use self::tracing::Tracing;
 use crate::parser::types::ExecutableDocument;
 use crate::Error;
 use serde_json::Value;
+use std::any::Any;
 
 pub(crate) type BoxExtension = Box<dyn Extension>;
 
@@ -44,6 +49,26 @@ pub struct ResolveInfo<'a> {
     pub return_type: &'a str,
 }
 
+/// Parameters for `Extension::prepare_request`
+pub struct PrepareRequestInfo<'a> {
+    /// The name of the operation that will be executed, `None` for an anonymous operation.
+    pub operation_name: Option<&'a str>,
+
+    pub(crate) request_data: &'a Data,
+    pub(crate) schema_data: &'a Data,
+}
+
+impl<'a> PrepareRequestInfo<'a> {
+    /// Gets the data of the request or `Schema`, or `None` if the specified type data does not exist.
+    ///
+    /// Like `Context::data`, the data of the request shadows the data of the `Schema`.
+    pub fn data_opt<D: Any + Send + Sync>(&self) -> Option<&'a D> {
+        self.request_data
+            .get::<D>()
+            .or_else(|| self.schema_data.get::<D>())
+    }
+}
+
 /// Represents a GraphQL extension
 #[allow(unused_variables)]
 pub trait Extension: Sync + Send + 'static {
@@ -52,9 +77,30 @@ pub trait Extension: Sync + Send + 'static {
         None
     }
 
+    /// Called before the parse, to look up a response that was stored for the request.
+    ///
+    /// Returning a response skips the parse, validation and execution, and the response is returned
+    /// instead. The other hooks of the extensions are not called for the request, but the
+    /// `SchemaBuilder::on_response` hooks are.
+    fn cached_response(&mut self, request: &Request) -> Option<Response> {
+        None
+    }
+
+    /// Called before the parse, to modify the variables of the request.
+    ///
+    /// This can add variables that are not sent by the clients, and the validation and execution
+    /// see the modified variables.
+    fn prepare_variables(&mut self, variables: &mut Variables) {}
+
     /// Called at the begin of the parse.
     fn parse_start(&mut self, query_source: &str, variables: &Variables) {}
 
+    /// Called after the parse if the query has arguments that are marked as secret, with the query
+    /// source and the variables in which their values are replaced with `***`.
+    ///
+    /// Extensions that log the query must log these instead of the ones passed to `parse_start`.
+    fn redacted_query(&mut self, query_source: &str, variables: &Variables) {}
+
     /// Called at the end of the parse.
     fn parse_end(&mut self, document: &ExecutableDocument) {}
 
@@ -64,12 +110,29 @@ pub trait Extension: Sync + Send + 'static {
     /// Called at the end of the validation.
     fn validation_end(&mut self) {}
 
+    /// Called after the operation to execute has been selected, before the execution.
+    ///
+    /// Returning an error rejects the request, and the error is returned as the response.
+    fn prepare_request(&mut self, info: &PrepareRequestInfo<'_>) -> Result<()> {
+        Ok(())
+    }
+
     /// Called at the begin of the execution.
     fn execution_start(&mut self) {}
 
     /// Called at the end of the execution.
     fn execution_end(&mut self) {}
 
+    /// Called when a subscription has started, before its first response.
+    fn subscription_start(&mut self) {}
+
+    /// Called with each response of a subscription, before it is sent.
+    fn subscription_next(&mut self, response: &Response) {}
+
+    /// Called when a subscription has ended, because its streams have ended, it failed or the
+    /// client dropped it.
+    fn subscription_end(&mut self) {}
+
     /// Called at the begin of the resolve field.
     fn resolve_start(&mut self, info: &ResolveInfo<'_>) {}
 
@@ -99,12 +162,28 @@ impl<T> ErrorLogger for Result<T> {
 }
 
 impl Extension for Extensions {
+    fn cached_response(&mut self, request: &Request) -> Option<Response> {
+        self.0.iter_mut().find_map(|e| e.cached_response(request))
+    }
+
+    fn prepare_variables(&mut self, variables: &mut Variables) {
+        self.0
+            .iter_mut()
+            .for_each(|e| e.prepare_variables(variables));
+    }
+
     fn parse_start(&mut self, query_source: &str, variables: &Variables) {
         self.0
             .iter_mut()
             .for_each(|e| e.parse_start(query_source, variables));
     }
 
+    fn redacted_query(&mut self, query_source: &str, variables: &Variables) {
+        self.0
+            .iter_mut()
+            .for_each(|e| e.redacted_query(query_source, variables));
+    }
+
     fn parse_end(&mut self, document: &ExecutableDocument) {
         self.0.iter_mut().for_each(|e| e.parse_end(document));
     }
@@ -117,6 +196,10 @@ impl Extension for Extensions {
         self.0.iter_mut().for_each(|e| e.validation_end());
     }
 
+    fn prepare_request(&mut self, info: &PrepareRequestInfo<'_>) -> Result<()> {
+        self.0.iter_mut().try_for_each(|e| e.prepare_request(info))
+    }
+
     fn execution_start(&mut self) {
         self.0.iter_mut().for_each(|e| e.execution_start());
     }
@@ -125,6 +208,20 @@ impl Extension for Extensions {
         self.0.iter_mut().for_each(|e| e.execution_end());
     }
 
+    fn subscription_start(&mut self) {
+        self.0.iter_mut().for_each(|e| e.subscription_start());
+    }
+
+    fn subscription_next(&mut self, response: &Response) {
+        self.0
+            .iter_mut()
+            .for_each(|e| e.subscription_next(response));
+    }
+
+    fn subscription_end(&mut self) {
+        self.0.iter_mut().for_each(|e| e.subscription_end());
+    }
+
     fn resolve_start(&mut self, info: &ResolveInfo<'_>) {
         self.0.iter_mut().for_each(|e| e.resolve_start(info));
     }
@@ -0,0 +1,53 @@
+use crate::extensions::{Extension, PrepareRequestInfo};
+use crate::{Pos, QueryError, Result};
+use std::any::Any;
+use std::sync::Arc;
+
+/// A rate limiter for the [`RateLimit`](struct.RateLimit.html) extension.
+pub trait RateLimiter: Send + Sync + 'static {
+    /// The type of the caller identifier, it is taken from the data of the request or `Schema`.
+    type Key: Any + Send + Sync;
+
+    /// Records a request and returns `false` if the caller has exceeded its limit for the operation.
+    ///
+    /// `key` is `None` if the caller identifier is not in the data, and `operation_name` is `None`
+    /// for an anonymous operation.
+    fn check(&self, key: Option<&Self::Key>, operation_name: Option<&str>) -> bool;
+}
+
+/// Rate limit extension
+///
+/// Consults a [`RateLimiter`](trait.RateLimiter.html) before the execution, and rejects the
+/// request with `QueryError::TooManyRequests` if the caller is over the limit.
+///
+/// Extensions are created for every request, so the limiter is shared with an `Arc`:
+///
+/// ```ignore
+/// let limiter = Arc::new(MyLimiter::default());
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .extension(move || RateLimit::new(limiter.clone()))
+///     .finish();
+/// ```
+pub struct RateLimit<T> {
+    limiter: Arc<T>,
+}
+
+impl<T: RateLimiter> RateLimit<T> {
+    /// Create a rate limit extension with a shared limiter.
+    pub fn new(limiter: Arc<T>) -> Self {
+        Self { limiter }
+    }
+}
+
+impl<T: RateLimiter> Extension for RateLimit<T> {
+    fn prepare_request(&mut self, info: &PrepareRequestInfo<'_>) -> Result<()> {
+        if self
+            .limiter
+            .check(info.data_opt::<T::Key>(), info.operation_name)
+        {
+            Ok(())
+        } else {
+            Err(QueryError::TooManyRequests.into_error(Pos::default()))
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::extensions::{Extension, ResolveInfo};
+use crate::parser::types::ExecutableDocument;
 use crate::Variables;
 use std::collections::BTreeMap;
 use tracing::{event, span, Id, Level};
@@ -6,6 +7,9 @@ use uuid::Uuid;
 
 /// Tracing extension
 ///
+/// The query and the variables are logged after the parse, with the values of secret arguments
+/// replaced with `***`. They are not logged if the query can't be parsed.
+///
 /// # References
 ///
 /// https://crates.io/crates/tracing
@@ -13,6 +17,8 @@ use uuid::Uuid;
 pub struct Tracing {
     root_id: Option<Id>,
     fields: BTreeMap<usize, Id>,
+    query: String,
+    variables: String,
 }
 
 impl Extension for Tracing {
@@ -31,11 +37,23 @@ impl Extension for Tracing {
             self.root_id.replace(id);
         }
 
+        // The query may have secret values, which are only known after the parse.
+        self.query = query_source.to_string();
+        self.variables = variables.to_string();
+    }
+
+    fn redacted_query(&mut self, query_source: &str, variables: &Variables) {
+        self.query = query_source.to_string();
+        self.variables = variables.to_string();
+    }
+
+    #[allow(clippy::deref_addrof)]
+    fn parse_end(&mut self, _document: &ExecutableDocument) {
         event!(
             target: "async_graphql::query",
             Level::DEBUG,
-            %variables,
-            query = %query_source
+            variables = %self.variables,
+            query = %self.query
         );
     }
 
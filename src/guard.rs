@@ -2,11 +2,20 @@
 
 use crate::{Context, FieldResult};
 use serde::export::PhantomData;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
 
 /// Field guard
 ///
 /// Guard is a pre-condition for a field that is resolved if `Ok(())` is returned, otherwise an error is returned.
 ///
+/// A field can have several guards, e.g. `#[field(guard(RoleGuard(...)), guard(UserGuard(...)))]`.
+/// They are checked in declaration order and the first error is returned. The error can have
+/// extensions, such as an error code added with `ErrorExtensions::extend_with`, which are included
+/// in the response.
+///
 /// This trait is defined through the [`async-trait`](https://crates.io/crates/async-trait) macro.
 #[async_trait::async_trait]
 pub trait Guard {
@@ -20,6 +29,14 @@ pub trait GuardExt: Guard + Sized {
     fn and<R: Guard>(self, other: R) -> And<Self, R> {
         And(self, other)
     }
+
+    /// Cache the result of the guard for the duration of a request.
+    fn cached(self) -> Cached<Self>
+    where
+        Self: Clone + Eq + Hash,
+    {
+        Cached(self)
+    }
 }
 
 impl<T: Guard> GuardExt for T {}
@@ -35,6 +52,80 @@ impl<A: Guard + Send + Sync, B: Guard + Send + Sync> Guard for And<A, B> {
     }
 }
 
+/// A guard that only allows access to the field if the data of type `T` is present in the
+/// context, e.g. the current user.
+///
+/// It can be used in the `guard` attribute as an expression, e.g.
+/// `#[field(guard("DataPresent::<CurrentUser>::with_message(\"Unauthorized\")"))]`.
+pub struct DataPresent<T> {
+    message: String,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> DataPresent<T> {
+    /// Create a guard that returns the error `Forbidden`.
+    pub fn new() -> Self {
+        Self::with_message("Forbidden")
+    }
+
+    /// Create a guard that returns an error with the given message.
+    pub fn with_message(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for DataPresent<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: Any + Send + Sync> Guard for DataPresent<T> {
+    async fn check(&self, ctx: &Context<'_>) -> FieldResult<()> {
+        match ctx.data_opt::<T>() {
+            Some(_) => Ok(()),
+            None => Err(self.message.clone().into()),
+        }
+    }
+}
+
+type GuardCache<G> = HashMap<G, Arc<futures::lock::Mutex<Option<FieldResult<()>>>>>;
+
+/// Guard for [`GuardExt::cached`](trait.GuardExt.html#method.cached).
+///
+/// Equal guards of the same type are only checked once per request, subsequent checks reuse the first result.
+/// In the `guard` attribute, wrap a guard with `cached(...)`, e.g. `#[field(guard(cached(RoleGuard(role = "Role::Admin"))))]`.
+pub struct Cached<G: Guard>(G);
+
+#[async_trait::async_trait]
+impl<G: Guard + Clone + Eq + Hash + Send + Sync + 'static> Guard for Cached<G> {
+    async fn check(&self, ctx: &Context<'_>) -> FieldResult<()> {
+        let cell = ctx
+            .query_env
+            .guard_cache
+            .lock()
+            .entry(TypeId::of::<G>())
+            .or_insert_with(|| Box::new(GuardCache::<G>::new()) as Box<dyn Any + Send + Sync>)
+            .downcast_mut::<GuardCache<G>>()
+            .unwrap()
+            .entry(self.0.clone())
+            .or_default()
+            .clone();
+
+        let mut cached_result = cell.lock().await;
+        if let Some(result) = &*cached_result {
+            return result.clone();
+        }
+        let result = self.0.check(ctx).await;
+        *cached_result = Some(result.clone());
+        result
+    }
+}
+
 /// Field post guard
 ///
 /// This is a post-condition for a field that is resolved if `Ok(()` is returned, otherwise an error is returned.
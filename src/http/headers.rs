@@ -0,0 +1,79 @@
+use std::iter::FromIterator;
+
+/// The headers of an HTTP request.
+///
+/// Integrations add this to the request data, so resolvers can read the headers with
+/// `ctx.data::<HttpHeaders>()`. Header names are case-insensitive.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::http::HttpHeaders;
+///
+/// struct QueryRoot;
+///
+/// #[GQLObject]
+/// impl QueryRoot {
+///     async fn token(&self, ctx: &Context<'_>) -> FieldResult<Option<String>> {
+///         Ok(ctx.data::<HttpHeaders>()?.get("authorization").map(ToString::to_string))
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let headers: HttpHeaders = vec![("Authorization", "Bearer abc")].into_iter().collect();
+///     let res = schema.execute(Request::new("{ token }").data(headers)).await;
+///     assert_eq!(res.into_result().unwrap().data, serde_json::json!({ "token": "Bearer abc" }));
+/// });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HttpHeaders(Vec<(String, String)>);
+
+impl HttpHeaders {
+    /// Create an empty set of headers.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a header. Existing headers with the same name are kept.
+    pub fn insert(&mut self, name: impl AsRef<str>, value: impl Into<String>) {
+        self.0
+            .push((name.as_ref().to_ascii_lowercase(), value.into()));
+    }
+
+    /// Get the first value of a header.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.get_all(name).next()
+    }
+
+    /// Get all the values of a header.
+    pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.0
+            .iter()
+            .filter(move |(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns `true` if the header is present.
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Iterate over all the headers. Names are in lowercase.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+impl<K: AsRef<str>, V: Into<String>> FromIterator<(K, V)> for HttpHeaders {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut headers = HttpHeaders::new();
+        for (name, value) in iter {
+            headers.insert(name, value);
+        }
+        headers
+    }
+}
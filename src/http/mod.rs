@@ -1,20 +1,25 @@
 //! A helper module that supports HTTP
 
 mod graphiql_source;
+mod headers;
 #[cfg(feature = "multipart")]
 mod multipart;
 mod playground_source;
 mod websocket;
 
 pub use graphiql_source::graphiql_source;
+pub use headers::HttpHeaders;
 #[cfg(feature = "multipart")]
 pub use multipart::{receive_multipart, MultipartOptions};
 pub use playground_source::{playground_source, GraphQLPlaygroundConfig};
-pub use websocket::WebSocketStream;
+pub use websocket::{Protocols, WebSocketStream};
 
-use crate::{ParseRequestError, Request};
+use crate::parser::types::OperationType;
+use crate::parser::{parse_query, ParseLimits};
+use crate::{ParseRequestError, Request, Variables};
 use futures::io::AsyncRead;
 use futures::AsyncReadExt;
+use serde::Deserialize;
 
 /// Receive a GraphQL request from a content type and body.
 ///
@@ -44,3 +49,58 @@ pub async fn receive_json(
         .map_err(ParseRequestError::Io)?;
     Ok(serde_json::from_slice::<Request>(&data).map_err(ParseRequestError::InvalidRequest)?)
 }
+
+/// The limits of the query of a GET request, which is parsed before the limits of the schema are
+/// applied.
+const GET_QUERY_LIMITS: ParseLimits = ParseLimits {
+    max_value_depth: Some(64),
+    max_tokens: None,
+};
+
+/// Parse a GraphQL request from the query string of a GET request.
+///
+/// The `query`, `operationName` and `variables` parameters are read, and `variables` is decoded
+/// as JSON. Since GET requests must not have side effects, requests for a mutation are rejected
+/// with `ParseRequestError::MutationInGetRequest`.
+///
+/// To find the type of the operation, the query is parsed here, before the limits of the schema
+/// are checked. Queries with values nested more than 64 levels deep are rejected with
+/// `ParseRequestError::QueryLimitsExceeded`, because they could overflow the stack of the parser.
+///
+/// **Reference:** <https://github.com/graphql/graphql-over-http>
+pub fn parse_query_string(query_string: &str) -> Result<Request, ParseRequestError> {
+    #[derive(Deserialize)]
+    struct GetRequest {
+        query: String,
+        #[serde(rename = "operationName")]
+        operation_name: Option<String>,
+        variables: Option<String>,
+    }
+
+    let get_request = serde_urlencoded::from_str::<GetRequest>(query_string)
+        .map_err(ParseRequestError::InvalidQueryString)?;
+
+    GET_QUERY_LIMITS
+        .check(&get_request.query)
+        .map_err(ParseRequestError::QueryLimitsExceeded)?;
+
+    // Documents that fail to parse are passed on, so the error is reported by the schema.
+    let is_mutation = parse_query(&get_request.query)
+        .ok()
+        .and_then(|document| document.into_data(get_request.operation_name.as_deref()))
+        .map_or(false, |document| {
+            document.operation.node.ty == OperationType::Mutation
+        });
+    if is_mutation {
+        return Err(ParseRequestError::MutationInGetRequest);
+    }
+
+    let mut request = Request::new(get_request.query);
+    request.operation_name = get_request.operation_name;
+    if let Some(variables) = get_request.variables {
+        request = request.variables(Variables::from_json(
+            serde_json::from_str(&variables).map_err(ParseRequestError::InvalidRequest)?,
+        ));
+    }
+    Ok(request)
+}
@@ -85,8 +85,8 @@ pub async fn receive_multipart(
                     if let Some(filename) = field.file_name().map(ToString::to_string) {
                         let content_type = field.content_type().map(|mime| mime.to_string());
                         let mut file = tempfile::tempfile().map_err(ParseRequestError::Io)?;
-                        while let Some(chunk) = field.chunk().await.unwrap() {
-                            file.write(&chunk).map_err(ParseRequestError::Io)?;
+                        while let Some(chunk) = field.chunk().await? {
+                            file.write_all(&chunk).map_err(ParseRequestError::Io)?;
                         }
                         file.seek(SeekFrom::Start(0))?;
                         files.push((name, filename, content_type, file));
@@ -96,12 +96,15 @@ pub async fn receive_multipart(
         }
     }
 
-    let mut request: Request = request.ok_or(ParseRequestError::MissingOperatorsPart)?;
+    let mut request: Request = request.ok_or(ParseRequestError::MissingOperationsPart)?;
     let map = map.as_mut().ok_or(ParseRequestError::MissingMapPart)?;
 
     for (name, filename, content_type, file) in files {
         if let Some(var_paths) = map.remove(&name) {
             for var_path in var_paths {
+                if request.variables.variable_path(&var_path).is_none() {
+                    return Err(ParseRequestError::InvalidFilesMapPath(var_path));
+                }
                 request.set_upload(
                     &var_path,
                     filename.clone(),
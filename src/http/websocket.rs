@@ -1,13 +1,15 @@
 //! WebSocket transport for subscription
 
 use crate::resolver_utils::ObjectType;
-use crate::{Data, FieldResult, Request, Response, Schema, SubscriptionType};
+use crate::{Data, FieldResult, QueryError, Request, Response, Schema, SubscriptionType};
 use futures::channel::mpsc;
+use futures::future::BoxFuture;
 use futures::task::{Context, Poll};
-use futures::{Future, Sink, SinkExt, Stream, StreamExt};
+use futures::{Future, FutureExt, Sink, SinkExt, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
 use std::pin::Pin;
+use std::str::FromStr;
 use std::sync::Arc;
 
 #[derive(Serialize, Deserialize)]
@@ -22,11 +24,45 @@ struct OperationMessage<'a, T> {
     payload: Option<T>,
 }
 
-type SubscriptionStreams = HashMap<String, Pin<Box<dyn Stream<Item = Response> + Send>>>;
+/// The responses of the operations, with whether they are errors that end the operation.
+type SubscriptionStreams = HashMap<String, Pin<Box<dyn Stream<Item = (Response, bool)> + Send>>>;
 
 type HandleRequestBoxFut = Pin<Box<dyn Future<Output = FieldResult<WSContext>> + Send>>;
 
-type InitializerFn = Arc<dyn Fn(serde_json::Value) -> FieldResult<Data> + Send + Sync>;
+type InitializerFn =
+    Arc<dyn Fn(serde_json::Value) -> BoxFuture<'static, FieldResult<Data>> + Send + Sync>;
+
+/// The GraphQL over WebSocket protocol spoken by a [`WebSocketStream`](struct.WebSocketStream.html).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Protocols {
+    /// The legacy [subscriptions-transport-ws](https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md) protocol.
+    SubscriptionsTransportWS,
+    /// The [graphql-transport-ws](https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md) protocol used by `graphql-ws`.
+    GraphQLTransportWS,
+}
+
+impl Protocols {
+    /// Returns the value of the `Sec-WebSocket-Protocol` header for this protocol.
+    pub fn sec_websocket_protocol(&self) -> &'static str {
+        match self {
+            Protocols::SubscriptionsTransportWS => "graphql-ws",
+            Protocols::GraphQLTransportWS => "graphql-transport-ws",
+        }
+    }
+}
+
+impl FromStr for Protocols {
+    type Err = String;
+
+    /// Parses the subprotocol negotiated with the `Sec-WebSocket-Protocol` header.
+    fn from_str(protocol: &str) -> Result<Self, Self::Err> {
+        match protocol {
+            "graphql-ws" => Ok(Protocols::SubscriptionsTransportWS),
+            "graphql-transport-ws" => Ok(Protocols::GraphQLTransportWS),
+            _ => Err(format!("Unsupported websocket protocol \"{}\"", protocol)),
+        }
+    }
+}
 
 /// A wrapper around an underlying raw stream which implements the WebSocket protocol.
 ///
@@ -66,7 +102,7 @@ impl Stream for WebSocketStream {
 }
 
 impl WebSocketStream {
-    /// Create a websocket transport.
+    /// Create a websocket transport using the `subscriptions-transport-ws` protocol.
     pub fn new<Query, Mutation, Subscription>(
         schema: &Schema<Query, Mutation, Subscription>,
     ) -> Self
@@ -75,31 +111,96 @@ impl WebSocketStream {
         Mutation: ObjectType + Send + Sync + 'static,
         Subscription: SubscriptionType + Send + Sync + 'static,
     {
-        Self::new_with_initializer(schema, |_| Ok(Default::default()))
+        Self::new_with_initializer(schema, |_| futures::future::ready(Ok(Default::default())))
+    }
+
+    /// Create a websocket transport using the `subscriptions-transport-ws` protocol and specify
+    /// a context initialization function.
+    ///
+    /// The initializer is called with the payload of the `connection_init` message, and the data
+    /// it returns is added to the context of every subscription on the connection. If it fails,
+    /// a `connection_error` message with the error is sent and the connection is closed. The
+    /// `graphql-transport-ws` protocol has no such message, so there the connection is just closed.
+    pub fn new_with_initializer<Query, Mutation, Subscription, F, R>(
+        schema: &Schema<Query, Mutation, Subscription>,
+        initializer: F,
+    ) -> Self
+    where
+        Query: ObjectType + Send + Sync + 'static,
+        Mutation: ObjectType + Send + Sync + 'static,
+        Subscription: SubscriptionType + Send + Sync + 'static,
+        F: Fn(serde_json::Value) -> R + Send + Sync + 'static,
+        R: Future<Output = FieldResult<Data>> + Send + 'static,
+    {
+        Self::new_with_protocol(schema, Protocols::SubscriptionsTransportWS, initializer)
+    }
+
+    /// Create a websocket transport for the negotiated protocol and specify a context
+    /// initialization function.
+    pub fn new_with_protocol<Query, Mutation, Subscription, F, R>(
+        schema: &Schema<Query, Mutation, Subscription>,
+        protocol: Protocols,
+        initializer: F,
+    ) -> Self
+    where
+        Query: ObjectType + Send + Sync + 'static,
+        Mutation: ObjectType + Send + Sync + 'static,
+        Subscription: SubscriptionType + Send + Sync + 'static,
+        F: Fn(serde_json::Value) -> R + Send + Sync + 'static,
+        R: Future<Output = FieldResult<Data>> + Send + 'static,
+    {
+        Self::create(schema, protocol, None, initializer)
+    }
+
+    /// Create a websocket transport for the negotiated protocol that allows at most
+    /// `max_subscriptions` active subscriptions, and specify a context initialization function.
+    ///
+    /// Starting another subscription sends an error message with its id, and the connection stays
+    /// open.
+    pub fn new_with_max_subscriptions<Query, Mutation, Subscription, F, R>(
+        schema: &Schema<Query, Mutation, Subscription>,
+        protocol: Protocols,
+        max_subscriptions: usize,
+        initializer: F,
+    ) -> Self
+    where
+        Query: ObjectType + Send + Sync + 'static,
+        Mutation: ObjectType + Send + Sync + 'static,
+        Subscription: SubscriptionType + Send + Sync + 'static,
+        F: Fn(serde_json::Value) -> R + Send + Sync + 'static,
+        R: Future<Output = FieldResult<Data>> + Send + 'static,
+    {
+        Self::create(schema, protocol, Some(max_subscriptions), initializer)
     }
 
-    /// Create a websocket transport and specify a context initialization function.
-    pub fn new_with_initializer<Query, Mutation, Subscription>(
+    fn create<Query, Mutation, Subscription, F, R>(
         schema: &Schema<Query, Mutation, Subscription>,
-        initializer: impl Fn(serde_json::Value) -> FieldResult<Data> + Send + Sync + 'static,
+        protocol: Protocols,
+        max_subscriptions: Option<usize>,
+        initializer: F,
     ) -> Self
     where
         Query: ObjectType + Send + Sync + 'static,
         Mutation: ObjectType + Send + Sync + 'static,
         Subscription: SubscriptionType + Send + Sync + 'static,
+        F: Fn(serde_json::Value) -> R + Send + Sync + 'static,
+        R: Future<Output = FieldResult<Data>> + Send + 'static,
     {
         let (tx, rx) = mpsc::unbounded();
         WebSocketStream {
             tx,
             rx: SubscriptionStream {
                 schema: schema.clone(),
-                initializer: Arc::new(initializer),
+                protocol,
+                initializer: Arc::new(move |value| initializer(value).boxed()),
                 rx_bytes: rx,
                 handle_request_fut: None,
                 ctx: Some(WSContext {
                     streams: Default::default(),
                     send_buf: Default::default(),
                     ctx_data: Arc::new(Data::default()),
+                    max_subscriptions,
+                    closed: false,
                 }),
             }
             .boxed(),
@@ -111,6 +212,9 @@ struct WSContext {
     streams: SubscriptionStreams,
     send_buf: VecDeque<String>,
     ctx_data: Arc<Data>,
+    max_subscriptions: Option<usize>,
+    /// The connection is closed once the pending messages are sent.
+    closed: bool,
 }
 
 fn send_message<T: Serialize>(send_buf: &mut VecDeque<String>, msg: &T) {
@@ -123,6 +227,7 @@ fn send_message<T: Serialize>(send_buf: &mut VecDeque<String>, msg: &T) {
 #[allow(clippy::type_complexity)]
 struct SubscriptionStream<Query, Mutation, Subscription> {
     schema: Schema<Query, Mutation, Subscription>,
+    protocol: Protocols,
     initializer: InitializerFn,
     rx_bytes: mpsc::UnboundedReceiver<String>,
     handle_request_fut: Option<HandleRequestBoxFut>,
@@ -146,6 +251,9 @@ where
                 if let Some(bytes) = ctx.send_buf.pop_front() {
                     return Poll::Ready(Some(bytes));
                 }
+                if ctx.closed {
+                    return Poll::Ready(None);
+                }
             }
 
             if let Some(handle_request_fut) = &mut this.handle_request_fut {
@@ -164,6 +272,7 @@ where
                         let ctx = this.ctx.take().unwrap();
                         this.handle_request_fut = Some(Box::pin(handle_request(
                             this.schema.clone(),
+                            this.protocol,
                             this.initializer.clone(),
                             ctx,
                             data,
@@ -181,8 +290,8 @@ where
 
                 for (id, incoming_stream) in ctx.streams.iter_mut() {
                     match incoming_stream.as_mut().poll_next(cx) {
-                        Poll::Ready(Some(res)) => {
-                            if let Some(err) = &res.error {
+                        Poll::Ready(Some((res, is_end))) => {
+                            if let (true, Some(err)) = (is_end, &res.error) {
                                 closed.push(id.to_string());
                                 send_message(
                                     &mut ctx.send_buf,
@@ -196,7 +305,10 @@ where
                                 send_message(
                                     &mut ctx.send_buf,
                                     &OperationMessage {
-                                        ty: "data",
+                                        ty: match this.protocol {
+                                            Protocols::SubscriptionsTransportWS => "data",
+                                            Protocols::GraphQLTransportWS => "next",
+                                        },
                                         id: Some(id.to_string()),
                                         payload: Some(&res),
                                     },
@@ -234,6 +346,7 @@ where
 
 async fn handle_request<Query, Mutation, Subscription>(
     schema: Schema<Query, Mutation, Subscription>,
+    protocol: Protocols,
     initializer: InitializerFn,
     mut ctx: WSContext,
     data: String,
@@ -244,10 +357,30 @@ where
     Subscription: SubscriptionType + Send + Sync + 'static,
 {
     match serde_json::from_str::<OperationMessage<serde_json::Value>>(&data) {
-        Ok(msg) => match msg.ty {
-            "connection_init" => {
+        Ok(msg) => match (protocol, msg.ty) {
+            (_, "connection_init") => {
                 if let Some(payload) = msg.payload {
-                    ctx.ctx_data = Arc::new(initializer(payload)?);
+                    match initializer(payload).await {
+                        Ok(data) => ctx.ctx_data = Arc::new(data),
+                        Err(err) => {
+                            if protocol == Protocols::SubscriptionsTransportWS {
+                                let mut payload = serde_json::json!({ "message": err.0 });
+                                if let Some(extensions) = err.1 {
+                                    payload["extensions"] = extensions;
+                                }
+                                send_message(
+                                    &mut ctx.send_buf,
+                                    &OperationMessage {
+                                        ty: "connection_error",
+                                        id: None,
+                                        payload: Some(payload),
+                                    },
+                                );
+                            }
+                            ctx.closed = true;
+                            return Ok(ctx);
+                        }
+                    }
                 }
                 send_message(
                     &mut ctx.send_buf,
@@ -258,8 +391,26 @@ where
                     },
                 );
             }
-            "start" => {
+            (Protocols::SubscriptionsTransportWS, "start")
+            | (Protocols::GraphQLTransportWS, "subscribe") => {
                 if let (Some(id), Some(payload)) = (msg.id, msg.payload) {
+                    if let Some(max) = ctx.max_subscriptions {
+                        if ctx.streams.len() >= max && !ctx.streams.contains_key(&id) {
+                            send_message(
+                                &mut ctx.send_buf,
+                                &OperationMessage {
+                                    ty: "error",
+                                    id: Some(id),
+                                    // The error is not caused by the query, so it has no locations.
+                                    payload: Some(serde_json::json!([{
+                                        "message": QueryError::TooManySubscriptions { max }
+                                            .to_string(),
+                                    }])),
+                                },
+                            );
+                            return Ok(ctx);
+                        }
+                    }
                     if let Ok(request) = serde_json::from_value::<Request>(payload) {
                         let stream = schema
                             .execute_stream_with_ctx_data(request, ctx.ctx_data.clone())
@@ -268,7 +419,7 @@ where
                     }
                 }
             }
-            "stop" => {
+            (Protocols::SubscriptionsTransportWS, "stop") => {
                 if let Some(id) = msg.id {
                     if ctx.streams.remove(&id).is_some() {
                         send_message(
@@ -282,7 +433,25 @@ where
                     }
                 }
             }
-            "connection_terminate" => return Err("connection_terminate".into()),
+            (Protocols::GraphQLTransportWS, "complete") => {
+                if let Some(id) = msg.id {
+                    ctx.streams.remove(&id);
+                }
+            }
+            (Protocols::GraphQLTransportWS, "ping") => {
+                send_message(
+                    &mut ctx.send_buf,
+                    &OperationMessage {
+                        ty: "pong",
+                        id: None,
+                        payload: msg.payload,
+                    },
+                );
+            }
+            (Protocols::GraphQLTransportWS, "pong") => {}
+            (Protocols::SubscriptionsTransportWS, "connection_terminate") => {
+                return Err("connection_terminate".into())
+            }
             _ => return Err("Unknown op".into()),
         },
         Err(err) => return Err(err.into()),
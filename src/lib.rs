@@ -105,8 +105,12 @@ mod look_ahead;
 mod model;
 mod request;
 mod response;
+mod runtime;
 mod schema;
+mod schema_router;
 mod serialize_resp;
+#[cfg(feature = "tower")]
+mod service;
 mod subscription;
 mod validation;
 
@@ -135,25 +139,50 @@ pub mod http;
 
 pub use base::{InputValueType, OutputValueType, ScalarType, Type};
 pub use context::{
-    Context, ContextBase, Data, QueryEnv, QueryPathNode, QueryPathSegment, Variables,
+    Context, ContextBase, Data, QueryEnv, QueryPathIter, QueryPathNode, QueryPathSegment,
+    SelectionDirective, SelectionField, Variables,
 };
 pub use error::{
     Error, ErrorExtensions, FieldError, FieldResult, InputValueError, InputValueResult,
-    ParseRequestError, QueryError, ResultExt, RuleError,
+    ParseRequestError, QueryError, ResultExt, RuleError, RuleErrorKind,
 };
 pub use look_ahead::Lookahead;
 pub use parser::{types::ConstValue as Value, Pos, Positioned};
-pub use registry::CacheControl;
+pub use registry::{
+    CacheControl, DirectiveSnapshot, EnumValueSnapshot, FieldSnapshot, InputValueSnapshot,
+    PrivateCacheKey, SchemaSnapshot, SdlOptions, TypeSnapshot,
+};
 pub use request::Request;
 pub use response::Response;
 pub use schema::{Schema, SchemaBuilder, SchemaEnv};
+pub use schema_router::{SchemaExecutor, SchemaRouter};
 pub use serde_json::Number;
 pub use types::*;
-pub use validation::ValidationMode;
+pub use validation::{ValidationMode, Visitor, VisitorContext};
 
 /// Result type
 pub type Result<T> = std::result::Result<T, Error>;
 
+pub use parser::types::ExecutableDocument;
+
+/// The types of the abstract syntax tree of GraphQL documents, such as the `ExecutableDocument`
+/// returned by [`parse_query`](fn.parse_query.html).
+pub mod ast {
+    pub use crate::parser::types::*;
+}
+
+/// Parse a GraphQL query document, without a schema.
+///
+/// The document is only parsed, not validated, which is useful for tools that inspect queries.
+///
+/// # Errors
+///
+/// Fails with [`Error::Parse`](enum.Error.html#variant.Parse) if the query is not a valid GraphQL
+/// document.
+pub fn parse_query<T: AsRef<str>>(query: T) -> Result<ExecutableDocument> {
+    parser::parse_query(query).map_err(Error::Parse)
+}
+
 // internal types
 #[doc(hidden)]
 pub use context::ContextSelectionSet;
@@ -185,12 +214,16 @@ pub use subscription::SubscriptionType;
 /// | name          | Field name                | string   | Y        |
 /// | desc          | Field description         | string   | Y        |
 /// | deprecation   | Field deprecation reason  | string   | Y        |
+/// | deprecation   | Deprecate the field without a reason | none | Y        |
 /// | cache_control | Field cache control       | [`CacheControl`](struct.CacheControl.html) | Y        |
 /// | external      | Mark a field as owned by another service. This allows service A to use fields from service B while also knowing at runtime the types of that field. | bool | Y |
 /// | provides      | Annotate the expected returned fieldset from a field on a base type that is guaranteed to be selectable by the gateway. | string | Y |
 /// | requires      | Annotate the required input fieldset from a base type for a resolver. It is used to develop a query plan where the required fields may not be needed by the client, but the service may need additional information from other services. | string | Y |
 /// | guard         | Field of guard            | [`Guard`](guard/trait.Guard.html) | Y        |
 /// | feature       | It's like a `#[cfg(feature = "foo")]` attribute but instead of not compiling this field it will just return a proper `FieldError` to tell you this feature is not enabled | string ("feature1,feature2") | Y |
+/// | timeout       | Resolve the field to `null` with an error if it takes longer than this to resolve, or fail the query if the field is non-null | string ("100ms", "2s", "1m") | Y |
+/// | flatten       | Merge the fields of the returned object into this object instead of exposing the method as a field | bool | Y |
+/// | complexity    | The weight of the field in the complexity of a query, 1 by default | int | Y |
 ///
 /// # Field argument parameters
 ///
@@ -201,7 +234,10 @@ pub use subscription::SubscriptionType;
 /// | default      | Use `Default::default` for default value | none        | Y        |
 /// | default      | Argument default value                   | literal     | Y        |
 /// | default_with | Expression to generate default value     | code string | Y        |
+/// | default_ctx  | Function or closure that takes a `&Context<'_>` to generate the default value when the argument is omitted or null, the argument is nullable in the schema | code string | Y        |
 /// | validator    | Input value validator                    | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
+/// | flatten      | The argument is an input object whose fields are the arguments of the field | bool | Y |
+/// | secret       | The value of the argument is replaced with `***` in validation errors and logs | none | Y |
 ///
 /// # Valid field return types
 ///
@@ -300,6 +336,7 @@ pub use async_graphql_derive::GQLObject;
 /// | name          | Field name                | string   | Y        |
 /// | desc          | Field description         | string   | Y        |
 /// | deprecation   | Field deprecation reason  | string   | Y        |
+/// | deprecation   | Deprecate the field without a reason | none | Y        |
 /// | owned         | Field resolver return a ownedship value  | bool   | Y        |
 /// | cache_control | Field cache control       | [`CacheControl`](struct.CacheControl.html) | Y        |
 /// | external      | Mark a field as owned by another service. This allows service A to use fields from service B while also knowing at runtime the types of that field. | bool | Y |
@@ -307,6 +344,8 @@ pub use async_graphql_derive::GQLObject;
 /// | requires      | Annotate the required input fieldset from a base type for a resolver. It is used to develop a query plan where the required fields may not be needed by the client, but the service may need additional information from other services. | string | Y |
 /// | guard         | Field of guard            | [`Guard`](guard/trait.Guard.html) | Y        |
 /// | feature       | It's like a `#[cfg(feature = "foo")]` attribute but instead of not compiling this field it will just return a proper `FieldError` to tell you this feature is not enabled | string ("feature1,feature2") | Y |
+/// | complexity    | The weight of the field in the complexity of a query, 1 by default | int | Y |
+/// | skip          | Do not expose the field in the schema, the field type does not need to be a GraphQL type. Can also be written as `#[graphql(skip)]` | bool | Y |
 ///
 /// # Examples
 ///
@@ -340,6 +379,8 @@ pub use async_graphql_derive::GQLSimpleObject;
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Enum name                 | string   | Y        |
 /// | desc        | Enum description          | string   | Y        |
+/// | as_int      | Use the discriminant of each item as an integer value for input and output | bool     | Y        |
+/// | case_insensitive | Ignore the case of the item names when parsing input values | bool     | Y        |
 ///
 /// # Item parameters
 ///
@@ -348,6 +389,7 @@ pub use async_graphql_derive::GQLSimpleObject;
 /// | name        | Item name                 | string   | Y        |
 /// | desc        | Item description          | string   | Y        |
 /// | deprecation | Item deprecation reason   | string   | Y        |
+/// | deprecation | Deprecate the item without a reason | none | Y        |
 ///
 /// # Examples
 ///
@@ -399,6 +441,8 @@ pub use async_graphql_derive::GQLEnum;
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Object name               | string   | Y        |
 /// | desc        | Object description        | string   | Y        |
+/// | one_of      | Exactly one field must be provided, all fields must be `Option` | bool | Y |
+/// | deny_unknown_fields | Reject values with fields that the input object doesn't have, `true` by default | bool | Y |
 ///
 /// # Field parameters
 ///
@@ -468,6 +512,8 @@ pub use async_graphql_derive::GQLInputObject;
 /// | type        | Field type                | string   | N        |
 /// | desc        | Field description         | string   | Y        |
 /// | deprecation | Field deprecation reason  | string   | Y        |
+/// | deprecation | Deprecate the field without a reason | none | Y        |
+/// | complexity  | The weight of the field in the complexity of a query, 1 by default | int | Y |
 /// | args        | Field arguments           |          | Y        |
 ///
 /// # Field argument parameters
@@ -480,6 +526,7 @@ pub use async_graphql_derive::GQLInputObject;
 /// | default      | Use `Default::default` for default value | none        | Y        |
 /// | default      | Argument default value                   | literal     | Y        |
 /// | default_with | Expression to generate default value     | code string | Y        |
+/// | secret       | The value of the argument is replaced with `***` in validation errors and logs | none | Y |
 ///
 /// # Define an interface
 ///
@@ -495,6 +542,28 @@ pub use async_graphql_derive::GQLInputObject;
 /// }
 /// ```
 ///
+/// # Conversions
+///
+/// A `From` implementation is generated for the type of every variant, so an implementation can
+/// be converted into the interface with `.into()`. For this reason every type can only be used by
+/// one variant, and using a type twice is a compile error:
+///
+/// ```rust,compile_fail
+/// use async_graphql::*;
+///
+/// #[derive(GQLSimpleObject)]
+/// struct TypeA {
+///     value: i32,
+/// }
+///
+/// #[derive(GQLInterface)]
+/// #[graphql(field(name = "value", type = "&i32"))]
+/// enum MyInterface {
+///     First(TypeA),
+///     Second(TypeA),
+/// }
+/// ```
+///
 /// # Fields
 ///
 /// The type, name, and parameter fields of the interface must exactly match the type of the
@@ -589,6 +658,12 @@ pub use async_graphql_derive::GQLInterface;
 /// | name        | Object name               | string   | Y        |
 /// | desc        | Object description        | string   | Y        |
 ///
+/// # Item parameters
+///
+/// | Attribute   | description               | Type     | Optional |
+/// |-------------|---------------------------|----------|----------|
+/// | typename    | The GraphQL type name of the item, used for `__typename` and possible types. Useful when the item type is a wrapper around an object | string   | Y        |
+///
 /// # Define a union
 ///
 /// Define TypeA, TypeB, ... as MyUnion
@@ -642,16 +717,41 @@ pub use async_graphql_derive::GQLInterface;
 ///     }));
 /// });
 /// ```
+///
+/// # Conversions
+///
+/// A `From` implementation is generated for the type of every variant, so a member can be
+/// converted into the union with `.into()`. For this reason every type can only be used by one
+/// variant, and using a type twice is a compile error:
+///
+/// ```rust,compile_fail
+/// use async_graphql::*;
+///
+/// #[derive(GQLSimpleObject)]
+/// struct TypeA {
+///     value_a: i32,
+/// }
+///
+/// #[derive(GQLUnion)]
+/// enum MyUnion {
+///     First(TypeA),
+///     Second(TypeA),
+/// }
+/// ```
 pub use async_graphql_derive::GQLUnion;
 
 /// Define a GraphQL subscription
 ///
 /// *[See also the Book](https://async-graphql.github.io/async-graphql/en/subscription.html).*
 ///
-/// The field function is a synchronization function that performs filtering. When true is returned, the message is pushed to the client.
-/// The second parameter is the type of the field.
-/// Starting with the third parameter is one or more filtering conditions, The filter condition is the parameter of the field.
-/// The filter function should be synchronous.
+/// The field function is an asynchronous function that returns a stream, each message of the stream is pushed to the client.
+/// Messages can be filtered with the `filter` attribute, which specifies an asynchronous function with the signature
+/// `async fn(ctx: &Context<'_>, msg: &T) -> FieldResult<bool>`. When true is returned, the message is pushed to the client.
+/// The next message is only taken from the stream after the filter has finished, and if the filter returns an error,
+/// the error is sent to the client and the subscription is terminated.
+///
+/// The stream can also yield `FieldResult<T>`. An error is sent to the client in the response of that message, with
+/// the field set to null if it is nullable and the whole data set to null otherwise, and the subscription continues.
 ///
 /// # Macro parameters
 ///
@@ -667,8 +767,10 @@ pub use async_graphql_derive::GQLUnion;
 /// | name        | Field name                | string   | Y        |
 /// | desc        | Field description         | string   | Y        |
 /// | deprecation | Field deprecation reason  | string   | Y        |
+/// | deprecation | Deprecate the field without a reason | none | Y        |
 /// | guard         | Field of guard            | [`Guard`](guard/trait.Guard.html) | Y        |
 /// | feature       | It's like a `#[cfg(feature = "foo")]` attribute but instead of not compiling this field it will just return a proper `FieldError` to tell you this feature is not enabled | string ("feature1,feature2") | Y |
+/// | filter        | Asynchronous function used to filter the messages of the stream | code path | Y |
 ///
 /// # Field argument parameters
 ///
@@ -679,7 +781,9 @@ pub use async_graphql_derive::GQLUnion;
 /// | default      | Use `Default::default` for default value | none        | Y        |
 /// | default      | Argument default value                   | literal     | Y        |
 /// | default_with | Expression to generate default value     | code string | Y        |
+/// | default_ctx  | Function or closure that takes a `&Context<'_>` to generate the default value when the argument is omitted or null, the argument is nullable in the schema | code string | Y        |
 /// | validator    | Input value validator                    | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
+/// | secret       | The value of the argument is replaced with `***` in validation errors and logs | none | Y |
 ///
 /// # Examples
 ///
@@ -710,10 +814,45 @@ pub use async_graphql_derive::GQLSubscription;
 ///
 pub use async_graphql_derive::GQLScalar;
 
+/// Define a Scalar for a newtype
+///
+/// The scalar delegates parsing and serialization to the `ScalarType` implementation of the
+/// inner type, and can check the parsed value with a `validate` function, which takes a reference
+/// to the inner value and returns a `Result<(), E>` where `E: Display`.
+///
+/// # Macro parameters
+///
+/// | Attribute   | description               | Type     | Optional |
+/// |-------------|---------------------------|----------|----------|
+/// | name        | Scalar name               | string   | Y        |
+/// | desc        | Scalar description        | string   | Y        |
+/// | validate    | Function used to validate the inner value | string   | Y        |
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// fn validate_email(value: &str) -> Result<(), &'static str> {
+///     if value.contains('@') {
+///         Ok(())
+///     } else {
+///         Err("Invalid email")
+///     }
+/// }
+///
+/// #[derive(GQLNewType)]
+/// #[graphql(validate = "validate_email")]
+/// struct Email(String);
+/// ```
+pub use async_graphql_derive::GQLNewType;
+
 /// Define a merged object with multiple object types.
 ///
 /// You can also [derive this](derive.GQLMergedObject.html).
 ///
+/// The merged object types must not define fields with the same name, otherwise building the schema panics.
+///
 /// *[See also the Book](https://async-graphql.github.io/async-graphql/en/merging_objects.html).*
 ///
 /// # Macro parameters
@@ -756,6 +895,8 @@ pub use async_graphql_derive::GQLMergedObject;
 ///
 /// You can also [derive this](derive.GQLMergedSubscription.html).
 ///
+/// The merged subscription types must not define fields with the same name, otherwise building the schema panics.
+///
 /// *[See also the Book](https://async-graphql.github.io/async-graphql/en/merging_objects.html).*
 ///
 /// # Macro parameters
@@ -1,3 +1,4 @@
+use crate::model::deprecation_reason;
 use crate::{registry, GQLObject};
 
 pub struct __EnumValue<'a> {
@@ -17,10 +18,10 @@ impl<'a> __EnumValue<'a> {
     }
 
     async fn is_deprecated(&self) -> bool {
-        self.value.deprecation.is_some()
+        self.value.deprecation.is_deprecated()
     }
 
     async fn deprecation_reason(&self) -> Option<String> {
-        self.value.deprecation.map(|s| s.to_string())
+        deprecation_reason(self.registry, self.value.deprecation)
     }
 }
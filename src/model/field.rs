@@ -1,4 +1,4 @@
-use crate::model::{__InputValue, __Type};
+use crate::model::{__InputValue, __Type, deprecation_reason};
 use crate::{registry, GQLObject};
 use itertools::Itertools;
 
@@ -35,10 +35,10 @@ impl<'a> __Field<'a> {
     }
 
     async fn is_deprecated(&self) -> bool {
-        self.field.deprecation.is_some()
+        self.field.deprecation.is_deprecated()
     }
 
     async fn deprecation_reason(&self) -> Option<String> {
-        self.field.deprecation.map(|s| s.to_string())
+        deprecation_reason(self.registry, self.field.deprecation)
     }
 }
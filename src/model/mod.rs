@@ -13,3 +13,16 @@ pub use input_value::__InputValue;
 pub use kind::__TypeKind;
 pub use r#type::__Type;
 pub use schema::__Schema;
+
+use crate::registry::{Deprecation, Registry};
+
+/// The reason of a deprecation, or the default reason of the schema if it has none.
+fn deprecation_reason(registry: &Registry, deprecation: Deprecation) -> Option<String> {
+    match deprecation {
+        Deprecation::NoDeprecated => None,
+        Deprecation::Deprecated {
+            reason: Some(reason),
+        } => Some(reason.to_string()),
+        Deprecation::Deprecated { reason: None } => registry.default_deprecation_reason.clone(),
+    }
+}
@@ -99,7 +99,7 @@ impl<'a> __Type<'a> {
                 fields
                     .values()
                     .filter(|field| {
-                        (include_deprecated || field.deprecation.is_none())
+                        (include_deprecated || !field.deprecation.is_deprecated())
                             && !field.name.starts_with("__")
                     })
                     .map(|field| __Field {
@@ -161,7 +161,7 @@ impl<'a> __Type<'a> {
             Some(
                 enum_values
                     .values()
-                    .filter(|field| include_deprecated || field.deprecation.is_none())
+                    .filter(|field| include_deprecated || !field.deprecation.is_deprecated())
                     .map(|value| __EnumValue {
                         registry: self.registry,
                         value,
@@ -191,6 +191,14 @@ impl<'a> __Type<'a> {
         }
     }
 
+    async fn is_one_of(&self) -> Option<bool> {
+        if let TypeDetail::Named(registry::MetaType::InputObject { one_of, .. }) = &self.detail {
+            Some(*one_of)
+        } else {
+            None
+        }
+    }
+
     async fn of_type(&self) -> Option<__Type<'a>> {
         if let TypeDetail::List(ty) = &self.detail {
             Some(__Type::new(self.registry, &ty))
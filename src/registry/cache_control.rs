@@ -1,5 +1,12 @@
 /// Cache control values
 ///
+/// A field can be marked with `#[field(cache_control(inherit_max_age))]`, so that neither the field
+/// nor the object it returns lowers the `max_age`, and the field inherits the `max_age` of its parent.
+///
+/// If the scope of a response is private and a [`PrivateCacheKey`](struct.PrivateCacheKey.html) is
+/// in the request data, `private_key` is set to its value, so that caches can store a separate
+/// entry per key.
+///
 /// # Examples
 ///
 /// ```rust
@@ -23,18 +30,22 @@
 /// #[async_std::main]
 /// async fn main() {
 ///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
-///     assert_eq!(schema.execute("{ value1 }").await.into_result().unwrap().cache_control, CacheControl { public: true, max_age: 30 });
-///     assert_eq!(schema.execute("{ value2 }").await.into_result().unwrap().cache_control, CacheControl { public: false, max_age: 60 });
-///     assert_eq!(schema.execute("{ value1 value2 }").await.into_result().unwrap().cache_control, CacheControl { public: false, max_age: 30 });
+///     assert_eq!(schema.execute("{ value1 }").await.into_result().unwrap().cache_control, CacheControl { public: true, max_age: 30, private_key: None });
+///     assert_eq!(schema.execute("{ value2 }").await.into_result().unwrap().cache_control, CacheControl { public: false, max_age: 60, private_key: None });
+///     assert_eq!(schema.execute("{ value1 value2 }").await.into_result().unwrap().cache_control, CacheControl { public: false, max_age: 30, private_key: None });
+///     assert_eq!(schema.execute("{ value1 value2 }").await.into_result().unwrap().cache_control.header_value(), Some("max-age=30, private".to_string()));
 /// }
 /// ```
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub struct CacheControl {
     /// Scope is public, default is true.
     pub public: bool,
 
     /// Cache max age, default is 0.
     pub max_age: usize,
+
+    /// The key of the private cache entry, default is `None`.
+    pub private_key: Option<String>,
 }
 
 impl Default for CacheControl {
@@ -42,14 +53,16 @@ impl Default for CacheControl {
         Self {
             public: true,
             max_age: 0,
+            private_key: None,
         }
     }
 }
 
 impl CacheControl {
-    /// Get 'Cache-Control' header value.
+    /// Get the value of the `Cache-Control` HTTP header, such as `max-age=60, private`, or `None`
+    /// if the response must not be cached.
     #[must_use]
-    pub fn value(&self) -> Option<String> {
+    pub fn header_value(&self) -> Option<String> {
         if self.max_age > 0 {
             Some(format!(
                 "max-age={}{}",
@@ -60,10 +73,18 @@ impl CacheControl {
             None
         }
     }
+
+    /// Get the value of the `Cache-Control` HTTP header.
+    #[must_use]
+    #[deprecated(note = "Use `CacheControl::header_value` instead.")]
+    pub fn value(&self) -> Option<String> {
+        self.header_value()
+    }
 }
 
 impl CacheControl {
-    pub(crate) fn merge(&mut self, other: &CacheControl) {
+    #[doc(hidden)]
+    pub fn merge(&mut self, other: &CacheControl) {
         self.public = self.public && other.public;
         self.max_age = if self.max_age == 0 {
             other.max_age
@@ -74,3 +95,36 @@ impl CacheControl {
         };
     }
 }
+
+/// The key of private cache entries, such as the id of the current user.
+///
+/// Add it to the request data, and it will be used as the `private_key` of responses with a private
+/// cache scope.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct QueryRoot;
+///
+/// #[GQLObject(cache_control(max_age = 60))]
+/// impl QueryRoot {
+///     #[field(cache_control(private))]
+///     async fn balance(&self) -> i32 {
+///         100
+///     }
+/// }
+///
+/// #[async_std::main]
+/// async fn main() {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let request = Request::new("{ balance }").data(PrivateCacheKey("user-1".to_string()));
+///     assert_eq!(
+///         schema.execute(request).await.into_result().unwrap().cache_control,
+///         CacheControl { public: false, max_age: 60, private_key: Some("user-1".to_string()) },
+///     );
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PrivateCacheKey(pub String);
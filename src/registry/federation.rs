@@ -1,11 +1,34 @@
-use crate::registry::{MetaField, MetaInputValue, MetaType, Registry};
+use crate::registry::{Deprecation, MetaField, MetaInputValue, MetaType, Registry};
 use crate::{Any, Type};
 use indexmap::IndexMap;
 use itertools::Itertools;
 use std::fmt::Write;
 
+/// Options for printing the SDL of a schema with
+/// [`Schema::sdl_with_options`](struct.Schema.html#method.sdl_with_options).
+#[derive(Default, Clone)]
+#[non_exhaustive]
+pub struct SdlOptions {
+    /// The indentation of fields, enum values and their descriptions. The default is a tab.
+    pub indent: Option<String>,
+}
+
+impl SdlOptions {
+    /// Set the indentation, such as two spaces.
+    pub fn indent(self, indent: impl Into<String>) -> Self {
+        SdlOptions {
+            indent: Some(indent.into()),
+        }
+    }
+}
+
 impl Registry {
     pub fn create_federation_sdl(&self) -> String {
+        self.create_sdl(&SdlOptions::default())
+    }
+
+    pub fn create_sdl(&self, options: &SdlOptions) -> String {
+        let indent = options.indent.as_deref().unwrap_or("\t");
         let mut sdl = String::new();
         for ty in self.types.values() {
             if ty.name().starts_with("__") {
@@ -15,7 +38,7 @@ impl Registry {
             if FEDERATION_TYPES.contains(&ty.name()) {
                 continue;
             }
-            self.create_federation_type(ty, &mut sdl);
+            self.create_federation_type(ty, &mut sdl, indent);
         }
         sdl
     }
@@ -37,11 +60,13 @@ impl Registry {
                             description: None,
                             args: Default::default(),
                             ty: "String".to_string(),
-                            deprecation: None,
+                            deprecation: Default::default(),
                             cache_control: Default::default(),
                             external: false,
                             requires: None,
                             provides: None,
+                            inherit_max_age: false,
+                            complexity: 1,
                         },
                     );
                     fields
@@ -63,11 +88,13 @@ impl Registry {
                     description: None,
                     args: Default::default(),
                     ty: "_Service!".to_string(),
-                    deprecation: None,
+                    deprecation: Default::default(),
                     cache_control: Default::default(),
                     external: false,
                     requires: None,
                     provides: None,
+                    inherit_max_age: false,
+                    complexity: 1,
                 },
             );
 
@@ -86,31 +113,41 @@ impl Registry {
                                 ty: "[_Any!]!".to_string(),
                                 default_value: None,
                                 validator: None,
+                                is_secret: false,
                             },
                         );
                         args
                     },
                     ty: "[_Entity]!".to_string(),
-                    deprecation: None,
+                    deprecation: Default::default(),
                     cache_control: Default::default(),
                     external: false,
                     requires: None,
                     provides: None,
+                    inherit_max_age: false,
+                    complexity: 1,
                 },
             );
         }
     }
 
-    fn create_federation_fields<'a, I: Iterator<Item = &'a MetaField>>(sdl: &mut String, it: I) {
+    fn create_federation_fields<'a, I: Iterator<Item = &'a MetaField>>(
+        sdl: &mut String,
+        it: I,
+        indent: &str,
+    ) {
         for field in it {
             if field.name.starts_with("__") || matches!(&*field.name, "_service" | "_entities") {
                 continue;
             }
 
+            write_description(sdl, field.description, indent);
+
             if !field.args.is_empty() {
                 write!(
                     sdl,
-                    "\t{}({}): {}",
+                    "{}{}({}): {}",
+                    indent,
                     field.name,
                     field
                         .args
@@ -121,7 +158,7 @@ impl Registry {
                 )
                 .ok();
             } else {
-                write!(sdl, "\t{}: {}", field.name, field.ty).ok();
+                write!(sdl, "{}{}: {}", indent, field.name, field.ty).ok();
             }
 
             if field.external {
@@ -137,16 +174,20 @@ impl Registry {
         }
     }
 
-    fn create_federation_type(&self, ty: &MetaType, sdl: &mut String) {
+    fn create_federation_type(&self, ty: &MetaType, sdl: &mut String, indent: &str) {
         match ty {
-            MetaType::Scalar { name, .. } => {
+            MetaType::Scalar {
+                name, description, ..
+            } => {
                 const SYSTEM_SCALARS: &[&str] = &["Int", "Float", "String", "Boolean", "ID", "Any"];
                 if !SYSTEM_SCALARS.contains(&name.as_str()) {
+                    write_description(sdl, *description, "");
                     writeln!(sdl, "scalar {}", name).ok();
                 }
             }
             MetaType::Object {
                 name,
+                description,
                 fields,
                 extends,
                 keys,
@@ -163,6 +204,9 @@ impl Registry {
                 }
                 if *extends {
                     write!(sdl, "extend ").ok();
+                } else {
+                    // Type extensions can't have descriptions.
+                    write_description(sdl, *description, "");
                 }
                 write!(sdl, "type {} ", name).ok();
                 if let Some(implements) = self.implements.get(name) {
@@ -176,11 +220,12 @@ impl Registry {
                     }
                 }
                 writeln!(sdl, "{{").ok();
-                Self::create_federation_fields(sdl, fields.values());
+                Self::create_federation_fields(sdl, fields.values(), indent);
                 writeln!(sdl, "}}").ok();
             }
             MetaType::Interface {
                 name,
+                description,
                 fields,
                 extends,
                 keys,
@@ -188,6 +233,9 @@ impl Registry {
             } => {
                 if *extends {
                     write!(sdl, "extend ").ok();
+                } else {
+                    // Type extensions can't have descriptions.
+                    write_description(sdl, *description, "");
                 }
                 write!(sdl, "interface {} ", name).ok();
                 if let Some(keys) = keys {
@@ -196,34 +244,63 @@ impl Registry {
                     }
                 }
                 writeln!(sdl, "{{").ok();
-                Self::create_federation_fields(sdl, fields.values());
+                Self::create_federation_fields(sdl, fields.values(), indent);
                 writeln!(sdl, "}}").ok();
             }
             MetaType::Enum {
-                name, enum_values, ..
+                name,
+                description,
+                enum_values,
+                ..
             } => {
+                write_description(sdl, *description, "");
                 write!(sdl, "enum {} ", name).ok();
                 writeln!(sdl, "{{").ok();
                 for value in enum_values.values() {
-                    writeln!(sdl, "{}", value.name).ok();
+                    write_description(sdl, value.description, indent);
+                    write!(sdl, "{}{}", indent, value.name).ok();
+                    match value.deprecation {
+                        Deprecation::Deprecated {
+                            reason: Some(reason),
+                        } => {
+                            sdl.push_str(" @deprecated(reason: ");
+                            write_string(sdl, reason);
+                            sdl.push(')');
+                        }
+                        Deprecation::Deprecated { reason: None } => {
+                            write!(sdl, " @deprecated").ok();
+                        }
+                        Deprecation::NoDeprecated => {}
+                    }
+                    writeln!(sdl).ok();
                 }
                 writeln!(sdl, "}}").ok();
             }
             MetaType::InputObject {
-                name, input_fields, ..
+                name,
+                description,
+                input_fields,
+                one_of,
+                ..
             } => {
+                write_description(sdl, *description, "");
                 write!(sdl, "input {} ", name).ok();
+                if *one_of {
+                    write!(sdl, "@oneOf ").ok();
+                }
                 writeln!(sdl, "{{").ok();
                 for field in input_fields.values() {
-                    writeln!(sdl, "{}", federation_input_value(&field)).ok();
+                    write_description(sdl, field.description, indent);
+                    writeln!(sdl, "{}{}", indent, federation_input_value(&field)).ok();
                 }
                 writeln!(sdl, "}}").ok();
             }
             MetaType::Union {
                 name,
+                description,
                 possible_types,
-                ..
             } => {
+                write_description(sdl, *description, "");
                 writeln!(
                     sdl,
                     "union {} = {}",
@@ -246,3 +323,43 @@ fn federation_input_value(input_value: &MetaInputValue) -> String {
         format!("{}: {}", input_value.name, input_value.ty)
     }
 }
+
+/// Writes a description, using a block string if it spans multiple lines.
+fn write_description(sdl: &mut String, description: Option<&str>, indent: &str) {
+    let description = match description {
+        Some(description) => description,
+        None => return,
+    };
+
+    if description.contains('\n') {
+        writeln!(sdl, "{}\"\"\"", indent).ok();
+        for line in description.lines() {
+            if line.is_empty() {
+                writeln!(sdl).ok();
+            } else {
+                writeln!(sdl, "{}{}", indent, line.replace(r#"""""#, r#"\""""#)).ok();
+            }
+        }
+        writeln!(sdl, "{}\"\"\"", indent).ok();
+    } else {
+        sdl.push_str(indent);
+        write_string(sdl, description);
+        writeln!(sdl).ok();
+    }
+}
+
+/// Writes a string value, with the characters that can't appear in it escaped.
+fn write_string(sdl: &mut String, value: &str) {
+    sdl.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => sdl.push_str("\\\""),
+            '\\' => sdl.push_str("\\\\"),
+            '\n' => sdl.push_str("\\n"),
+            '\r' => sdl.push_str("\\r"),
+            '\t' => sdl.push_str("\\t"),
+            c => sdl.push(c),
+        }
+    }
+    sdl.push('"');
+}
@@ -1,5 +1,7 @@
 mod cache_control;
 mod federation;
+mod sdl;
+mod snapshot;
 
 use crate::parser::types::{BaseType as ParsedBaseType, Type as ParsedType};
 use crate::validators::InputValueValidator;
@@ -9,7 +11,13 @@ use indexmap::set::IndexSet;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-pub use cache_control::CacheControl;
+pub use crate::model::__DirectiveLocation;
+pub use cache_control::{CacheControl, PrivateCacheKey};
+pub use federation::SdlOptions;
+pub use snapshot::{
+    DirectiveSnapshot, EnumValueSnapshot, FieldSnapshot, InputValueSnapshot, SchemaSnapshot,
+    TypeSnapshot,
+};
 
 fn strip_brackets(type_name: &str) -> Option<&str> {
     if let Some(rest) = type_name.strip_prefix('[') {
@@ -90,6 +98,33 @@ pub struct MetaInputValue {
     pub ty: String,
     pub default_value: Option<String>,
     pub validator: Option<Arc<dyn InputValueValidator>>,
+    pub is_secret: bool,
+}
+
+/// The deprecation of a field or an enum value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Deprecation {
+    /// Not deprecated.
+    NoDeprecated,
+
+    /// Deprecated, with an optional reason.
+    ///
+    /// Without a reason, the default reason of the schema is used, which is set with
+    /// `SchemaBuilder::default_deprecation_reason`.
+    Deprecated { reason: Option<&'static str> },
+}
+
+impl Default for Deprecation {
+    fn default() -> Self {
+        Deprecation::NoDeprecated
+    }
+}
+
+impl Deprecation {
+    /// Returns `true` if the field or enum value is deprecated.
+    pub fn is_deprecated(&self) -> bool {
+        matches!(self, Deprecation::Deprecated { .. })
+    }
 }
 
 #[derive(Clone)]
@@ -98,18 +133,21 @@ pub struct MetaField {
     pub description: Option<&'static str>,
     pub args: IndexMap<&'static str, MetaInputValue>,
     pub ty: String,
-    pub deprecation: Option<&'static str>,
-    pub cache_control: CacheControl,
+    pub deprecation: Deprecation,
+    pub cache_control: Option<CacheControl>,
     pub external: bool,
     pub requires: Option<&'static str>,
     pub provides: Option<&'static str>,
+    pub inherit_max_age: bool,
+    pub complexity: usize,
 }
 
 #[derive(Clone)]
 pub struct MetaEnumValue {
     pub name: &'static str,
     pub description: Option<&'static str>,
-    pub deprecation: Option<&'static str>,
+    pub deprecation: Deprecation,
+    pub int_value: Option<i64>,
 }
 
 pub enum MetaType {
@@ -143,11 +181,14 @@ pub enum MetaType {
         name: String,
         description: Option<&'static str>,
         enum_values: IndexMap<&'static str, MetaEnumValue>,
+        case_insensitive: bool,
     },
     InputObject {
         name: String,
         description: Option<&'static str>,
         input_fields: IndexMap<String, MetaInputValue>,
+        one_of: bool,
+        deny_unknown_fields: bool,
     },
 }
 
@@ -259,6 +300,16 @@ pub struct Registry {
     pub query_type: String,
     pub mutation_type: Option<String>,
     pub subscription_type: Option<String>,
+    pub enable_suggestions: bool,
+    pub default_deprecation_reason: Option<String>,
+    pub default_cache_control: Option<CacheControl>,
+    /// The input objects whose fields are the arguments of a field with a flattened argument.
+    pub flattened_input_objects: HashSet<String>,
+    /// The objects whose fields were merged into another object by `#[field(flatten)]`.
+    pub flattened_objects: HashSet<String>,
+    /// The fields that were merged into an object by `#[field(flatten)]`, by object and field
+    /// name, with the index of the flattened method that provides them.
+    pub flattened_fields: HashMap<String, HashMap<String, usize>>,
 }
 
 impl Registry {
@@ -340,6 +391,207 @@ impl Registry {
         })
     }
 
+    /// Remove the objects whose fields were merged into another object, unless they are also the
+    /// type of a field, a root type or a possible type of an interface or a union.
+    pub(crate) fn remove_flattened_objects(&mut self) {
+        loop {
+            let mut referenced = HashSet::new();
+            referenced.insert(self.query_type.as_str());
+            referenced.extend(self.mutation_type.as_deref());
+            referenced.extend(self.subscription_type.as_deref());
+            for ty in self.types.values() {
+                match ty {
+                    MetaType::Object { name, fields, .. } => {
+                        for field in fields.values() {
+                            let field_type = MetaTypeName::concrete_typename(&field.ty);
+                            if field_type != name.as_str() {
+                                referenced.insert(field_type);
+                            }
+                        }
+                    }
+                    MetaType::Interface {
+                        fields,
+                        possible_types,
+                        ..
+                    } => {
+                        for field in fields.values() {
+                            referenced.insert(MetaTypeName::concrete_typename(&field.ty));
+                        }
+                        referenced.extend(possible_types.iter().map(String::as_str));
+                    }
+                    MetaType::Union { possible_types, .. } => {
+                        referenced.extend(possible_types.iter().map(String::as_str));
+                    }
+                    _ => {}
+                }
+            }
+
+            let unreferenced = self
+                .flattened_objects
+                .iter()
+                .filter(|name| {
+                    self.types.contains_key(name.as_str()) && !referenced.contains(name.as_str())
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            if unreferenced.is_empty() {
+                break;
+            }
+            for name in unreferenced {
+                self.types.remove(&name);
+                self.implements.remove(&name);
+            }
+        }
+    }
+
+    /// Remove the input objects that were flattened into the arguments of fields, unless they are
+    /// also the type of an argument or an input field.
+    pub(crate) fn remove_flattened_input_objects(&mut self) {
+        loop {
+            let mut referenced = HashSet::new();
+            for ty in self.types.values() {
+                match ty {
+                    MetaType::Object { fields, .. } | MetaType::Interface { fields, .. } => {
+                        for field in fields.values() {
+                            for arg in field.args.values() {
+                                referenced.insert(MetaTypeName::concrete_typename(&arg.ty));
+                            }
+                        }
+                    }
+                    MetaType::InputObject {
+                        name, input_fields, ..
+                    } => {
+                        for field in input_fields.values() {
+                            let field_type = MetaTypeName::concrete_typename(&field.ty);
+                            if field_type != name.as_str() {
+                                referenced.insert(field_type);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            for directive in self.directives.values() {
+                for arg in directive.args.values() {
+                    referenced.insert(MetaTypeName::concrete_typename(&arg.ty));
+                }
+            }
+
+            let unreferenced = self
+                .flattened_input_objects
+                .iter()
+                .filter(|name| {
+                    self.types.contains_key(name.as_str()) && !referenced.contains(name.as_str())
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            if unreferenced.is_empty() {
+                break;
+            }
+            for name in unreferenced {
+                self.types.remove(&name);
+            }
+        }
+    }
+
+    /// Panics if an object does not provide all the fields of an interface it implements, with
+    /// compatible types and arguments.
+    pub(crate) fn check_interfaces(&self) {
+        for name in self.types.keys() {
+            if let Err(err) = self.check_interface(name) {
+                panic!("{}", err);
+            }
+        }
+    }
+
+    /// Check that the types that implement an interface have all its fields.
+    pub(crate) fn check_interface(&self, name: &str) -> std::result::Result<(), String> {
+        let (interface_name, interface_fields, possible_types) = match self.types.get(name) {
+            Some(MetaType::Interface {
+                name,
+                fields,
+                possible_types,
+                ..
+            }) => (name, fields, possible_types),
+            _ => return Ok(()),
+        };
+
+        for object_name in possible_types {
+            let object_fields = match self.types.get(object_name) {
+                Some(MetaType::Object { fields, .. }) => fields,
+                _ => continue,
+            };
+
+            for interface_field in interface_fields.values() {
+                let object_field = match object_fields.get(&interface_field.name) {
+                    Some(field) => field,
+                    None => {
+                        return Err(format!(
+                        "Object \"{}\" does not implement the field \"{}\" of interface \"{}\".",
+                        object_name, interface_field.name, interface_name
+                    ))
+                    }
+                };
+
+                if !self.is_valid_implementation_type(
+                    &MetaTypeName::create(&interface_field.ty),
+                    &MetaTypeName::create(&object_field.ty),
+                ) {
+                    return Err(format!(
+                        "Field \"{}.{}\" of type \"{}\" is not compatible with the type \"{}\" of interface field \"{}.{}\".",
+                        object_name,
+                        object_field.name,
+                        object_field.ty,
+                        interface_field.ty,
+                        interface_name,
+                        interface_field.name
+                    ));
+                }
+
+                for interface_arg in interface_field.args.values() {
+                    match object_field.args.get(interface_arg.name) {
+                        Some(object_arg) if object_arg.ty == interface_arg.ty => {}
+                        _ => {
+                            return Err(format!(
+                                "Field \"{}.{}\" must have the argument \"{}\" of type \"{}\" of interface field \"{}.{}\".",
+                                object_name,
+                                object_field.name,
+                                interface_arg.name,
+                                interface_arg.ty,
+                                interface_name,
+                                interface_field.name
+                            ))
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_valid_implementation_type(
+        &self,
+        interface_ty: &MetaTypeName<'_>,
+        object_ty: &MetaTypeName<'_>,
+    ) -> bool {
+        match (interface_ty, object_ty) {
+            (MetaTypeName::NonNull(interface_ty), MetaTypeName::NonNull(object_ty))
+            | (MetaTypeName::Named(interface_ty), MetaTypeName::NonNull(object_ty))
+            | (MetaTypeName::List(interface_ty), MetaTypeName::List(object_ty)) => self
+                .is_valid_implementation_type(
+                    &MetaTypeName::create(interface_ty),
+                    &MetaTypeName::create(object_ty),
+                ),
+            (MetaTypeName::Named(interface_ty), MetaTypeName::Named(object_ty)) => {
+                interface_ty == object_ty
+                    || self.types.get(*interface_ty).map_or(false, |ty| {
+                        ty.is_abstract() && ty.is_possible_type(object_ty)
+                    })
+            }
+            _ => false,
+        }
+    }
+
     fn create_entity_type(&mut self) {
         let possible_types = self
             .types
@@ -0,0 +1,298 @@
+use crate::parser::types::{
+    ConstDirective, FieldDefinition, InputValueDefinition, Name, TypeDefinition, TypeKind,
+    TypeSystemDefinition,
+};
+use crate::parser::{parse_schema, Error, Pos, Positioned, Result};
+use crate::registry::{
+    Deprecation, MetaEnumValue, MetaField, MetaInputValue, MetaType, MetaTypeName, Registry,
+};
+use indexmap::{IndexMap, IndexSet};
+use std::collections::HashSet;
+
+/// The names and descriptions in the registry are static, so the ones of imported types are
+/// leaked. This happens once, when the schema is built.
+fn leak(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
+
+fn description(description: Option<Positioned<String>>) -> Option<&'static str> {
+    description.map(|description| leak(description.node))
+}
+
+fn deprecation(directives: &[Positioned<ConstDirective>]) -> Deprecation {
+    match directives
+        .iter()
+        .find(|directive| directive.node.name.node == "deprecated")
+    {
+        Some(directive) => Deprecation::Deprecated {
+            reason: directive
+                .node
+                .get_argument("reason")
+                .and_then(|reason| match &reason.node {
+                    crate::Value::String(reason) => Some(leak(reason.clone())),
+                    _ => None,
+                }),
+        },
+        None => Deprecation::NoDeprecated,
+    }
+}
+
+fn input_values(
+    values: Vec<Positioned<InputValueDefinition>>,
+    referenced: &mut Vec<(String, Pos)>,
+) -> IndexMap<&'static str, MetaInputValue> {
+    values
+        .into_iter()
+        .map(|value| {
+            let value = value.node;
+            referenced.push((value.ty.node.to_string(), value.ty.pos));
+            let name = leak(value.name.node.into_string());
+            (
+                name,
+                MetaInputValue {
+                    name,
+                    description: description(value.description),
+                    ty: value.ty.node.to_string(),
+                    default_value: value.default_value.map(|value| value.node.to_string()),
+                    validator: None,
+                    is_secret: false,
+                },
+            )
+        })
+        .collect()
+}
+
+fn fields(
+    fields: Vec<Positioned<FieldDefinition>>,
+    referenced: &mut Vec<(String, Pos)>,
+) -> IndexMap<String, MetaField> {
+    fields
+        .into_iter()
+        .map(|field| {
+            let field = field.node;
+            referenced.push((field.ty.node.to_string(), field.ty.pos));
+            (
+                field.name.node.to_string(),
+                MetaField {
+                    name: field.name.node.to_string(),
+                    description: description(field.description),
+                    args: input_values(field.arguments, referenced),
+                    ty: field.ty.node.to_string(),
+                    deprecation: deprecation(&field.directives),
+                    cache_control: Default::default(),
+                    external: false,
+                    requires: None,
+                    provides: None,
+                    inherit_max_age: false,
+                    complexity: 1,
+                },
+            )
+        })
+        .collect()
+}
+
+impl Registry {
+    /// Register the types defined in an SDL document.
+    ///
+    /// Object and interface types that already exist can be extended with `extend type` and
+    /// `extend interface`. Schema and directive definitions are ignored.
+    pub(crate) fn import_sdl(&mut self, sdl: &str) -> Result<()> {
+        let document = parse_schema(sdl)?;
+        let mut referenced = Vec::new();
+        let mut implements = Vec::new();
+        let mut extended_interfaces = Vec::new();
+
+        for definition in document.definitions {
+            if let TypeSystemDefinition::Type(ty) = definition {
+                self.import_type(
+                    ty,
+                    &mut referenced,
+                    &mut implements,
+                    &mut extended_interfaces,
+                )?;
+            }
+        }
+
+        // The interfaces can be defined after the types that implement them.
+        for (ty, interface) in implements {
+            match self.types.get_mut(interface.node.as_str()) {
+                Some(MetaType::Interface { possible_types, .. }) => {
+                    possible_types.insert(ty.clone());
+                }
+                _ => {
+                    return Err(Error::new(
+                        format!("Unknown interface \"{}\"", interface.node),
+                        interface.pos,
+                    ))
+                }
+            }
+            self.add_implements(&ty, &interface.node);
+            extended_interfaces.push((interface.node.to_string(), interface.pos));
+        }
+
+        for (ty, pos) in referenced {
+            let name = MetaTypeName::concrete_typename(&ty);
+            if !self.types.contains_key(name) {
+                return Err(Error::new(format!("Unknown type \"{}\"", name), pos));
+            }
+        }
+
+        // The types that implement the interfaces must have their new fields.
+        for (interface, pos) in extended_interfaces {
+            self.check_interface(&interface)
+                .map_err(|err| Error::new(err, pos))?;
+        }
+        Ok(())
+    }
+
+    fn import_type(
+        &mut self,
+        ty: Positioned<TypeDefinition>,
+        referenced: &mut Vec<(String, Pos)>,
+        implements: &mut Vec<(String, Positioned<Name>)>,
+        extended_interfaces: &mut Vec<(String, Pos)>,
+    ) -> Result<()> {
+        let pos = ty.pos;
+        let TypeDefinition {
+            extend,
+            description: desc,
+            name,
+            directives,
+            kind,
+        } = ty.node;
+        let name = name.node.to_string();
+
+        if extend {
+            let (new_fields, interfaces) = match kind {
+                TypeKind::Object(object) => (object.fields, object.implements),
+                TypeKind::Interface(interface) => (interface.fields, Vec::new()),
+                _ => {
+                    return Err(Error::new(
+                        "Only object and interface types can be extended",
+                        pos,
+                    ))
+                }
+            };
+            let existing_fields = match self.types.get(&name) {
+                Some(MetaType::Object { fields, .. }) => fields,
+                Some(MetaType::Interface { fields, .. }) => {
+                    extended_interfaces.push((name.clone(), pos));
+                    fields
+                }
+                _ => {
+                    return Err(Error::new(
+                        format!("Unknown object or interface type \"{}\"", name),
+                        pos,
+                    ))
+                }
+            };
+            let mut field_names = HashSet::new();
+            for field in &new_fields {
+                let field_name = field.node.name.node.as_str();
+                if existing_fields.contains_key(field_name) || !field_names.insert(field_name) {
+                    return Err(Error::new(
+                        format!("Field \"{}.{}\" already exists", name, field_name),
+                        field.pos,
+                    ));
+                }
+            }
+            let new_fields = fields(new_fields, referenced);
+            if let Some(MetaType::Object { fields, .. })
+            | Some(MetaType::Interface { fields, .. }) = self.types.get_mut(&name)
+            {
+                fields.extend(new_fields);
+            }
+            implements.extend(
+                interfaces
+                    .into_iter()
+                    .map(|interface| (name.clone(), interface)),
+            );
+            return Ok(());
+        }
+
+        if self.types.contains_key(&name) {
+            return Err(Error::new(format!("Type \"{}\" already exists", name), pos));
+        }
+
+        let description = description(desc);
+        let meta_type = match kind {
+            TypeKind::Scalar => MetaType::Scalar {
+                name: name.clone(),
+                description,
+                is_valid: |_| true,
+            },
+            TypeKind::Object(object) => {
+                implements.extend(
+                    object
+                        .implements
+                        .into_iter()
+                        .map(|interface| (name.clone(), interface)),
+                );
+                MetaType::Object {
+                    name: name.clone(),
+                    description,
+                    fields: fields(object.fields, referenced),
+                    cache_control: Default::default(),
+                    extends: false,
+                    keys: None,
+                }
+            }
+            TypeKind::Interface(interface) => MetaType::Interface {
+                name: name.clone(),
+                description,
+                fields: fields(interface.fields, referenced),
+                possible_types: IndexSet::new(),
+                extends: false,
+                keys: None,
+            },
+            TypeKind::Union(union_type) => MetaType::Union {
+                name: name.clone(),
+                description,
+                possible_types: union_type
+                    .members
+                    .into_iter()
+                    .map(|member| {
+                        referenced.push((member.node.to_string(), member.pos));
+                        member.node.to_string()
+                    })
+                    .collect(),
+            },
+            TypeKind::Enum(enum_type) => MetaType::Enum {
+                name: name.clone(),
+                description,
+                enum_values: enum_type
+                    .values
+                    .into_iter()
+                    .map(|value| {
+                        let value = value.node;
+                        let name = leak(value.value.node.into_string());
+                        (
+                            name,
+                            MetaEnumValue {
+                                name,
+                                description: description(value.description),
+                                deprecation: deprecation(&value.directives),
+                                int_value: None,
+                            },
+                        )
+                    })
+                    .collect(),
+                case_insensitive: false,
+            },
+            TypeKind::InputObject(input_object) => MetaType::InputObject {
+                name: name.clone(),
+                description,
+                input_fields: input_values(input_object.fields, referenced)
+                    .into_iter()
+                    .map(|(name, value)| (name.to_string(), value))
+                    .collect(),
+                one_of: directives
+                    .iter()
+                    .any(|directive| directive.node.name.node == "oneOf"),
+                deny_unknown_fields: true,
+            },
+        };
+        self.types.insert(name, meta_type);
+        Ok(())
+    }
+}
@@ -0,0 +1,345 @@
+use crate::registry::{
+    Deprecation, MetaDirective, MetaEnumValue, MetaField, MetaInputValue, MetaType, Registry,
+};
+use indexmap::IndexMap;
+use serde::Serialize;
+
+/// A canonical, serializable snapshot of a schema.
+///
+/// Types are sorted by name, so snapshots of the same schema are always equal and serialize
+/// to the same output, which makes them suitable for snapshot tests.
+/// Introspection types and fields (whose names start with `__`) are not included.
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaSnapshot {
+    /// Name of the query root type.
+    pub query_type: String,
+
+    /// Name of the mutation root type.
+    pub mutation_type: Option<String>,
+
+    /// Name of the subscription root type.
+    pub subscription_type: Option<String>,
+
+    /// All types of the schema, sorted by name.
+    pub types: Vec<TypeSnapshot>,
+
+    /// All directives of the schema, sorted by name.
+    pub directives: Vec<DirectiveSnapshot>,
+}
+
+/// A type in a [`SchemaSnapshot`](struct.SchemaSnapshot.html).
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+#[serde(tag = "kind", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TypeSnapshot {
+    /// A scalar type.
+    Scalar {
+        /// Type name.
+        name: String,
+        /// Type description.
+        description: Option<String>,
+    },
+    /// An object type.
+    Object {
+        /// Type name.
+        name: String,
+        /// Type description.
+        description: Option<String>,
+        /// Fields in declaration order.
+        fields: Vec<FieldSnapshot>,
+        /// Implemented interfaces, sorted by name.
+        interfaces: Vec<String>,
+    },
+    /// An interface type.
+    #[serde(rename_all = "camelCase")]
+    Interface {
+        /// Type name.
+        name: String,
+        /// Type description.
+        description: Option<String>,
+        /// Fields in declaration order.
+        fields: Vec<FieldSnapshot>,
+        /// Possible types, sorted by name.
+        possible_types: Vec<String>,
+    },
+    /// A union type.
+    #[serde(rename_all = "camelCase")]
+    Union {
+        /// Type name.
+        name: String,
+        /// Type description.
+        description: Option<String>,
+        /// Possible types, sorted by name.
+        possible_types: Vec<String>,
+    },
+    /// An enum type.
+    #[serde(rename_all = "camelCase")]
+    Enum {
+        /// Type name.
+        name: String,
+        /// Type description.
+        description: Option<String>,
+        /// Enum values in declaration order.
+        enum_values: Vec<EnumValueSnapshot>,
+    },
+    /// An input object type.
+    #[serde(rename_all = "camelCase")]
+    InputObject {
+        /// Type name.
+        name: String,
+        /// Type description.
+        description: Option<String>,
+        /// Input fields in declaration order.
+        input_fields: Vec<InputValueSnapshot>,
+        /// Whether exactly one of the fields must be provided.
+        one_of: bool,
+    },
+}
+
+/// A field in a [`SchemaSnapshot`](struct.SchemaSnapshot.html).
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct FieldSnapshot {
+    /// Field name.
+    pub name: String,
+
+    /// Field description.
+    pub description: Option<String>,
+
+    /// Arguments in declaration order.
+    pub args: Vec<InputValueSnapshot>,
+
+    /// Field type, e.g. `[String!]!`.
+    #[serde(rename = "type")]
+    pub ty: String,
+
+    /// Deprecation reason, if the field is deprecated.
+    pub deprecation: Option<String>,
+}
+
+/// An argument or input field in a [`SchemaSnapshot`](struct.SchemaSnapshot.html).
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InputValueSnapshot {
+    /// Name.
+    pub name: String,
+
+    /// Description.
+    pub description: Option<String>,
+
+    /// Type, e.g. `[String!]!`.
+    #[serde(rename = "type")]
+    pub ty: String,
+
+    /// Default value in GraphQL syntax.
+    pub default_value: Option<String>,
+}
+
+/// A directive in a [`SchemaSnapshot`](struct.SchemaSnapshot.html).
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct DirectiveSnapshot {
+    /// Directive name, without the leading `@`.
+    pub name: String,
+
+    /// Directive description.
+    pub description: Option<String>,
+
+    /// Locations where the directive may be used, e.g. `FIELD`.
+    pub locations: Vec<String>,
+
+    /// Arguments in declaration order.
+    pub args: Vec<InputValueSnapshot>,
+}
+
+/// An enum value in a [`SchemaSnapshot`](struct.SchemaSnapshot.html).
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct EnumValueSnapshot {
+    /// Value name.
+    pub name: String,
+
+    /// Value description.
+    pub description: Option<String>,
+
+    /// Deprecation reason, if the value is deprecated.
+    pub deprecation: Option<String>,
+}
+
+impl Registry {
+    pub fn snapshot(&self) -> SchemaSnapshot {
+        let mut types = self
+            .types
+            .values()
+            .filter(|ty| !ty.name().starts_with("__"))
+            .map(|ty| self.type_snapshot(ty))
+            .collect::<Vec<_>>();
+        types.sort_by(|a, b| a.name().cmp(b.name()));
+
+        let mut directives = self
+            .directives
+            .values()
+            .map(directive_snapshot)
+            .collect::<Vec<_>>();
+        directives.sort_by(|a, b| a.name.cmp(&b.name));
+
+        SchemaSnapshot {
+            query_type: self.query_type.clone(),
+            mutation_type: self.mutation_type.clone(),
+            subscription_type: self.subscription_type.clone(),
+            types,
+            directives,
+        }
+    }
+
+    fn type_snapshot(&self, ty: &MetaType) -> TypeSnapshot {
+        match ty {
+            MetaType::Scalar {
+                name, description, ..
+            } => TypeSnapshot::Scalar {
+                name: name.clone(),
+                description: description.map(ToString::to_string),
+            },
+            MetaType::Object {
+                name,
+                description,
+                fields,
+                ..
+            } => TypeSnapshot::Object {
+                name: name.clone(),
+                description: description.map(ToString::to_string),
+                fields: self.fields_snapshot(fields),
+                interfaces: self
+                    .implements
+                    .get(name)
+                    .map(|interfaces| sorted(interfaces.iter()))
+                    .unwrap_or_default(),
+            },
+            MetaType::Interface {
+                name,
+                description,
+                fields,
+                possible_types,
+                ..
+            } => TypeSnapshot::Interface {
+                name: name.clone(),
+                description: description.map(ToString::to_string),
+                fields: self.fields_snapshot(fields),
+                possible_types: sorted(possible_types.iter()),
+            },
+            MetaType::Union {
+                name,
+                description,
+                possible_types,
+            } => TypeSnapshot::Union {
+                name: name.clone(),
+                description: description.map(ToString::to_string),
+                possible_types: sorted(possible_types.iter()),
+            },
+            MetaType::Enum {
+                name,
+                description,
+                enum_values,
+                ..
+            } => TypeSnapshot::Enum {
+                name: name.clone(),
+                description: description.map(ToString::to_string),
+                enum_values: enum_values
+                    .values()
+                    .map(|value| self.enum_value_snapshot(value))
+                    .collect(),
+            },
+            MetaType::InputObject {
+                name,
+                description,
+                input_fields,
+                one_of,
+                ..
+            } => TypeSnapshot::InputObject {
+                name: name.clone(),
+                description: description.map(ToString::to_string),
+                input_fields: input_fields.values().map(input_value_snapshot).collect(),
+                one_of: *one_of,
+            },
+        }
+    }
+
+    fn fields_snapshot(&self, fields: &IndexMap<String, MetaField>) -> Vec<FieldSnapshot> {
+        fields
+            .values()
+            .filter(|field| !field.name.starts_with("__"))
+            .map(|field| self.field_snapshot(field))
+            .collect()
+    }
+
+    fn field_snapshot(&self, field: &MetaField) -> FieldSnapshot {
+        FieldSnapshot {
+            name: field.name.clone(),
+            description: field.description.map(ToString::to_string),
+            args: field.args.values().map(input_value_snapshot).collect(),
+            ty: field.ty.clone(),
+            deprecation: self.deprecation_snapshot(field.deprecation),
+        }
+    }
+
+    fn enum_value_snapshot(&self, value: &MetaEnumValue) -> EnumValueSnapshot {
+        EnumValueSnapshot {
+            name: value.name.to_string(),
+            description: value.description.map(ToString::to_string),
+            deprecation: self.deprecation_snapshot(value.deprecation),
+        }
+    }
+
+    /// Deprecations without a reason have the default reason of the schema. Without one, they
+    /// have the default reason of the `@deprecated` directive, so that they are still recorded.
+    fn deprecation_snapshot(&self, deprecation: Deprecation) -> Option<String> {
+        match deprecation {
+            Deprecation::NoDeprecated => None,
+            Deprecation::Deprecated { reason } => Some(
+                reason
+                    .map(ToString::to_string)
+                    .or_else(|| self.default_deprecation_reason.clone())
+                    .unwrap_or_else(|| "No longer supported".to_string()),
+            ),
+        }
+    }
+}
+
+impl TypeSnapshot {
+    /// Returns the name of the type.
+    pub fn name(&self) -> &str {
+        match self {
+            TypeSnapshot::Scalar { name, .. } => name,
+            TypeSnapshot::Object { name, .. } => name,
+            TypeSnapshot::Interface { name, .. } => name,
+            TypeSnapshot::Union { name, .. } => name,
+            TypeSnapshot::Enum { name, .. } => name,
+            TypeSnapshot::InputObject { name, .. } => name,
+        }
+    }
+}
+
+fn sorted<'a>(names: impl Iterator<Item = &'a String>) -> Vec<String> {
+    let mut names = names.cloned().collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+fn input_value_snapshot(value: &MetaInputValue) -> InputValueSnapshot {
+    InputValueSnapshot {
+        name: value.name.to_string(),
+        description: value.description.map(ToString::to_string),
+        ty: value.ty.clone(),
+        default_value: value.default_value.clone(),
+    }
+}
+
+fn directive_snapshot(directive: &MetaDirective) -> DirectiveSnapshot {
+    DirectiveSnapshot {
+        name: directive.name.to_string(),
+        description: directive.description.map(ToString::to_string),
+        locations: directive
+            .locations
+            .iter()
+            .map(|location| format!("{:?}", location))
+            .collect(),
+        args: directive.args.values().map(input_value_snapshot).collect(),
+    }
+}
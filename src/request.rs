@@ -1,29 +1,53 @@
 use crate::parser::types::UploadValue;
 use crate::{Data, Value, Variables};
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, Serialize};
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::fs::File;
+use std::time::Duration;
 
 /// GraphQL request.
 ///
-/// This can be deserialized from a structure of the query string, the operation name and the
-/// variables. The names are all in `camelCase` (e.g. `operationName`).
-#[derive(Debug, Deserialize)]
+/// This can be deserialized from a structure of the query string, the operation name, the
+/// variables and the extensions, and serialized back to the same structure. The names are all in
+/// `camelCase` (e.g. `operationName`). Empty variables and extensions are not serialized.
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Request {
     /// The query source of the request.
     pub query: String,
     /// The operation name of the request.
-    #[serde(default, rename = "operationName")]
+    #[serde(
+        default,
+        rename = "operationName",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub operation_name: Option<String>,
     /// The variables of the request.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Variables::is_empty")]
     pub variables: Variables,
+    /// The extensions of the request, such as `persistedQuery`.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_null_default",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub extensions: BTreeMap<String, serde_json::Value>,
     /// The data of the request that can be accessed through `Context::data`.
     ///
     /// **This data is only valid for this request**
     #[serde(skip)]
     pub data: Data,
+    /// The raw body of the HTTP request, which can be accessed through `Context::raw_body`.
+    ///
+    /// It is not set by default, integrations or users can set it when the raw bytes are needed,
+    /// for example to verify the signature of a webhook.
+    #[serde(skip)]
+    pub raw_body: Option<Vec<u8>>,
+    /// The maximum duration of the execution of this request, which takes precedence over
+    /// `SchemaBuilder::execution_timeout`.
+    #[serde(skip)]
+    pub timeout: Option<Duration>,
 }
 
 impl Request {
@@ -33,7 +57,10 @@ impl Request {
             query: query.into(),
             operation_name: None,
             variables: Variables::default(),
+            extensions: BTreeMap::new(),
             data: Data::default(),
+            raw_body: None,
+            timeout: None,
         }
     }
 
@@ -50,12 +77,38 @@ impl Request {
         Self { variables, ..self }
     }
 
+    /// Set an extension of the request.
+    pub fn extension(mut self, name: impl Into<String>, value: serde_json::Value) -> Self {
+        self.extensions.insert(name.into(), value);
+        self
+    }
+
     /// Insert some data for this request.
+    ///
+    /// It takes precedence over data of the same type added with `SchemaBuilder::data`.
     pub fn data<D: Any + Send + Sync>(mut self, data: D) -> Self {
         self.data.insert(data);
         self
     }
 
+    /// Set the raw body of the HTTP request.
+    pub fn raw_body(self, raw_body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            raw_body: Some(raw_body.into()),
+            ..self
+        }
+    }
+
+    /// Set the maximum duration of the execution of this request.
+    ///
+    /// See `SchemaBuilder::execution_timeout` for what happens when it expires.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
     /// Set a variable to an upload value.
     ///
     /// `var_path` is a dot-separated path to the item that begins with `variables`, for example
@@ -81,6 +134,15 @@ impl Request {
     }
 }
 
+/// Deserialize `null` as the default value, since clients send it for missing values.
+fn deserialize_null_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 impl<T: Into<String>> From<T> for Request {
     fn from(query: T) -> Self {
         Self::new(query)
@@ -137,4 +199,38 @@ mod tests {
         assert!(request.operation_name.is_none());
         assert_eq!(request.query, "{ a b c }");
     }
+
+    #[test]
+    fn test_request_with_extensions() {
+        let body = json!({
+            "query": "query Q($id: ID!) { user(id: $id) { name } }",
+            "operationName": "Q",
+            "variables": { "id": "1" },
+            "extensions": {
+                "persistedQuery": {
+                    "version": 1,
+                    "sha256Hash": "abc",
+                },
+                "tracing": true,
+            },
+        });
+        let request: Request = serde_json::from_value(body.clone()).unwrap();
+        assert_eq!(
+            request.extensions.get("tracing"),
+            Some(&serde_json::Value::Bool(true))
+        );
+        assert_eq!(serde_json::to_value(&request).unwrap(), body);
+
+        let body = json!({ "query": "{ a b c }" });
+        let request: Request = serde_json::from_value(body.clone()).unwrap();
+        assert!(request.extensions.is_empty());
+        assert_eq!(serde_json::to_value(&request).unwrap(), body);
+
+        let request: Request = serde_json::from_value(json!({
+            "query": "{ a b c }",
+            "extensions": null,
+        }))
+        .unwrap();
+        assert!(request.extensions.is_empty());
+    }
 }
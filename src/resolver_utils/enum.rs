@@ -19,6 +19,20 @@ pub trait EnumType: Type + Sized + Eq + Send + Copy + Sized + 'static {
 ///
 /// This can be used to implement `InputValueType::parse`.
 pub fn parse_enum<T: EnumType>(value: Value) -> InputValueResult<T> {
+    parse_enum_by(value, |name, value| name == value)
+}
+
+/// Parse a value as an enum value, ignoring the case of the variant names.
+///
+/// This can be used to implement `InputValueType::parse`.
+pub fn parse_enum_case_insensitive<T: EnumType>(value: Value) -> InputValueResult<T> {
+    parse_enum_by(value, |name, value| name.eq_ignore_ascii_case(value))
+}
+
+fn parse_enum_by<T: EnumType>(
+    value: Value,
+    eq: impl Fn(&str, &str) -> bool,
+) -> InputValueResult<T> {
     let value = match &value {
         Value::Enum(s) => s,
         Value::String(s) => s.as_str(),
@@ -27,14 +41,11 @@ pub fn parse_enum<T: EnumType>(value: Value) -> InputValueResult<T> {
 
     T::items()
         .iter()
-        .find(|item| item.name == value)
+        .find(|item| eq(item.name, value))
         .map(|item| item.value)
-        .ok_or_else(|| {
-            InputValueError::Custom(format!(
-                r#"Enumeration type "{}" does not contain the value "{}""#,
-                T::type_name(),
-                value,
-            ))
+        .ok_or_else(|| InputValueError::InvalidEnumValue {
+            ty: T::type_name().to_string(),
+            value: value.to_string(),
         })
 }
 
@@ -0,0 +1,22 @@
+use crate::parser::types::Field;
+use crate::{ContextSelectionSet, OutputValueType, Positioned, Result};
+
+/// Resolve a list by resolving each of its items concurrently.
+///
+/// Each item is resolved with its index pushed onto the query path, so errors thrown by an item
+/// point to it, e.g. `["users", 2, "email"]`.
+///
+/// This can be used to implement `OutputValueType::resolve` for list types.
+pub async fn resolve_list<'a, T: OutputValueType + Send + Sync + 'a>(
+    ctx: &ContextSelectionSet<'_>,
+    field: &Positioned<Field>,
+    iter: impl IntoIterator<Item = &'a T>,
+) -> Result<serde_json::Value> {
+    let iter = iter.into_iter();
+    let mut futures = Vec::with_capacity(iter.size_hint().0);
+    for (idx, item) in iter.enumerate() {
+        let ctx_idx = ctx.with_index(idx);
+        futures.push(async move { OutputValueType::resolve(item, &ctx_idx, field).await });
+    }
+    Ok(futures::future::try_join_all(futures).await?.into())
+}
@@ -1,7 +1,11 @@
 //! Utilities for implementing `OutputValueType::resolve`.
 
 mod r#enum;
+mod list;
 mod object;
+mod timeout;
 
+pub use list::*;
 pub use object::*;
 pub use r#enum::*;
+pub use timeout::*;
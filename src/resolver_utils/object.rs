@@ -1,8 +1,10 @@
 use crate::extensions::{ErrorLogger, Extension, ResolveInfo};
 use crate::parser::types::Selection;
 use crate::registry::MetaType;
+use crate::resolver_utils::resolve_before_deadline;
 use crate::{Context, ContextSelectionSet, Error, OutputValueType, QueryError, Result, Value};
 use futures::TryFutureExt;
+use itertools::Either;
 use std::future::Future;
 use std::pin::Pin;
 
@@ -63,15 +65,7 @@ pub async fn resolve_object<'a, T: ObjectType + Send + Sync>(
     let res = futures::future::try_join_all(futures).await?;
     let mut map = serde_json::Map::new();
     for (name, value) in res {
-        if let serde_json::Value::Object(b) = value {
-            if let Some(serde_json::Value::Object(a)) = map.get_mut(&name) {
-                a.extend(b);
-            } else {
-                map.insert(name, b.into());
-            }
-        } else {
-            map.insert(name, value);
-        }
+        merge_field(&mut map, name, value);
     }
     Ok(map.into())
 }
@@ -88,18 +82,43 @@ pub async fn resolve_object_serial<'a, T: ObjectType + Send + Sync>(
     let mut map = serde_json::Map::new();
     for field in futures {
         let (name, value) = field.await?;
+        merge_field(&mut map, name, value);
+    }
+    Ok(map.into())
+}
 
-        if let serde_json::Value::Object(b) = value {
-            if let Some(serde_json::Value::Object(a)) = map.get_mut(&name) {
-                a.extend(b);
-            } else {
-                map.insert(name, b.into());
-            }
-        } else {
+/// Add the value of a field to the response object.
+///
+/// The keys stay in the order in which they first appear in the selection set. A response key
+/// that is selected more than once, such as in a field and in a fragment, has its values merged
+/// into the first one.
+fn merge_field(
+    map: &mut serde_json::Map<String, serde_json::Value>,
+    name: String,
+    value: serde_json::Value,
+) {
+    match map.get_mut(&name) {
+        Some(existing) => merge_value(existing, value),
+        None => {
             map.insert(name, value);
         }
     }
-    Ok(map.into())
+}
+
+fn merge_value(target: &mut serde_json::Value, value: serde_json::Value) {
+    match (target, value) {
+        (serde_json::Value::Object(a), serde_json::Value::Object(b)) => {
+            for (name, value) in b {
+                merge_field(a, name, value);
+            }
+        }
+        (serde_json::Value::Array(a), serde_json::Value::Array(b)) => {
+            for (a, b) in a.iter_mut().zip(b) {
+                merge_value(a, b);
+            }
+        }
+        (target, value) => *target = value,
+    }
 }
 
 type BoxFieldFuture<'a> =
@@ -141,8 +160,11 @@ impl<'a> Fields<'a> {
                     }
 
                     if ctx.is_ifdef(&field.node.directives) {
-                        if let Some(MetaType::Object { fields, .. }) =
-                            ctx.schema_env.registry.types.get(T::type_name().as_ref())
+                        if let Some(MetaType::Object { fields, .. }) = ctx
+                            .schema_env
+                            .registry
+                            .types
+                            .get(root.introspection_type_name().as_ref())
                         {
                             if !fields.contains_key(field.node.name.node.as_str()) {
                                 continue;
@@ -155,6 +177,9 @@ impl<'a> Fields<'a> {
                         let ctx = ctx.clone();
                         async move {
                             let ctx_field = ctx.with_field(field);
+                            // The object type of the value, which is not `T` for values whose
+                            // type is only known at runtime, such as `TypedJson`.
+                            let type_name = root.introspection_type_name();
                             let field_name = ctx_field
                                 .item
                                 .node
@@ -167,12 +192,12 @@ impl<'a> Fields<'a> {
                                 resolve_id: ctx_field.resolve_id,
                                 path_node: ctx_field.path_node.as_ref().unwrap(),
                                 context: &ctx_field,
-                                parent_type: &T::type_name(),
+                                parent_type: &type_name,
                                 return_type: match ctx_field
                                     .schema_env
                                     .registry
                                     .types
-                                    .get(T::type_name().as_ref())
+                                    .get(type_name.as_ref())
                                     .and_then(|ty| ty.field_by_name(field.node.name.node.as_str()))
                                     .map(|field| &field.ty)
                                 {
@@ -188,7 +213,7 @@ impl<'a> Fields<'a> {
                                                     .node
                                                     .clone()
                                                     .into_string(),
-                                                object: T::type_name().to_string(),
+                                                object: type_name.to_string(),
                                             },
                                         })
                                     }
@@ -201,8 +226,25 @@ impl<'a> Fields<'a> {
                                 .lock()
                                 .resolve_start(&resolve_info);
 
-                            let res = root
-                                .resolve_field(&ctx_field)
+                            let non_null = resolve_info.return_type.ends_with('!');
+                            let resolve = async {
+                                let value = root.resolve_field(&ctx_field).await?;
+                                if non_null && value.is_null() {
+                                    return Err(Error::Query {
+                                        pos: field.pos,
+                                        path: ctx_field
+                                            .path_node
+                                            .as_ref()
+                                            .and_then(|path| serde_json::to_value(path).ok()),
+                                        err: QueryError::NullValueForNonNullField {
+                                            object: type_name.to_string(),
+                                            field_name: field.node.name.node.to_string(),
+                                        },
+                                    });
+                                }
+                                Ok(value)
+                            };
+                            let res = resolve_before_deadline(&ctx_field, non_null, resolve)
                                 .map_ok(move |value| (field_name, value))
                                 .await
                                 .log_error(&ctx_field.query_env.extensions)?;
@@ -217,7 +259,7 @@ impl<'a> Fields<'a> {
                     }));
                 }
                 selection => {
-                    let (type_condition, selection_set) = match selection {
+                    let (type_condition, selection_set, directives) = match selection {
                         Selection::Field(_) => unreachable!(),
                         Selection::FragmentSpread(spread) => {
                             let fragment = ctx
@@ -240,11 +282,19 @@ impl<'a> Fields<'a> {
                             (
                                 Some(&fragment.node.type_condition),
                                 &fragment.node.selection_set,
+                                Either::Left(
+                                    spread
+                                        .node
+                                        .directives
+                                        .iter()
+                                        .chain(fragment.node.directives.iter()),
+                                ),
                             )
                         }
                         Selection::InlineFragment(fragment) => (
                             fragment.node.type_condition.as_ref(),
                             &fragment.node.selection_set,
+                            Either::Right(fragment.node.directives.iter()),
                         ),
                     };
                     let type_condition =
@@ -276,10 +326,13 @@ impl<'a> Fields<'a> {
                         //         # able to query InterfaceA fields.
                         //     }
                         // }
-                        root.collect_all_fields(&ctx.with_selection_set(selection_set), self)?;
+                        root.collect_all_fields(
+                            &ctx.with_fragment(selection_set, directives),
+                            self,
+                        )?;
                     } else if type_condition.map_or(true, |condition| T::type_name() == condition) {
                         // The fragment applies to an interface type.
-                        self.add_set(&ctx.with_selection_set(selection_set), root)?;
+                        self.add_set(&ctx.with_fragment(selection_set, directives), root)?;
                     }
                 }
             }
@@ -0,0 +1,86 @@
+use crate::runtime::sleep;
+use crate::{Context, Error, FieldError, FieldResult, Result};
+use futures::future::{BoxFuture, Either, FutureExt, Shared};
+use futures::Future;
+use std::time::Duration;
+
+/// Resolve a field, or return `None` if it takes longer than `timeout`.
+///
+/// This is used by the `timeout` attribute of object fields.
+pub async fn resolve_with_timeout<T>(
+    timeout: Duration,
+    future: impl Future<Output = FieldResult<T>>,
+) -> Option<FieldResult<T>> {
+    let delay = sleep(timeout);
+    futures::pin_mut!(future, delay);
+    match futures::future::select(future, delay).await {
+        Either::Left((res, _)) => Some(res),
+        Either::Right(_) => None,
+    }
+}
+
+/// Resolve a field that took longer than the `timeout` of its `timeout` attribute.
+pub fn resolve_field_timed_out(
+    ctx: &Context<'_>,
+    timeout: Duration,
+    non_null: bool,
+) -> Result<serde_json::Value> {
+    resolve_timed_out(
+        ctx,
+        non_null,
+        FieldError::from(format!("Field timed out after {:?}", timeout))
+            .into_error_with_path(ctx.item.pos, ctx.path_node.as_ref()),
+    )
+}
+
+/// The deadline of an execution with a timeout.
+pub(crate) struct Deadline {
+    timeout: Duration,
+    timer: Shared<BoxFuture<'static, ()>>,
+}
+
+impl Deadline {
+    pub(crate) fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            timer: sleep(timeout).boxed().shared(),
+        }
+    }
+}
+
+/// Resolve a field that timed out.
+///
+/// A nullable field is resolved to `null`, and the error is added to the response with the fields
+/// that were resolved in time. The error of a non-null field fails the query.
+fn resolve_timed_out(ctx: &Context<'_>, non_null: bool, err: Error) -> Result<serde_json::Value> {
+    if non_null {
+        return Err(err);
+    }
+    ctx.query_env.field_error.lock().get_or_insert(err);
+    Ok(serde_json::Value::Null)
+}
+
+/// Resolve a field, unless the execution reaches its deadline first.
+pub(crate) async fn resolve_before_deadline(
+    ctx: &Context<'_>,
+    non_null: bool,
+    future: impl Future<Output = Result<serde_json::Value>>,
+) -> Result<serde_json::Value> {
+    let deadline = match &ctx.query_env.deadline {
+        Some(deadline) => deadline,
+        None => return future.await,
+    };
+    futures::pin_mut!(future);
+    match futures::future::select(future, deadline.timer.clone()).await {
+        Either::Left((res, _)) => res,
+        Either::Right(_) => resolve_timed_out(
+            ctx,
+            non_null,
+            FieldError(
+                format!("Execution timed out after {:?}", deadline.timeout),
+                Some(serde_json::json!({ "code": "EXECUTION_TIMEOUT" })),
+            )
+            .into_error_with_path(ctx.item.pos, ctx.path_node.as_ref()),
+        ),
+    }
+}
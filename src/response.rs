@@ -14,6 +14,16 @@ pub struct Response {
 
     /// Error
     pub error: Option<Error>,
+
+    /// Path of the list item in a subsequent response of an incremental query
+    pub path: Option<serde_json::Value>,
+
+    /// Whether more responses follow, only set when the query is executed incrementally
+    pub has_next: Option<bool>,
+
+    /// Name of the executed operation, `None` for anonymous operations or if no operation was
+    /// executed. It is not serialized.
+    pub operation_name: Option<String>,
 }
 
 impl Response {
@@ -44,12 +54,44 @@ impl Response {
         }
     }
 
+    /// Set the error of the response, keeping its data.
+    ///
+    /// A response with both data and an error is a partial response, such as one that timed out.
+    #[must_use]
+    pub fn with_error(self, error: impl Into<Error>) -> Self {
+        Self {
+            error: Some(error.into()),
+            ..self
+        }
+    }
+
     /// Set the extensions result of the response.
     #[must_use]
     pub fn extensions(self, extensions: Option<serde_json::Value>) -> Self {
         Self { extensions, ..self }
     }
 
+    /// Add an entry to the extensions result of the response, replacing an entry with the same
+    /// name.
+    #[must_use]
+    pub fn with_extension(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        match &mut self.extensions {
+            Some(serde_json::Value::Object(extensions)) => {
+                extensions.insert(name.into(), value.into());
+            }
+            extensions => {
+                let mut map = serde_json::Map::new();
+                map.insert(name.into(), value.into());
+                *extensions = Some(serde_json::Value::Object(map));
+            }
+        }
+        self
+    }
+
     /// Set the cache control of the response.
     #[must_use]
     pub fn cache_control(self, cache_control: CacheControl) -> Self {
@@ -59,6 +101,33 @@ impl Response {
         }
     }
 
+    /// Set the path of the list item in a subsequent response of an incremental query.
+    #[must_use]
+    pub fn path(self, path: serde_json::Value) -> Self {
+        Self {
+            path: Some(path),
+            ..self
+        }
+    }
+
+    /// Set whether more responses follow this response.
+    #[must_use]
+    pub fn has_next(self, has_next: bool) -> Self {
+        Self {
+            has_next: Some(has_next),
+            ..self
+        }
+    }
+
+    /// Set the name of the executed operation.
+    #[must_use]
+    pub fn operation_name(self, operation_name: impl Into<String>) -> Self {
+        Self {
+            operation_name: Some(operation_name.into()),
+            ..self
+        }
+    }
+
     /// Returns `true` if the response is ok.
     #[inline]
     pub fn is_ok(&self) -> bool {
@@ -0,0 +1,35 @@
+//! Timers that don't depend on a specific async runtime.
+//!
+//! With only one of the `tokio` and `async-std` features the timer of that runtime is used,
+//! otherwise the timer of `futures-timer`, which runs on its own thread, so timed features work on
+//! any runtime. When both features are enabled, for example by different dependencies, it is not
+//! known which runtime executes the queries, so neither timer is used. The timer of tokio panics
+//! outside of a tokio runtime, so with the `tokio` feature the queries executed by another runtime
+//! use the timer of `futures-timer` too.
+
+use std::time::Duration;
+
+/// Wait until `duration` has elapsed.
+#[cfg(all(feature = "tokio", not(feature = "async-std")))]
+pub(crate) async fn sleep(duration: Duration) {
+    if tokio::runtime::Handle::try_current().is_ok() {
+        tokio::time::delay_for(duration).await
+    } else {
+        futures_timer::Delay::new(duration).await
+    }
+}
+
+/// Wait until `duration` has elapsed.
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+pub(crate) async fn sleep(duration: Duration) {
+    async_std::task::sleep(duration).await
+}
+
+/// Wait until `duration` has elapsed.
+#[cfg(any(
+    all(feature = "tokio", feature = "async-std"),
+    not(any(feature = "tokio", feature = "async-std"))
+))]
+pub(crate) async fn sleep(duration: Duration) {
+    futures_timer::Delay::new(duration).await
+}
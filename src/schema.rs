@@ -1,16 +1,22 @@
-use crate::context::{Data, ResolveId};
-use crate::extensions::{BoxExtension, ErrorLogger, Extension, Extensions};
+use crate::context::{Data, QueryPathNode, QueryPathSegment, ResolveId};
+use crate::extensions::{
+    BoxExtension, ComplexityReport, ErrorLogger, Extension, Extensions, PrepareRequestInfo,
+};
 use crate::model::__DirectiveLocation;
-use crate::parser::parse_query;
-use crate::parser::types::OperationType;
-use crate::registry::{MetaDirective, MetaInputValue, Registry};
+use crate::parser::types::{ExecutableDefinition, OperationType};
+use crate::parser::{parse_query_with_limits, ParseLimits};
+use crate::registry::{
+    MetaDirective, MetaInputValue, MetaType, Registry, SchemaSnapshot, SdlOptions,
+};
 use crate::resolver_utils::{resolve_object, resolve_object_serial, ObjectType};
 use crate::subscription::collect_subscription_streams;
 use crate::types::QueryRoot;
-use crate::validation::{check_rules, CheckResult, ValidationMode};
+use crate::validation::{
+    check_rules, secret_values, BoxVisitor, CheckResult, ValidationMode, Visitor,
+};
 use crate::{
-    CacheControl, ContextBase, Error, Pos, QueryEnv, QueryError, Request, Response, Result,
-    SubscriptionType, Type, Variables, ID,
+    CacheControl, ContextBase, Error, Pos, PrivateCacheKey, QueryEnv, QueryError, Request,
+    Response, Result, SubscriptionType, Type, ID,
 };
 use async_graphql_parser::types::ExecutableDocumentData;
 use futures::stream::{self, Stream, StreamExt};
@@ -20,6 +26,7 @@ use std::any::Any;
 use std::ops::Deref;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Schema builder
 pub struct SchemaBuilder<Query, Mutation, Subscription> {
@@ -31,7 +38,15 @@ pub struct SchemaBuilder<Query, Mutation, Subscription> {
     data: Data,
     complexity: Option<usize>,
     depth: Option<usize>,
+    complexity_report: bool,
+    query_bytes: Option<usize>,
+    parse_limits: ParseLimits,
+    execution_timeout: Option<Duration>,
     extensions: Vec<Box<dyn Fn() -> BoxExtension + Send + Sync>>,
+    validation_rules: Vec<Box<dyn Fn() -> BoxVisitor + Send + Sync>>,
+    on_response: Vec<Box<dyn Fn(Response) -> Response + Send + Sync>>,
+    on_scalar_serialize:
+        Vec<Box<dyn Fn(&str, serde_json::Value) -> serde_json::Value + Send + Sync>>,
     enable_federation: bool,
 }
 
@@ -40,18 +55,126 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
 {
     /// Manually register a type in the schema.
     ///
-    /// You can use this function to register schema types that are not directly referenced.
+    /// You can use this function to register schema types that are not directly referenced, such
+    /// as the implementations of an interface that is only returned as the interface, or types
+    /// that are only used as `_entities` representations. Registered types are visible to
+    /// introspection and in the schema snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// #[derive(GQLSimpleObject)]
+    /// struct Unreferenced {
+    ///     value: i32,
+    /// }
+    ///
+    /// struct QueryRoot;
+    ///
+    /// #[GQLObject]
+    /// impl QueryRoot {
+    ///     async fn value(&self) -> i32 {
+    ///         10
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+    ///     .register_type::<Unreferenced>()
+    ///     .finish();
+    /// assert!(schema.snapshot().types.iter().any(|ty| ty.name() == "Unreferenced"));
+    /// ```
     pub fn register_type<T: Type>(mut self) -> Self {
         T::create_type_info(&mut self.registry);
         self
     }
 
+    /// Register the object `T` as an implementor of the interface `I`, so that it can be returned
+    /// in a `DynInterface<I>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `I` is not an interface or `T` is not an object.
+    pub fn register_implementor<I: Type, T: ObjectType>(mut self) -> Self {
+        I::create_type_info(&mut self.registry);
+        T::create_type_info(&mut self.registry);
+        if !matches!(
+            self.registry.types.get(&*T::type_name()),
+            Some(MetaType::Object { .. })
+        ) {
+            panic!("\"{}\" is not an object.", T::type_name());
+        }
+        match self.registry.types.get_mut(&*I::type_name()) {
+            Some(MetaType::Interface { possible_types, .. }) => {
+                possible_types.insert(T::type_name().to_string());
+            }
+            _ => panic!("\"{}\" is not an interface.", I::type_name()),
+        }
+        self.registry
+            .add_implements(&T::type_name(), &I::type_name());
+        self
+    }
+
+    /// Register a custom directive, so that queries can use it.
+    ///
+    /// The executor ignores custom directives, and resolvers can read them with
+    /// `SelectionField::directives` and `SelectionField::fragment_directives`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a directive with the same name is already registered.
+    pub fn register_directive(mut self, directive: MetaDirective) -> Self {
+        if self.registry.directives.contains_key(directive.name) {
+            panic!("Directive \"{}\" is already registered.", directive.name);
+        }
+        self.registry.add_directive(directive);
+        self
+    }
+
+    /// Register the types defined in an SDL document, such as the schema of another service.
+    ///
+    /// The imported types are part of introspection and the SDL of the schema, and the existing
+    /// object and interface types can get more fields with `extend type` and `extend interface`.
+    /// They are not resolved by this schema, so queries that select them fail when they are
+    /// executed here, and they have to be delegated to the other service instead.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the SDL is invalid, if it defines a type or a field that already exists, if it
+    /// refers to types that don't exist, or if the types that implement an interface don't have
+    /// all its fields.
+    pub fn extend_with_sdl(mut self, sdl: &str) -> std::result::Result<Self, crate::parser::Error> {
+        self.registry.import_sdl(sdl)?;
+        Ok(self)
+    }
+
     /// Disable introspection queries.
     pub fn disable_introspection(mut self) -> Self {
         self.query.disable_introspection = true;
         self
     }
 
+    /// Disable the "Did you mean" suggestions in validation errors for unknown fields and arguments.
+    pub fn disable_suggestions(mut self) -> Self {
+        self.registry.enable_suggestions = false;
+        self
+    }
+
+    /// Set the deprecation reason that introspection returns for fields and enum values that are
+    /// deprecated without a reason. By default it is `null`.
+    pub fn default_deprecation_reason(mut self, reason: impl Into<String>) -> Self {
+        self.registry.default_deprecation_reason = Some(reason.into());
+        self
+    }
+
+    /// Set the cache control of the fields that don't have one, i.e. without a
+    /// `#[field(cache_control(...))]` attribute. Any cache control set on a field overrides it,
+    /// even `cache_control(max_age = 0)`.
+    pub fn default_cache_control(mut self, cache_control: CacheControl) -> Self {
+        self.registry.default_cache_control = Some(cache_control);
+        self
+    }
+
     /// Set the maximum complexity a query can have. By default there is no limit.
     pub fn limit_complexity(mut self, complexity: usize) -> Self {
         self.complexity = Some(complexity);
@@ -64,6 +187,57 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
         self
     }
 
+    /// Set the maximum duration of the execution of queries and mutations. By default there is no
+    /// limit.
+    ///
+    /// When the timeout expires, the nullable fields that haven't been resolved yet are `null`, and
+    /// the response has the data of the other fields and an error with the `EXECUTION_TIMEOUT`
+    /// code. If a non-null field hasn't been resolved, the query fails with that error instead.
+    /// The timeout can be overridden for a request with `Request::timeout`.
+    pub fn execution_timeout(mut self, timeout: Duration) -> Self {
+        self.execution_timeout = Some(timeout);
+        self
+    }
+
+    /// Report the complexity and depth of every query in the `complexityReport` response extension.
+    ///
+    /// Queries are not rejected unless `limit_complexity` or `limit_depth` is also set, so this can
+    /// be used to measure real traffic before choosing the limits.
+    ///
+    /// ```json
+    /// { "data": { ... }, "extensions": { "complexityReport": { "complexity": 5, "depth": 2 } } }
+    /// ```
+    pub fn with_complexity_report(mut self) -> Self {
+        self.complexity_report = true;
+        self
+    }
+
+    /// Set the maximum size in bytes of the query source. By default there is no limit.
+    ///
+    /// Larger queries are rejected before they are parsed.
+    pub fn limit_query_bytes(mut self, query_bytes: usize) -> Self {
+        self.query_bytes = Some(query_bytes);
+        self
+    }
+
+    /// Set the maximum nesting of list and object values in the query source. By default there is
+    /// no limit.
+    ///
+    /// Deeper values are rejected before the query is parsed, so they can't overflow the stack of
+    /// the parser.
+    pub fn limit_value_depth(mut self, value_depth: usize) -> Self {
+        self.parse_limits.max_value_depth = Some(value_depth);
+        self
+    }
+
+    /// Set the maximum number of tokens in the query source. By default there is no limit.
+    ///
+    /// Larger queries are rejected before they are parsed.
+    pub fn limit_tokens(mut self, tokens: usize) -> Self {
+        self.parse_limits.max_tokens = Some(tokens);
+        self
+    }
+
     /// Add an extension to the schema.
     pub fn extension<F: Fn() -> E + Send + Sync + 'static, E: Extension>(
         mut self,
@@ -74,7 +248,77 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
         self
     }
 
+    /// Add a custom validation rule to the schema.
+    ///
+    /// A new rule is created with `rule_factory` for every query. Custom rules run after the
+    /// built-in rules have passed, and are skipped in `ValidationMode::OnlyParse`.
+    pub fn add_validation_rule<F, V>(mut self, rule_factory: F) -> Self
+    where
+        F: Fn() -> V + Send + Sync + 'static,
+        V: for<'a> Visitor<'a> + 'static,
+    {
+        self.validation_rules
+            .push(Box::new(move || Box::new(rule_factory())));
+        self
+    }
+
+    /// Add a hook that transforms every response after the execution has finished.
+    ///
+    /// Hooks are called in the order they were added, before the response is returned from
+    /// `Schema::execute` or yielded by `Schema::execute_stream`, so they can for example remove
+    /// internal extensions before the response is serialized.
+    pub fn on_response<F: Fn(Response) -> Response + Send + Sync + 'static>(
+        mut self,
+        hook: F,
+    ) -> Self {
+        self.on_response.push(Box::new(hook));
+        self
+    }
+
+    /// Add a hook that transforms the output value of every scalar.
+    ///
+    /// The hook is called with the name of the scalar type and its serialized value, and returns
+    /// the value that is written to the response. Hooks are called in the order they were added.
+    /// Only the response is affected, resolvers and input values are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct QueryRoot;
+    ///
+    /// #[GQLObject]
+    /// impl QueryRoot {
+    ///     async fn price(&self) -> f64 {
+    ///         9.999
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async move {
+    ///     let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+    ///         .on_scalar_serialize(|type_name, value| match (type_name, value.as_f64()) {
+    ///             ("Float", Some(n)) => format!("{:.2}", n).parse::<f64>().unwrap().into(),
+    ///             _ => value,
+    ///         })
+    ///         .finish();
+    ///     let res = schema.execute("{ price }").await.into_result().unwrap().data;
+    ///     assert_eq!(res, serde_json::json!({ "price": 10.0 }));
+    /// });
+    /// ```
+    pub fn on_scalar_serialize<
+        F: Fn(&str, serde_json::Value) -> serde_json::Value + Send + Sync + 'static,
+    >(
+        mut self,
+        hook: F,
+    ) -> Self {
+        self.on_scalar_serialize.push(Box::new(hook));
+        self
+    }
+
     /// Add a global data that can be accessed in the `Schema`. You access it with `Context::data`.
+    ///
+    /// Data of the same type added with `Request::data` takes precedence for that request.
     pub fn data<D: Any + Send + Sync>(mut self, data: D) -> Self {
         self.data.insert(data);
         self
@@ -99,6 +343,10 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
             self.registry.create_federation_types();
         }
 
+        self.registry.remove_flattened_objects();
+        self.registry.remove_flattened_input_objects();
+        self.registry.check_interfaces();
+
         Schema(Arc::new(SchemaInner {
             validation_mode: self.validation_mode,
             query: self.query,
@@ -106,10 +354,17 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
             subscription: self.subscription,
             complexity: self.complexity,
             depth: self.depth,
+            complexity_report: self.complexity_report,
+            query_bytes: self.query_bytes,
+            parse_limits: self.parse_limits,
+            execution_timeout: self.execution_timeout,
             extensions: self.extensions,
+            validation_rules: self.validation_rules,
+            on_response: self.on_response,
             env: SchemaEnv(Arc::new(SchemaEnvInner {
                 registry: self.registry,
                 data: self.data,
+                on_scalar_serialize: self.on_scalar_serialize,
             })),
         }))
     }
@@ -119,12 +374,21 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
 pub struct SchemaEnvInner {
     pub registry: Registry,
     pub data: Data,
+    pub on_scalar_serialize:
+        Vec<Box<dyn Fn(&str, serde_json::Value) -> serde_json::Value + Send + Sync>>,
 }
 
 #[doc(hidden)]
 #[derive(Clone)]
 pub struct SchemaEnv(Arc<SchemaEnvInner>);
 
+impl SchemaEnv {
+    /// Returns a canonical snapshot of the schema.
+    pub fn snapshot(&self) -> SchemaSnapshot {
+        self.registry.snapshot()
+    }
+}
+
 impl Deref for SchemaEnv {
     type Target = SchemaEnvInner;
 
@@ -141,7 +405,13 @@ pub struct SchemaInner<Query, Mutation, Subscription> {
     pub(crate) subscription: Subscription,
     pub(crate) complexity: Option<usize>,
     pub(crate) depth: Option<usize>,
+    pub(crate) complexity_report: bool,
+    pub(crate) query_bytes: Option<usize>,
+    pub(crate) parse_limits: ParseLimits,
+    pub(crate) execution_timeout: Option<Duration>,
     pub(crate) extensions: Vec<Box<dyn Fn() -> BoxExtension + Send + Sync>>,
+    pub(crate) validation_rules: Vec<Box<dyn Fn() -> BoxVisitor + Send + Sync>>,
+    pub(crate) on_response: Vec<Box<dyn Fn(Response) -> Response + Send + Sync>>,
     pub(crate) env: SchemaEnv,
 }
 
@@ -210,6 +480,12 @@ where
             } else {
                 Some(Subscription::type_name().to_string())
             },
+            enable_suggestions: true,
+            default_deprecation_reason: None,
+            default_cache_control: None,
+            flattened_input_objects: Default::default(),
+            flattened_objects: Default::default(),
+            flattened_fields: Default::default(),
         };
 
         registry.add_directive(MetaDirective {
@@ -228,6 +504,7 @@ where
                     ty: "Boolean!".to_string(),
                     default_value: None,
                     validator: None,
+                    is_secret: false,
                 });
                 args
             }
@@ -249,6 +526,7 @@ where
                     ty: "Boolean!".to_string(),
                     default_value: None,
                     validator: None,
+                    is_secret: false,
                 });
                 args
             }
@@ -261,6 +539,33 @@ where
             args: Default::default(),
         });
 
+        registry.add_directive(MetaDirective {
+            name: "oneOf",
+            description: Some(
+                "Indicates that exactly one field of an input object must be provided.",
+            ),
+            locations: vec![__DirectiveLocation::INPUT_OBJECT],
+            args: Default::default(),
+        });
+
+        registry.add_directive(MetaDirective {
+            name: "stream",
+            description: Some("Directs the executor to deliver the items of a `ListStream` incrementally, after the initial response."),
+            locations: vec![__DirectiveLocation::FIELD],
+            args: {
+                let mut args = IndexMap::new();
+                args.insert("initialCount", MetaInputValue {
+                    name: "initialCount",
+                    description: Some("The number of items returned in the initial response."),
+                    ty: "Int!".to_string(),
+                    default_value: Some("0".to_string()),
+                    validator: None,
+                    is_secret: false,
+                });
+                args
+            }
+        });
+
         // register scalars
         bool::create_type_info(&mut registry);
         i32::create_type_info(&mut registry);
@@ -288,7 +593,14 @@ where
             data: Default::default(),
             complexity: None,
             depth: None,
+            complexity_report: false,
+            query_bytes: None,
+            parse_limits: Default::default(),
+            execution_timeout: None,
             extensions: Default::default(),
+            validation_rules: Default::default(),
+            on_response: Default::default(),
+            on_scalar_serialize: Default::default(),
             enable_federation: false,
         }
     }
@@ -302,35 +614,73 @@ where
         Self::build(query, mutation, subscription).finish()
     }
 
+    /// Returns a canonical snapshot of the schema.
+    ///
+    /// Unlike the SDL, the snapshot is structured and serializable, so it can be used in snapshot tests.
+    /// It is also the public, read-only view of the registered types and directives, for tools such as schema diffing.
+    pub fn snapshot(&self) -> SchemaSnapshot {
+        self.env.snapshot()
+    }
+
+    /// Returns the SDL of the schema, as in the `_service { sdl }` field of federated schemas.
+    ///
+    /// Multi-line descriptions are printed as block strings.
+    pub fn sdl(&self) -> String {
+        self.env.registry.create_federation_sdl()
+    }
+
+    /// Returns the SDL of the schema, printed with `options`.
+    pub fn sdl_with_options(&self, options: SdlOptions) -> String {
+        self.env.registry.create_sdl(&options)
+    }
+
+    fn create_extensions(&self) -> spin::Mutex<Extensions> {
+        spin::Mutex::new(Extensions(
+            self.0
+                .extensions
+                .iter()
+                .map(|factory| factory())
+                .collect_vec(),
+        ))
+    }
+
     fn prepare_request(
         &self,
-        request: &Request,
+        extensions: spin::Mutex<Extensions>,
+        request: &mut Request,
     ) -> Result<(
         ExecutableDocumentData,
         CacheControl,
         spin::Mutex<Extensions>,
     )> {
-        // create extension instances
-        let extensions = spin::Mutex::new(Extensions(
-            self.0
-                .extensions
-                .iter()
-                .map(|factory| factory())
-                .collect_vec(),
-        ));
+        if let Some(limit_query_bytes) = self.query_bytes {
+            if request.query.len() > limit_query_bytes {
+                return Err(QueryError::TooLarge.into_error(Pos::default())).log_error(&extensions);
+            }
+        }
 
+        extensions.lock().prepare_variables(&mut request.variables);
         extensions
             .lock()
             .parse_start(&request.query, &request.variables);
-        let document = parse_query(&request.query)
+        let document = parse_query_with_limits(&request.query, &self.parse_limits)
             .map_err(Into::<Error>::into)
             .log_error(&extensions)?;
+        if !extensions.lock().0.is_empty() {
+            let secrets = secret_values(&self.env.registry, &document);
+            if !secrets.is_empty() {
+                extensions.lock().redacted_query(
+                    &secrets.redact_query(&request.query),
+                    &secrets.redact_variables(&request.variables),
+                );
+            }
+        }
         extensions.lock().parse_end(&document);
 
         // check rules
         extensions.lock().validation_start();
         let CheckResult {
-            cache_control,
+            mut cache_control,
             complexity,
             depth,
         } = check_rules(
@@ -338,10 +688,25 @@ where
             &document,
             Some(&request.variables),
             self.validation_mode,
+            &self.validation_rules,
         )
         .log_error(&extensions)?;
         extensions.lock().validation_end();
 
+        if !cache_control.public {
+            cache_control.private_key = request
+                .data
+                .get::<PrivateCacheKey>()
+                .map(|key| key.0.clone());
+        }
+
+        if self.complexity_report {
+            extensions
+                .lock()
+                .0
+                .push(Box::new(ComplexityReport { complexity, depth }));
+        }
+
         // check limit
         if let Some(limit_complexity) = self.complexity {
             if complexity > limit_complexity {
@@ -356,6 +721,18 @@ where
             }
         }
 
+        if request.operation_name.is_none()
+            && document
+                .definitions
+                .iter()
+                .filter(|definition| matches!(definition, ExecutableDefinition::Operation(_)))
+                .count()
+                > 1
+        {
+            return Err(QueryError::OperationNameRequired.into_error(Pos::default()))
+                .log_error(&extensions);
+        }
+
         let document = match document.into_data(request.operation_name.as_deref()) {
             Some(document) => document,
             None => {
@@ -379,26 +756,32 @@ where
             }
         };
 
+        let res = extensions.lock().prepare_request(&PrepareRequestInfo {
+            operation_name: document
+                .operation
+                .node
+                .name
+                .as_ref()
+                .map(|name| &*name.node),
+            request_data: &request.data,
+            schema_data: &self.env.data,
+        });
+        res.log_error(&extensions)?;
+
         Ok((document, cache_control, extensions))
     }
 
-    async fn execute_once(
-        &self,
-        document: ExecutableDocumentData,
-        extensions: spin::Mutex<Extensions>,
-        variables: Variables,
-        ctx_data: Data,
-    ) -> Response {
+    async fn execute_once(&self, env: &QueryEnv) -> Response {
         // execute
         let inc_resolve_id = AtomicUsize::default();
-        let env = QueryEnv::new(extensions, variables, document, Arc::new(ctx_data));
         let ctx = ContextBase {
             path_node: None,
             resolve_id: ResolveId::root(),
             inc_resolve_id: &inc_resolve_id,
             item: &env.document.operation.node.selection_set,
             schema_env: &self.env,
-            query_env: &env,
+            query_env: env,
+            fragment_directives: Vec::new(),
         };
 
         env.extensions.lock().execution_start();
@@ -407,55 +790,148 @@ where
             OperationType::Query => resolve_object(&ctx, &self.query).await,
             OperationType::Mutation => resolve_object_serial(&ctx, &self.mutation).await,
             OperationType::Subscription => {
-                return Error::Query {
+                let mut response = Response::from(Error::Query {
                     pos: Pos::default(),
                     path: None,
                     err: QueryError::NotSupported,
-                }
-                .into()
+                });
+                response.operation_name = operation_name(&env.document);
+                return response;
             }
         };
 
         env.extensions.lock().execution_end();
         let extensions = env.extensions.lock().result();
 
-        Response::from_result(data).extensions(extensions)
+        let mut response = Response::from_result(data).extensions(extensions);
+        if let Some(err) = env.field_error.lock().take() {
+            if response.is_ok() {
+                response.error = Some(err);
+            }
+        }
+        response.operation_name = operation_name(&env.document);
+        response
+    }
+
+    fn apply_on_response(&self, response: Response) -> Response {
+        self.on_response
+            .iter()
+            .fold(response, |response, hook| hook(response))
     }
 
     /// Execute an GraphQL query.
     pub async fn execute(&self, request: impl Into<Request>) -> Response {
-        let request = request.into();
-        match self.prepare_request(&request) {
-            Ok((document, cache_control, extensions)) => self
-                .execute_once(document, extensions, request.variables, request.data)
-                .await
-                .cache_control(cache_control),
-            Err(e) => Response::from_error(e),
+        let mut request = request.into();
+        let extensions = self.create_extensions();
+        if let Some(response) = extensions.lock().cached_response(&request) {
+            return self.apply_on_response(response);
         }
+
+        let response = match self.prepare_request(extensions, &mut request) {
+            Ok((document, cache_control, extensions)) => {
+                let env = QueryEnv::new(
+                    extensions,
+                    request.variables,
+                    document,
+                    Arc::new(request.data),
+                    request.raw_body,
+                    request.timeout.or(self.execution_timeout),
+                );
+                self.execute_once(&env).await.cache_control(cache_control)
+            }
+            Err(e) => Response::from_error(e),
+        };
+        self.apply_on_response(response)
     }
 
+    /// Execute a request and yield its responses, with whether they are errors that end the
+    /// stream, such as a failed validation or the end of a subscription because of an error.
     pub(crate) fn execute_stream_with_ctx_data(
         &self,
         request: impl Into<Request>,
         ctx_data: Arc<Data>,
-    ) -> impl Stream<Item = Response> {
+    ) -> impl Stream<Item = (Response, bool)> {
         let schema = self.clone();
 
-        async_stream::stream! {
-            let request = request.into();
-            let (document, cache_control, extensions) = match schema.prepare_request(&request) {
+        let stream = async_stream::stream! {
+            let mut request = request.into();
+            let extensions = schema.create_extensions();
+            let cached_response = extensions.lock().cached_response(&request);
+            if let Some(response) = cached_response {
+                yield (response, false);
+                return;
+            }
+
+            let (document, cache_control, extensions) = match schema.prepare_request(extensions, &mut request) {
                 Ok(res) => res,
                 Err(err) => {
-                    yield Response::from(err);
+                    yield (Response::from(err), true);
                     return;
                 }
             };
+            let operation_name = operation_name(&document);
+            let named = |mut response: Response| {
+                response.operation_name = operation_name.clone();
+                response
+            };
 
             if document.operation.node.ty != OperationType::Subscription {
-                yield schema
-                    .execute_once(document, extensions, request.variables, request.data)
-                    .await
-                    .cache_control(cache_control);
+                let env = QueryEnv::new(
+                    extensions,
+                    request.variables,
+                    document,
+                    Arc::new(request.data),
+                    request.raw_body,
+                    request.timeout.or(schema.execution_timeout),
+                );
+                *env.pending_streams.lock() = Some(Vec::new());
+
+                let response = schema.execute_once(&env).await.cache_control(cache_control);
+                let pending_streams = env.pending_streams.lock().take().unwrap_or_default();
+                if pending_streams.is_empty() || response.is_err() {
+                    yield (response, false);
+                    return;
+                }
+                yield (response.has_next(true), false);
+
+                // Deliver the remaining items of the lists with the `@stream` directive.
+                let resolve_id = AtomicUsize::default();
+                for mut pending in pending_streams {
+                    let mut idx = pending.next_index;
+                    loop {
+                        let ctx = env.create_context(
+                            &schema.env,
+                            Some(QueryPathNode {
+                                parent: None,
+                                segment: QueryPathSegment::Index(idx),
+                            }),
+                            &pending.field.node.selection_set,
+                            &resolve_id,
+                        );
+                        match pending.items.resolve_next(&ctx, &pending.field).await {
+                            Some(Ok(data)) => {
+                                let mut path = pending.path.as_array().cloned().unwrap_or_default();
+                                path.push(idx.into());
+                                let mut response = Response::new(data).path(path.into()).has_next(true);
+                                response.error = env.field_error.lock().take();
+                                yield (named(response), false);
+                            }
+                            Some(Err(err)) => {
+                                yield (
+                                    named(
+                                        Response::from_error(prefix_error_path(err, &pending.path))
+                                            .has_next(false),
+                                    ),
+                                    false,
+                                );
+                                return;
+                            }
+                            None => break,
+                        }
+                        idx += 1;
+                    }
+                }
+                yield (named(Response::default().has_next(false)), false);
                 return;
             }
 
@@ -465,6 +941,8 @@ where
                 request.variables,
                 document,
                 ctx_data,
+                request.raw_body,
+                None,
             );
 
             let ctx = env.create_context(
@@ -474,24 +952,38 @@ where
                 &resolve_id,
             );
 
-            // TODO: Invoke extensions
-
             let mut streams = Vec::new();
             if let Err(e) = collect_subscription_streams(&ctx, &schema.subscription, &mut streams) {
-                yield Response::from(e);
+                yield (named(Response::from(e)), true);
                 return;
             }
 
+            env.extensions.lock().subscription_start();
+            let _end = SubscriptionEnd(&env.extensions);
+
             let mut stream = stream::select_all(streams);
-            while let Some(data) = stream.next().await {
-                let is_err = data.is_err();
+            while let Some(res) = stream.next().await {
+                let (response, is_end) = match res {
+                    Ok(mut response) => {
+                        if response.is_ok() {
+                            response.error = env.field_error.lock().take();
+                        }
+                        (response, false)
+                    }
+                    Err(err) => (Response::from(err), true),
+                };
+                let response = named(response);
+                env.extensions.lock().subscription_next(&response);
                 let extensions = env.extensions.lock().result();
-                yield Response::from_result(data).extensions(extensions);
-                if is_err {
+                yield (response.extensions(extensions), is_end);
+                if is_end {
                     break;
                 }
             }
-        }
+        };
+
+        let schema = self.clone();
+        stream.map(move |(response, is_end)| (schema.apply_on_response(response), is_end))
     }
 
     /// Execute an GraphQL subscription.
@@ -499,5 +991,44 @@ where
         let mut request = request.into();
         let ctx_data = std::mem::take(&mut request.data);
         self.execute_stream_with_ctx_data(request, Arc::new(ctx_data))
+            .map(|(response, _)| response)
+    }
+}
+
+/// Get the name of the executed operation.
+fn operation_name(document: &ExecutableDocumentData) -> Option<String> {
+    document
+        .operation
+        .node
+        .name
+        .as_ref()
+        .map(|name| name.node.to_string())
+}
+
+/// Calls `Extension::subscription_end` when the subscription ends, including when its stream is
+/// dropped before the end.
+struct SubscriptionEnd<'a>(&'a spin::Mutex<Extensions>);
+
+impl Drop for SubscriptionEnd<'_> {
+    fn drop(&mut self) {
+        self.0.lock().subscription_end();
+    }
+}
+
+/// Prefix the path of an error thrown by a streamed list item with the path of the list.
+fn prefix_error_path(err: Error, prefix: &serde_json::Value) -> Error {
+    match err {
+        Error::Query { pos, path, err } => {
+            let mut full_path = prefix.as_array().cloned().unwrap_or_default();
+            if let Some(serde_json::Value::Array(path)) = path {
+                full_path.extend(path);
+            }
+            Error::Query {
+                pos,
+                path: Some(full_path.into()),
+                err,
+            }
+        }
+        err => err,
     }
 }
@@ -0,0 +1,106 @@
+use crate::resolver_utils::ObjectType;
+use crate::{Pos, QueryError, Request, Response, Schema, SubscriptionType};
+use std::collections::HashMap;
+
+/// An object that can execute GraphQL requests.
+///
+/// It is implemented by every [`Schema`](struct.Schema.html), and it is object safe, so schemas
+/// with different root types can be stored together.
+#[async_trait::async_trait]
+pub trait SchemaExecutor: Send + Sync {
+    /// Execute a GraphQL request.
+    async fn execute(&self, request: Request) -> Response;
+}
+
+#[async_trait::async_trait]
+impl<Query, Mutation, Subscription> SchemaExecutor for Schema<Query, Mutation, Subscription>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+{
+    async fn execute(&self, request: Request) -> Response {
+        Schema::execute(self, request).await
+    }
+}
+
+/// A router that dispatches requests to one of several schemas.
+///
+/// This allows a single endpoint to serve multiple schemas, for example one per tenant.
+/// Each schema is registered with a key, and the key of a request is extracted by the function
+/// passed to `SchemaRouter::new`.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct QueryA;
+///
+/// #[GQLObject]
+/// impl QueryA {
+///     async fn value(&self) -> i32 {
+///         1
+///     }
+/// }
+///
+/// struct QueryB;
+///
+/// #[GQLObject]
+/// impl QueryB {
+///     async fn value(&self) -> String {
+///         "b".to_string()
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let router = SchemaRouter::new(|request| request.operation_name.clone())
+///         .schema("a", Schema::new(QueryA, EmptyMutation, EmptySubscription))
+///         .schema("b", Schema::new(QueryB, EmptyMutation, EmptySubscription));
+///
+///     let res = router.execute_with_key("b", "{ value }").await;
+///     assert_eq!(res.data, serde_json::json!({ "value": "b" }));
+/// });
+/// ```
+pub struct SchemaRouter {
+    schemas: HashMap<String, Box<dyn SchemaExecutor>>,
+    key: Box<dyn Fn(&Request) -> Option<String> + Send + Sync>,
+}
+
+impl SchemaRouter {
+    /// Create a router which selects the schema with the key returned by `key` for each request.
+    pub fn new(key: impl Fn(&Request) -> Option<String> + Send + Sync + 'static) -> Self {
+        Self {
+            schemas: Default::default(),
+            key: Box::new(key),
+        }
+    }
+
+    /// Add a schema with the key. If a schema with the same key exists, it is replaced.
+    pub fn schema(mut self, key: impl Into<String>, schema: impl SchemaExecutor + 'static) -> Self {
+        self.schemas.insert(key.into(), Box::new(schema));
+        self
+    }
+
+    /// Execute the request with the schema selected by its key.
+    pub async fn execute(&self, request: impl Into<Request>) -> Response {
+        let request = request.into();
+        match (self.key)(&request) {
+            Some(key) => self.execute_with_key(&key, request).await,
+            None => Response::from_error(QueryError::MissingSchemaKey.into_error(Pos::default())),
+        }
+    }
+
+    /// Execute the request with the schema of the key, without extracting the key from the request.
+    pub async fn execute_with_key(&self, key: &str, request: impl Into<Request>) -> Response {
+        match self.schemas.get(key) {
+            Some(schema) => schema.execute(request.into()).await,
+            None => Response::from_error(
+                QueryError::UnknownSchema {
+                    key: key.to_string(),
+                }
+                .into_error(Pos::default()),
+            ),
+        }
+    }
+}
@@ -7,17 +7,36 @@ impl Serialize for Response {
     fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
         match &self.error {
             None => {
-                let mut s = serializer
-                    .serialize_struct("Response", if self.extensions.is_some() { 2 } else { 1 })?;
-                s.serialize_field("data", &self.data)?;
+                // The final response of an incremental query only has `hasNext`.
+                let is_end = self.has_next.is_some() && self.path.is_none() && self.data.is_null();
+                let mut s = serializer.serialize_struct("Response", 4)?;
+                if !is_end {
+                    s.serialize_field("data", &self.data)?;
+                }
+                if let Some(path) = &self.path {
+                    s.serialize_field("path", path)?;
+                }
+                if let Some(has_next) = &self.has_next {
+                    s.serialize_field("hasNext", has_next)?;
+                }
                 if let Some(extensions) = &self.extensions {
                     s.serialize_field("extensions", extensions)?;
                 }
                 s.end()
             }
             Some(err) => {
-                let mut s = serializer.serialize_struct("Response", 1)?;
+                let mut s = serializer.serialize_struct("Response", 4)?;
+                // Only a partial response, such as one that timed out, has data with the error.
+                if !self.data.is_null() {
+                    s.serialize_field("data", &self.data)?;
+                }
                 s.serialize_field("errors", err)?;
+                if let Some(has_next) = &self.has_next {
+                    s.serialize_field("hasNext", has_next)?;
+                }
+                if let Some(extensions) = &self.extensions {
+                    s.serialize_field("extensions", extensions)?;
+                }
                 s.end()
             }
         }
@@ -73,10 +92,24 @@ impl Serialize for Error {
             Error::Rule { errors } => {
                 let mut seq = serializer.serialize_seq(Some(errors.len()))?;
                 for error in errors {
-                    seq.serialize_element(&serde_json::json!({
-                        "message": error.message,
-                        "locations": error.locations.iter().map(|pos| serde_json::json!({"line": pos.line, "column": pos.column})).collect_vec(),
-                    }))?;
+                    let mut map = serde_json::Map::new();
+
+                    map.insert("message".to_string(), error.message.clone().into());
+                    map.insert(
+                        "locations".to_string(),
+                        error
+                            .locations
+                            .iter()
+                            .map(|pos| serde_json::json!({"line": pos.line, "column": pos.column}))
+                            .collect_vec()
+                            .into(),
+                    );
+
+                    if let Some(obj @ serde_json::Value::Object(_)) = &error.extensions {
+                        map.insert("extensions".to_string(), obj.clone());
+                    }
+
+                    seq.serialize_element(&serde_json::Value::Object(map))?;
                 }
                 seq.end()
             }
@@ -97,6 +130,9 @@ mod tests {
             extensions: None,
             cache_control: Default::default(),
             error: None,
+            path: None,
+            has_next: None,
+            operation_name: None,
         };
         assert_eq!(
             serde_json::to_value(resp).unwrap(),
@@ -160,4 +196,36 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_response_builder() {
+        let resp = Response::new(json!({ "ok": true }))
+            .with_error(Error::Query {
+                pos: Pos { line: 1, column: 3 },
+                path: Some(json!(["value"])),
+                err: QueryError::FieldError {
+                    err: "MyErrorMessage".to_owned(),
+                    extended_error: None,
+                },
+            })
+            .with_extension("a", 1)
+            .with_extension("b", json!({ "c": true }))
+            .with_extension("a", 2);
+        assert!(resp.is_err());
+        assert_eq!(
+            serde_json::to_value(resp).unwrap(),
+            json!({
+                "data": { "ok": true },
+                "errors": [{
+                    "message": "MyErrorMessage",
+                    "locations": [{"line": 1, "column": 3}],
+                    "path": ["value"]
+                }],
+                "extensions": {
+                    "a": 2,
+                    "b": { "c": true }
+                }
+            })
+        );
+    }
 }
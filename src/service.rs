@@ -0,0 +1,31 @@
+//! `Schema` as a `tower::Service`, so it can be wrapped in tower middleware.
+
+use crate::resolver_utils::ObjectType;
+use crate::{Request, Response, Schema, SubscriptionType};
+use futures::future::BoxFuture;
+use std::convert::Infallible;
+use std::task::{Context, Poll};
+
+/// Executes a request with `Schema::execute`.
+///
+/// Execution errors are returned in the `Response`, so the service never fails.
+impl<Query, Mutation, Subscription> tower::Service<Request>
+    for Schema<Query, Mutation, Subscription>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Response, Infallible>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        let schema = self.clone();
+        Box::pin(async move { Ok(schema.execute(request).await) })
+    }
+}
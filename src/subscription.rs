@@ -1,5 +1,5 @@
 use crate::parser::types::{Selection, TypeCondition};
-use crate::{Context, ContextSelectionSet, Result, Type};
+use crate::{Context, ContextSelectionSet, Response, Result, Type};
 use futures::{Stream, StreamExt};
 use std::pin::Pin;
 
@@ -11,17 +11,21 @@ pub trait SubscriptionType: Type {
         false
     }
 
+    /// Create the stream of responses for the field.
+    ///
+    /// An `Ok` response is sent for a message, and can carry the error of the message; an `Err`
+    /// ends the subscription.
     #[doc(hidden)]
     fn create_field_stream<'a>(
         &'a self,
         ctx: &'a Context<'a>,
-    ) -> Pin<Box<dyn Stream<Item = Result<serde_json::Value>> + Send + 'a>>;
+    ) -> Pin<Box<dyn Stream<Item = Result<Response>> + Send + 'a>>;
 }
 
 pub(crate) fn collect_subscription_streams<'a, T: SubscriptionType + Send + Sync + 'static>(
     ctx: &ContextSelectionSet<'a>,
     root: &'a T,
-    streams: &mut Vec<Pin<Box<dyn Stream<Item = Result<serde_json::Value>> + Send + 'a>>>,
+    streams: &mut Vec<Pin<Box<dyn Stream<Item = Result<Response>> + Send + 'a>>>,
 ) -> Result<()> {
     for selection in &ctx.item.node.items {
         if ctx.is_skip(selection.node.directives())? {
@@ -46,7 +50,14 @@ pub(crate) fn collect_subscription_streams<'a, T: SubscriptionType + Send + Sync
                     .get(&fragment_spread.node.fragment_name.node)
                 {
                     collect_subscription_streams(
-                        &ctx.with_selection_set(&fragment.node.selection_set),
+                        &ctx.with_fragment(
+                            &fragment.node.selection_set,
+                            fragment_spread
+                                .node
+                                .directives
+                                .iter()
+                                .chain(&fragment.node.directives),
+                        ),
                         root,
                         streams,
                     )?;
@@ -61,14 +72,20 @@ pub(crate) fn collect_subscription_streams<'a, T: SubscriptionType + Send + Sync
                 {
                     if name.node.as_str() == T::type_name() {
                         collect_subscription_streams(
-                            &ctx.with_selection_set(&inline_fragment.node.selection_set),
+                            &ctx.with_fragment(
+                                &inline_fragment.node.selection_set,
+                                &inline_fragment.node.directives,
+                            ),
                             root,
                             streams,
                         )?;
                     }
                 } else {
                     collect_subscription_streams(
-                        &ctx.with_selection_set(&inline_fragment.node.selection_set),
+                        &ctx.with_fragment(
+                            &inline_fragment.node.selection_set,
+                            &inline_fragment.node.directives,
+                        ),
                         root,
                         streams,
                     )?;
@@ -83,7 +100,7 @@ impl<T: SubscriptionType + Send + Sync> SubscriptionType for &T {
     fn create_field_stream<'a>(
         &'a self,
         ctx: &'a Context<'a>,
-    ) -> Pin<Box<dyn Stream<Item = Result<serde_json::Value>> + Send + 'a>> {
+    ) -> Pin<Box<dyn Stream<Item = Result<Response>> + Send + 'a>> {
         T::create_field_stream(*self, ctx)
     }
 }
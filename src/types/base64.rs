@@ -0,0 +1,127 @@
+use crate::{GQLScalar, InputValueError, InputValueResult, ScalarType, Value};
+use std::ops::{Deref, DerefMut};
+
+/// A scalar that represents binary data as a base64 encoded string.
+///
+/// The input is a standard base64 string (with padding), and the output is a standard base64 string.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct Base64(pub Vec<u8>);
+
+impl Deref for Base64 {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Base64 {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Vec<u8>> for Base64 {
+    fn from(bytes: Vec<u8>) -> Self {
+        Base64(bytes)
+    }
+}
+
+impl From<&[u8]> for Base64 {
+    fn from(bytes: &[u8]) -> Self {
+        Base64(bytes.to_vec())
+    }
+}
+
+impl From<Base64> for Vec<u8> {
+    fn from(value: Base64) -> Self {
+        value.0
+    }
+}
+
+/// A scalar that represents binary data as a base64 encoded string.
+#[GQLScalar(internal, name = "Base64")]
+impl ScalarType for Base64 {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => base64::decode(&s)
+                .map(Base64)
+                .map_err(|err| InputValueError::Custom(format!("Invalid base64: {}", err))),
+            _ => Err(InputValueError::ExpectedType(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(base64::encode(&self.0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[async_std::test]
+    async fn test_base64_type() {
+        struct Query;
+
+        #[GQLObject(internal)]
+        impl Query {
+            async fn echo(&self, input: Base64) -> Base64 {
+                input
+            }
+
+            async fn bytes(&self) -> Base64 {
+                b"async-graphql".to_vec().into()
+            }
+
+            async fn len(&self, input: Base64) -> i32 {
+                input.len() as i32
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        assert_eq!(
+            schema
+                .execute(r#"{ bytes echo(input: "YXN5bmMtZ3JhcGhxbA==") }"#)
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            serde_json::json!({
+                "bytes": "YXN5bmMtZ3JhcGhxbA==",
+                "echo": "YXN5bmMtZ3JhcGhxbA==",
+            })
+        );
+
+        assert_eq!(
+            schema
+                .execute(r#"{ echo(input: "") len(input: "") }"#)
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            serde_json::json!({
+                "echo": "",
+                "len": 0,
+            })
+        );
+
+        let err = schema
+            .execute(r#"{ echo(input: "not base64!") }"#)
+            .await
+            .into_result()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Query {
+                err: QueryError::ParseInputValue { ref reason },
+                ..
+            } if reason.starts_with("Invalid base64")
+        ));
+    }
+}
@@ -151,11 +151,13 @@ where
                             description: Some("Information to aid in pagination."),
                             args: Default::default(),
                             ty: PageInfo::create_type_info(registry),
-                            deprecation: None,
+                            deprecation: Default::default(),
                             cache_control: Default::default(),
                             external: false,
                             requires: None,
                             provides: None,
+                            inherit_max_age: false,
+                            complexity: 1,
                         },
                     );
 
@@ -168,11 +170,13 @@ where
                             ty: <Option<Vec<Option<Edge<C, T, EE>>>> as Type>::create_type_info(
                                 registry,
                             ),
-                            deprecation: None,
+                            deprecation: Default::default(),
                             cache_control: Default::default(),
                             external: false,
                             requires: None,
                             provides: None,
+                            inherit_max_age: false,
+                            complexity: 1,
                         },
                     );
 
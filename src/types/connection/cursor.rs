@@ -7,6 +7,10 @@ use std::num::ParseIntError;
 ///
 /// A custom scalar that serializes as a string.
 /// https://relay.dev/graphql/connections.htm#sec-Cursor
+///
+/// Implement this trait to use your own cursor encoding with `Connection` and `connection::query`,
+/// for example a composite sort key. If `decode_cursor` fails, `connection::query` returns a
+/// `FieldError` with the message of the error.
 pub trait CursorType: Sized {
     /// Error type for `decode_cursor`.
     type Error: Display;
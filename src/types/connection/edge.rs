@@ -69,11 +69,13 @@ where
                             description: Some("The item at the end of the edge"),
                             args: Default::default(),
                             ty: T::create_type_info(registry),
-                            deprecation: None,
+                            deprecation: Default::default(),
                             cache_control: Default::default(),
                             external: false,
                             requires: None,
                             provides: None,
+                            inherit_max_age: false,
+                            complexity: 1,
                         },
                     );
 
@@ -84,11 +86,13 @@ where
                             description: Some("A cursor for use in pagination"),
                             args: Default::default(),
                             ty: String::create_type_info(registry),
-                            deprecation: None,
+                            deprecation: Default::default(),
                             cache_control: Default::default(),
                             external: false,
                             requires: None,
                             provides: None,
+                            inherit_max_age: false,
+                            complexity: 1,
                         },
                     );
 
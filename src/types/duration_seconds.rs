@@ -0,0 +1,101 @@
+use crate::{GQLScalar, InputValueError, InputValueResult, ScalarType, Value};
+use std::ops::{Deref, DerefMut};
+use std::time::Duration;
+
+/// A `std::time::Duration` that is serialized as a number of seconds, instead of an ISO-8601
+/// duration string.
+///
+/// Durations with a fraction of a second are serialized as floats, and both integers and floats
+/// are accepted as input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub struct DurationSeconds(pub Duration);
+
+impl Deref for DurationSeconds {
+    type Target = Duration;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for DurationSeconds {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Duration> for DurationSeconds {
+    fn from(duration: Duration) -> Self {
+        Self(duration)
+    }
+}
+
+/// The `DurationSeconds` scalar type represents a non-negative span of time in seconds.
+#[GQLScalar(internal, name = "DurationSeconds")]
+impl ScalarType for DurationSeconds {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::Number(n) => {
+                if let Some(secs) = n.as_u64() {
+                    Ok(DurationSeconds(Duration::from_secs(secs)))
+                } else {
+                    match n.as_f64() {
+                        Some(secs) if secs >= 0.0 && secs.is_finite() => {
+                            Ok(DurationSeconds(Duration::from_secs_f64(secs)))
+                        }
+                        _ => Err(InputValueError::from(
+                            "Only non-negative numbers are accepted.",
+                        )),
+                    }
+                }
+            }
+            _ => Err(InputValueError::ExpectedType(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::Number(_))
+    }
+
+    fn to_value(&self) -> Value {
+        if self.0.subsec_nanos() == 0 {
+            Value::Number(self.0.as_secs().into())
+        } else {
+            serde_json::Number::from_f64(self.0.as_secs_f64())
+                .map(Value::Number)
+                .unwrap_or(Value::Null)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_duration_seconds_round_trip() {
+        for duration in &[
+            Duration::from_secs(0),
+            Duration::from_secs(5400),
+            Duration::from_millis(2500),
+        ] {
+            let value = ScalarType::to_value(&DurationSeconds(*duration));
+            assert_eq!(
+                <DurationSeconds as ScalarType>::parse(value).unwrap(),
+                DurationSeconds(*duration)
+            );
+        }
+
+        assert_eq!(
+            ScalarType::to_value(&DurationSeconds(Duration::from_secs(5400))),
+            Value::Number(5400.into())
+        );
+        assert_eq!(
+            ScalarType::to_value(&DurationSeconds(Duration::from_millis(2500))),
+            Value::Number(serde_json::Number::from_f64(2.5).unwrap())
+        );
+        assert!(<DurationSeconds as ScalarType>::parse(Value::Number((-1).into())).is_err());
+        assert!(<DurationSeconds as ScalarType>::parse(Value::String("PT1H".to_string())).is_err());
+    }
+}
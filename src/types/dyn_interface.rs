@@ -0,0 +1,161 @@
+use crate::parser::types::Field;
+use crate::registry::Registry;
+use crate::resolver_utils::{resolve_object, Fields, ObjectType};
+use crate::{Context, ContextSelectionSet, FieldError, OutputValueType, Positioned, Result, Type};
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+/// A value of the interface `I`, whose object type is only known at runtime.
+///
+/// This allows implementors of an interface that are not known when the interface is defined,
+/// such as the types of plugins. `I` is a `GQLInterface` which declares the fields of the
+/// interface, and it can be an enum without any variants. Every object type that is wrapped in a
+/// `DynInterface` must be registered with `SchemaBuilder::register_implementor`, otherwise resolving
+/// it fails with an error. `__typename` returns the name of the wrapped object type.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// #[derive(GQLInterface)]
+/// #[graphql(field(name = "name", type = "String"))]
+/// enum Plugin {}
+///
+/// struct Greeter;
+///
+/// #[GQLObject]
+/// impl Greeter {
+///     async fn name(&self) -> String {
+///         "greeter".to_string()
+///     }
+///
+///     async fn greeting(&self) -> &str {
+///         "Hello"
+///     }
+/// }
+///
+/// struct QueryRoot;
+///
+/// #[GQLObject]
+/// impl QueryRoot {
+///     async fn plugins(&self) -> Vec<DynInterface<Plugin>> {
+///         vec![DynInterface::new(Greeter)]
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+///         .register_implementor::<Plugin, Greeter>()
+///         .finish();
+///     let res = schema.execute("{ plugins { __typename name ... on Greeter { greeting } } }").await;
+///     assert_eq!(res.into_result().unwrap().data, serde_json::json!({
+///         "plugins": [{ "__typename": "Greeter", "name": "greeter", "greeting": "Hello" }],
+///     }));
+/// });
+/// ```
+pub struct DynInterface<I> {
+    object: Box<dyn DynObject>,
+    _marker: PhantomData<fn() -> I>,
+}
+
+impl<I> DynInterface<I> {
+    /// Wrap an object that implements the interface.
+    pub fn new<T: ObjectType + Send + Sync + 'static>(object: T) -> Self {
+        Self {
+            object: Box::new(object),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<I: Type> Type for DynInterface<I> {
+    fn type_name() -> Cow<'static, str> {
+        I::type_name()
+    }
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        self.object.introspection_type_name()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        I::create_type_info(registry)
+    }
+}
+
+#[async_trait::async_trait]
+impl<I: Type> ObjectType for DynInterface<I> {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> Result<serde_json::Value> {
+        self.object.resolve_field(ctx).await
+    }
+
+    fn collect_all_fields<'a>(
+        &'a self,
+        ctx: &ContextSelectionSet<'a>,
+        fields: &mut Fields<'a>,
+    ) -> Result<()> {
+        self.object.collect_all_fields(ctx, fields)
+    }
+}
+
+#[async_trait::async_trait]
+impl<I: Type> OutputValueType for DynInterface<I> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> Result<serde_json::Value> {
+        let typename = self.object.introspection_type_name();
+        let is_possible_type = ctx
+            .schema_env
+            .registry
+            .types
+            .get(I::type_name().as_ref())
+            .map_or(false, |ty| ty.is_possible_type(&typename));
+        if !is_possible_type {
+            return Err(FieldError(
+                format!(
+                    "\"{}\" is not registered as an implementor of \"{}\"",
+                    typename,
+                    I::type_name()
+                ),
+                None,
+            )
+            .into_error_with_path(field.pos, ctx.path_node.as_ref()));
+        }
+        resolve_object(ctx, self).await
+    }
+}
+
+/// The object safe part of `ObjectType`.
+#[async_trait::async_trait]
+trait DynObject: Send + Sync {
+    fn introspection_type_name(&self) -> Cow<'static, str>;
+
+    async fn resolve_field(&self, ctx: &Context<'_>) -> Result<serde_json::Value>;
+
+    fn collect_all_fields<'a>(
+        &'a self,
+        ctx: &ContextSelectionSet<'a>,
+        fields: &mut Fields<'a>,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl<T: ObjectType + Send + Sync> DynObject for T {
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        Type::introspection_type_name(self)
+    }
+
+    async fn resolve_field(&self, ctx: &Context<'_>) -> Result<serde_json::Value> {
+        ObjectType::resolve_field(self, ctx).await
+    }
+
+    fn collect_all_fields<'a>(
+        &'a self,
+        ctx: &ContextSelectionSet<'a>,
+        fields: &mut Fields<'a>,
+    ) -> Result<()> {
+        ObjectType::collect_all_fields(self, ctx, fields)
+    }
+}
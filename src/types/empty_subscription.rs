@@ -1,4 +1,4 @@
-use crate::{registry, Context, Error, Pos, QueryError, Result, SubscriptionType, Type};
+use crate::{registry, Context, Error, Pos, QueryError, Response, Result, SubscriptionType, Type};
 use futures::{stream, Stream};
 use std::borrow::Cow;
 use std::pin::Pin;
@@ -34,7 +34,7 @@ impl SubscriptionType for EmptySubscription {
     fn create_field_stream<'a>(
         &'a self,
         _ctx: &'a Context<'a>,
-    ) -> Pin<Box<dyn Stream<Item = Result<serde_json::Value>> + Send + 'a>>
+    ) -> Pin<Box<dyn Stream<Item = Result<Response>> + Send + 'a>>
     where
         Self: Send + Sync + 'static + Sized,
     {
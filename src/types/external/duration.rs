@@ -0,0 +1,237 @@
+use crate::{GQLScalar, InputValueError, InputValueResult, ScalarType, Value};
+use std::time::Duration as StdDuration;
+
+/// The `Duration` scalar type represents a non-negative span of time.
+///
+/// It is serialized as an ISO-8601 duration string such as `PT1H30M`, and accepts either such a
+/// string or an integer number of seconds as input. Use `DurationSeconds` to serialize it as a
+/// number of seconds instead.
+#[cfg(feature = "duration")]
+#[GQLScalar(internal, name = "Duration")]
+impl ScalarType for StdDuration {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => match parse_iso8601(&s)? {
+                (false, duration) => Ok(duration),
+                (true, _) => Err(InputValueError::from(
+                    "Negative durations are not accepted.",
+                )),
+            },
+            Value::Number(n) => Ok(StdDuration::from_secs(n.as_u64().ok_or_else(|| {
+                InputValueError::from("Only non-negative integers are accepted.")
+            })?)),
+            _ => Err(InputValueError::ExpectedType(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::String(_) => true,
+            Value::Number(n) if n.is_u64() => true,
+            _ => false,
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(format_iso8601(false, self))
+    }
+}
+
+/// The `SignedDuration` scalar type represents a span of time, which can be negative.
+///
+/// It is serialized as an ISO-8601 duration string such as `PT1H30M` or `-PT5S`, and accepts
+/// either such a string or an integer number of seconds as input.
+#[cfg(feature = "chrono")]
+#[GQLScalar(internal, name = "SignedDuration")]
+impl ScalarType for chrono::Duration {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        let (negative, duration) = match value {
+            Value::String(s) => parse_iso8601(&s)?,
+            Value::Number(n) => {
+                let secs = n
+                    .as_i64()
+                    .ok_or_else(|| InputValueError::from("Invalid number"))?;
+                let abs_secs = secs
+                    .checked_abs()
+                    .ok_or_else(|| InputValueError::from("Duration is out of range."))?;
+                (secs < 0, StdDuration::from_secs(abs_secs as u64))
+            }
+            _ => return Err(InputValueError::ExpectedType(value)),
+        };
+        let duration = chrono::Duration::from_std(duration)?;
+        Ok(if negative { -duration } else { duration })
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::String(_) => true,
+            Value::Number(n) if n.is_i64() => true,
+            _ => false,
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        // `to_std` only fails for negative durations, which are negated first.
+        Value::String(if *self < chrono::Duration::zero() {
+            format_iso8601(true, &(-*self).to_std().unwrap())
+        } else {
+            format_iso8601(false, &self.to_std().unwrap())
+        })
+    }
+}
+
+/// Parses an ISO-8601 duration such as `PT1H30M` or `-P1DT2.5S`, returning whether it is negative.
+///
+/// Years and months are rejected because their length is not fixed.
+fn parse_iso8601(s: &str) -> Result<(bool, StdDuration), String> {
+    let invalid = || format!("Invalid ISO-8601 duration \"{}\".", s);
+    let (negative, rest) = if s.starts_with('-') {
+        (true, &s[1..])
+    } else {
+        (false, s)
+    };
+    if !rest.starts_with('P') {
+        return Err(invalid());
+    }
+
+    let mut secs: u64 = 0;
+    let mut nanos: u32 = 0;
+    let mut in_time = false;
+    let mut last_rank = 0;
+    let mut number = String::new();
+
+    for c in rest[1..].chars() {
+        match c {
+            '0'..='9' | '.' => number.push(c),
+            'T' if !in_time && number.is_empty() => in_time = true,
+            _ => {
+                let (rank, unit_secs) = match (in_time, c) {
+                    (false, 'W') => (1, 604_800),
+                    (false, 'D') => (2, 86_400),
+                    (true, 'H') => (3, 3_600),
+                    (true, 'M') => (4, 60),
+                    (true, 'S') => (5, 1),
+                    _ => return Err(invalid()),
+                };
+                if number.is_empty() || rank <= last_rank {
+                    return Err(invalid());
+                }
+                last_rank = rank;
+
+                let mut parts = number.splitn(2, '.');
+                let whole = parts.next().unwrap_or_default();
+                if let Some(fraction) = parts.next() {
+                    if c != 'S' || whole.is_empty() || fraction.is_empty() || fraction.len() > 9 {
+                        return Err(invalid());
+                    }
+                    nanos = format!("{:0<9}", fraction).parse().map_err(|_| invalid())?;
+                }
+                let value: u64 = whole.parse().map_err(|_| invalid())?;
+                secs = value
+                    .checked_mul(unit_secs)
+                    .and_then(|value| secs.checked_add(value))
+                    .ok_or_else(invalid)?;
+                number.clear();
+            }
+        }
+    }
+
+    if !number.is_empty() || last_rank == 0 || (in_time && last_rank < 3) {
+        return Err(invalid());
+    }
+    Ok((negative, StdDuration::new(secs, nanos)))
+}
+
+fn format_iso8601(negative: bool, duration: &StdDuration) -> String {
+    let secs = duration.as_secs();
+    let nanos = duration.subsec_nanos();
+    let (hours, minutes, seconds) = (secs / 3_600, secs / 60 % 60, secs % 60);
+
+    let mut s = String::from(if negative { "-PT" } else { "PT" });
+    if hours > 0 {
+        s.push_str(&format!("{}H", hours));
+    }
+    if minutes > 0 {
+        s.push_str(&format!("{}M", minutes));
+    }
+    if nanos > 0 {
+        let fraction = format!("{:09}", nanos);
+        s.push_str(&format!("{}.{}S", seconds, fraction.trim_end_matches('0')));
+    } else if seconds > 0 || (hours == 0 && minutes == 0) {
+        s.push_str(&format!("{}S", seconds));
+    }
+    s
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[cfg(feature = "duration")]
+    #[test]
+    fn test_std_duration_round_trip() {
+        for (input, output) in &[
+            ("PT1H30M", "PT1H30M"),
+            ("PT90M", "PT1H30M"),
+            ("P1DT2.5S", "PT24H2.5S"),
+            ("P2W", "PT336H"),
+            ("PT0S", "PT0S"),
+        ] {
+            let duration =
+                <std::time::Duration as ScalarType>::parse(Value::String(input.to_string()))
+                    .unwrap();
+            assert_eq!(
+                ScalarType::to_value(&duration),
+                Value::String(output.to_string())
+            );
+            assert_eq!(
+                <std::time::Duration as ScalarType>::parse(ScalarType::to_value(&duration))
+                    .unwrap(),
+                duration
+            );
+        }
+
+        let duration =
+            <std::time::Duration as ScalarType>::parse(Value::Number(5400.into())).unwrap();
+        assert_eq!(duration, std::time::Duration::from_secs(5400));
+        assert_eq!(
+            ScalarType::to_value(&duration),
+            Value::String("PT1H30M".to_string())
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_duration_round_trip() {
+        for (input, output) in &[("-PT1M30S", "-PT1M30S"), ("PT0.25S", "PT0.25S")] {
+            let duration =
+                <chrono::Duration as ScalarType>::parse(Value::String(input.to_string())).unwrap();
+            assert_eq!(
+                ScalarType::to_value(&duration),
+                Value::String(output.to_string())
+            );
+            assert_eq!(
+                <chrono::Duration as ScalarType>::parse(ScalarType::to_value(&duration)).unwrap(),
+                duration
+            );
+        }
+
+        assert_eq!(
+            <chrono::Duration as ScalarType>::parse(Value::Number((-90).into())).unwrap(),
+            chrono::Duration::seconds(-90)
+        );
+    }
+
+    #[cfg(feature = "duration")]
+    #[test]
+    fn test_invalid_duration() {
+        for input in &[
+            "", "1H", "P", "PT", "P1DT", "P1Y", "P1M", "PT1.5M", "PT1M1H", "P1D2", "PT.5S", "-PT1S",
+        ] {
+            assert!(matches!(
+                <std::time::Duration as ScalarType>::parse(Value::String(input.to_string())),
+                Err(InputValueError::Custom(_))
+            ));
+        }
+    }
+}
@@ -1,12 +1,16 @@
-use crate::parser::types::Name;
+use crate::parser::types::{Field, Name};
+use crate::registry::{MetaType, Registry};
 use crate::{
-    GQLScalar, InputValueError, InputValueResult, InputValueType, OutputValueType, ScalarType,
-    Value,
+    ContextSelectionSet, InputValueError, InputValueResult, InputValueType, OutputValueType,
+    Positioned, Result, ScalarType, Type, Value,
 };
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 
 /// A scalar that can represent any JSON Object value.
-#[GQLScalar(internal, name = "JSONObject")]
+///
+/// Each entry of the map is a key of the object. In the response all keys are kept, but as an
+/// input value or in `ScalarType::to_value` the keys that are not valid GraphQL names are skipped.
 impl<T> ScalarType for BTreeMap<String, T>
 where
     T: OutputValueType + InputValueType + Send + Sync,
@@ -34,3 +38,54 @@ where
         Value::Object(map)
     }
 }
+
+impl<T> Type for BTreeMap<String, T>
+where
+    T: OutputValueType + InputValueType + Send + Sync,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed("JSONObject")
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_type::<Self, _>(|_| MetaType::Scalar {
+            name: Self::type_name().to_string(),
+            description: Some("A scalar that can represent any JSON Object value."),
+            is_valid: |value| <Self as ScalarType>::is_valid(value),
+        })
+    }
+}
+
+impl<T> InputValueType for BTreeMap<String, T>
+where
+    T: OutputValueType + InputValueType + Send + Sync,
+{
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        <Self as ScalarType>::parse(value.unwrap_or_default())
+    }
+
+    fn to_value(&self) -> Value {
+        <Self as ScalarType>::to_value(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> OutputValueType for BTreeMap<String, T>
+where
+    T: OutputValueType + InputValueType + Send + Sync,
+{
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> Result<serde_json::Value> {
+        let mut map = serde_json::Map::new();
+        for (name, value) in self {
+            map.insert(
+                name.clone(),
+                OutputValueType::resolve(value, ctx, field).await?,
+            );
+        }
+        Ok(ctx.serialize_scalar("JSONObject", map.into()))
+    }
+}
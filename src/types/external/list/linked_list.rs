@@ -1,7 +1,7 @@
 use crate::parser::types::Field;
 use crate::{
-    registry, ContextSelectionSet, InputValueResult, InputValueType, OutputValueType, Positioned,
-    Result, Type, Value,
+    registry, resolver_utils, ContextSelectionSet, InputValueResult, InputValueType,
+    OutputValueType, Positioned, Result, Type, Value,
 };
 use std::borrow::Cow;
 use std::collections::LinkedList;
@@ -51,11 +51,6 @@ impl<T: OutputValueType + Send + Sync> OutputValueType for LinkedList<T> {
         ctx: &ContextSelectionSet<'_>,
         field: &Positioned<Field>,
     ) -> Result<serde_json::Value> {
-        let mut futures = Vec::with_capacity(self.len());
-        for (idx, item) in self.iter().enumerate() {
-            let ctx_idx = ctx.with_index(idx);
-            futures.push(async move { OutputValueType::resolve(item, &ctx_idx, field).await });
-        }
-        Ok(futures::future::try_join_all(futures).await?.into())
+        resolver_utils::resolve_list(ctx, field, self.iter()).await
     }
 }
@@ -1,5 +1,7 @@
 use crate::parser::types::Field;
-use crate::{registry, ContextSelectionSet, OutputValueType, Positioned, Result, Type};
+use crate::{
+    registry, resolver_utils, ContextSelectionSet, OutputValueType, Positioned, Result, Type,
+};
 use std::borrow::Cow;
 
 impl<'a, T: Type + 'a> Type for &'a [T] {
@@ -24,11 +26,6 @@ impl<T: OutputValueType + Send + Sync> OutputValueType for &[T] {
         ctx: &ContextSelectionSet<'_>,
         field: &Positioned<Field>,
     ) -> Result<serde_json::Value> {
-        let mut futures = Vec::with_capacity(self.len());
-        for (idx, item) in (*self).iter().enumerate() {
-            let ctx_idx = ctx.with_index(idx);
-            futures.push(async move { OutputValueType::resolve(item, &ctx_idx, field).await });
-        }
-        Ok(futures::future::try_join_all(futures).await?.into())
+        resolver_utils::resolve_list(ctx, field, (*self).iter()).await
     }
 }
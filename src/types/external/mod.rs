@@ -17,6 +17,8 @@ mod bson;
 mod chrono_tz;
 #[cfg(feature = "chrono")]
 mod datetime;
+#[cfg(any(feature = "chrono", feature = "duration"))]
+mod duration;
 #[cfg(feature = "chrono")]
 mod naive_time;
 #[cfg(feature = "url")]
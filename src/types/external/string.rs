@@ -4,6 +4,7 @@ use crate::{
     Positioned, Result, ScalarType, Type, Value,
 };
 use std::borrow::Cow;
+use std::sync::Arc;
 
 /// The `String` scalar type represents textual data, represented as UTF-8 character sequences. The String type is most often used by GraphQL to represent free-form human-readable text.
 #[GQLScalar(internal)]
@@ -41,9 +42,51 @@ impl<'a> Type for &'a str {
 impl<'a> OutputValueType for &'a str {
     async fn resolve(
         &self,
-        _: &ContextSelectionSet<'_>,
+        ctx: &ContextSelectionSet<'_>,
         _field: &Positioned<Field>,
     ) -> Result<serde_json::Value> {
-        Ok((*self).into())
+        Ok(ctx.serialize_scalar("String", (*self).into()))
+    }
+}
+
+impl<'a> Type for Cow<'a, str> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed("String")
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        <String as Type>::create_type_info(registry)
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> OutputValueType for Cow<'a, str> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> Result<serde_json::Value> {
+        Ok(ctx.serialize_scalar("String", (**self).into()))
+    }
+}
+
+impl Type for Arc<str> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed("String")
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        <String as Type>::create_type_info(registry)
+    }
+}
+
+#[async_trait::async_trait]
+impl OutputValueType for Arc<str> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> Result<serde_json::Value> {
+        Ok(ctx.serialize_scalar("String", (**self).into()))
     }
 }
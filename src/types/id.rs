@@ -77,11 +77,12 @@ impl PartialEq<&str> for ID {
     }
 }
 
+/// Integer inputs are accepted and converted to strings, as required by the specification.
 #[GQLScalar(internal)]
 impl ScalarType for ID {
     fn parse(value: Value) -> InputValueResult<Self> {
         match value {
-            Value::Number(n) if n.is_i64() => Ok(ID(n.to_string())),
+            Value::Number(n) if n.is_i64() || n.is_u64() => Ok(ID(n.to_string())),
             Value::String(s) => Ok(ID(s)),
             _ => Err(InputValueError::ExpectedType(value)),
         }
@@ -89,7 +90,7 @@ impl ScalarType for ID {
 
     fn is_valid(value: &Value) -> bool {
         match value {
-            Value::Number(n) if n.is_i64() => true,
+            Value::Number(n) if n.is_i64() || n.is_u64() => true,
             Value::String(_) => true,
             _ => false,
         }
@@ -99,3 +100,60 @@ impl ScalarType for ID {
         Value::String(self.0.clone())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[async_std::test]
+    async fn test_id_type() {
+        struct Query;
+
+        #[GQLObject(internal)]
+        impl Query {
+            async fn id(&self, input: ID) -> ID {
+                input
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        assert_eq!(
+            schema
+                .execute(r#"{ a: id(input: 123) b: id(input: "123") }"#)
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            serde_json::json!({
+                "a": "123",
+                "b": "123",
+            })
+        );
+
+        assert_eq!(
+            schema
+                .execute(
+                    Request::new("query($id: ID!) { id(input: $id) }").variables(
+                        Variables::from_json(serde_json::json!({ "id": 18446744073709551615u64 }))
+                    )
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            serde_json::json!({
+                "id": "18446744073709551615",
+            })
+        );
+
+        assert!(matches!(
+            schema
+                .execute("{ id(input: 1.5) }")
+                .await
+                .into_result()
+                .unwrap_err(),
+            Error::Rule { .. }
+        ));
+    }
+}
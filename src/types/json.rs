@@ -1,8 +1,8 @@
 use crate::parser::types::Field;
 use crate::registry::{MetaType, Registry};
 use crate::{
-    ContextSelectionSet, GQLScalar, InputValueResult, OutputValueType, Positioned, Result,
-    ScalarType, Type, Value,
+    ContextSelectionSet, GQLScalar, InputValueError, InputValueResult, OutputValueType, Positioned,
+    Result, ScalarType, Type, Value,
 };
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
@@ -40,7 +40,15 @@ impl<T: DeserializeOwned + Serialize> From<T> for Json<T> {
 #[GQLScalar(internal, name = "JSON")]
 impl<T: DeserializeOwned + Serialize + Send + Sync> ScalarType for Json<T> {
     fn parse(value: Value) -> InputValueResult<Self> {
-        Ok(serde_json::from_value(value.into_json()?)?)
+        serde_path_to_error::deserialize(value.into_json()?)
+            .map(Json)
+            .map_err(|err| {
+                InputValueError::Custom(format!(
+                    "Invalid JSON value at \"{}\": {}",
+                    err.path(),
+                    err.inner()
+                ))
+            })
     }
 
     fn to_value(&self) -> Value {
@@ -93,10 +101,13 @@ impl<T> Type for OutputJson<T> {
 impl<T: Serialize + Send + Sync> OutputValueType for OutputJson<T> {
     async fn resolve(
         &self,
-        _ctx: &ContextSelectionSet<'_>,
+        ctx: &ContextSelectionSet<'_>,
         _field: &Positioned<Field>,
     ) -> Result<serde_json::Value> {
-        Ok(serde_json::to_value(&self.0).unwrap_or_else(|_| serde_json::Value::Null))
+        Ok(ctx.serialize_scalar(
+            "Json",
+            serde_json::to_value(&self.0).unwrap_or_else(|_| serde_json::Value::Null),
+        ))
     }
 }
 
@@ -138,6 +149,46 @@ mod test {
         );
     }
 
+    #[async_std::test]
+    async fn test_json_type_invalid_input() {
+        #[derive(Serialize, Deserialize)]
+        struct MyStruct {
+            a: i32,
+            b: Vec<i32>,
+        }
+
+        struct Query;
+
+        #[GQLObject(internal)]
+        impl Query {
+            async fn obj(&self, input: Json<MyStruct>) -> i32 {
+                input.a
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        let query = r#"{ obj(input: { a: 1, b: [1, "x"] }) }"#;
+        let err = schema.execute(query).await.into_result().unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Query {
+                err: QueryError::ParseInputValue { ref reason },
+                ..
+            } if reason == r#"Invalid JSON value at "b[1]": invalid type: string "x", expected i32"#
+        ));
+
+        let query = r#"{ obj(input: { b: [] }) }"#;
+        let err = schema.execute(query).await.into_result().unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Query {
+                err: QueryError::ParseInputValue { ref reason },
+                ..
+            } if reason == r#"Invalid JSON value at ".": missing field `a`"#
+        ));
+    }
+
     #[async_std::test]
     async fn test_output_json_type() {
         #[derive(Serialize)]
@@ -0,0 +1,152 @@
+use crate::parser::types::Field;
+use crate::{
+    registry, resolver_utils, ContextSelectionSet, OutputValueType, Positioned, Result, Type,
+};
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt};
+use std::borrow::Cow;
+
+/// A list that is resolved from a stream.
+///
+/// Without the `@stream` directive, or when the query is executed with `Schema::execute`, the
+/// whole stream is collected and resolved like a `Vec<T>`.
+///
+/// When the query is executed with `Schema::execute_stream` and the field has the
+/// `@stream(initialCount: n)` directive, the first `n` items are returned in the initial response
+/// and the rest are delivered in subsequent responses, one item per response. These responses
+/// have a `path` pointing to the item and `hasNext` set to `true`, and a final response with
+/// `hasNext` set to `false` ends the query. Lists nested in streamed items are not streamed again.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct QueryRoot;
+///
+/// #[GQLObject]
+/// impl QueryRoot {
+///     async fn values(&self) -> ListStream<i32> {
+///         ListStream::new(futures::stream::iter(vec![1, 2, 3]))
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ values }").await.into_result().unwrap().data;
+///     assert_eq!(res, serde_json::json!({ "values": [1, 2, 3] }));
+/// });
+/// ```
+pub struct ListStream<T>(spin::Mutex<Option<BoxStream<'static, T>>>);
+
+impl<T> ListStream<T> {
+    /// Create a list from a stream.
+    pub fn new(stream: impl Stream<Item = T> + Send + 'static) -> Self {
+        Self(spin::Mutex::new(Some(stream.boxed())))
+    }
+}
+
+impl<T: Type> Type for ListStream<T> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("[{}]", T::qualified_type_name()))
+    }
+
+    fn qualified_type_name() -> String {
+        format!("[{}]!", T::qualified_type_name())
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        T::create_type_info(registry);
+        Self::qualified_type_name()
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: OutputValueType + Send + Sync + 'static> OutputValueType for ListStream<T> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> Result<serde_json::Value> {
+        let mut stream = match self.0.lock().take() {
+            Some(stream) => stream,
+            None => return Ok(serde_json::Value::Array(Vec::new())),
+        };
+
+        let initial_count = if ctx.query_env.pending_streams.lock().is_some() {
+            ctx.stream_initial_count(&field.node.directives)?
+        } else {
+            None
+        };
+
+        let mut items = Vec::new();
+        match initial_count {
+            Some(initial_count) => {
+                while items.len() < initial_count {
+                    match stream.next().await {
+                        Some(item) => items.push(item),
+                        None => break,
+                    }
+                }
+            }
+            None => {
+                while let Some(item) = stream.next().await {
+                    items.push(item);
+                }
+            }
+        }
+
+        let value = resolver_utils::resolve_list(ctx, field, items.iter()).await?;
+
+        if initial_count.is_some() {
+            if let Some(pending_streams) = &mut *ctx.query_env.pending_streams.lock() {
+                pending_streams.push(PendingStream {
+                    path: ctx
+                        .path_node
+                        .as_ref()
+                        .map(|path| serde_json::to_value(path).unwrap())
+                        .unwrap_or_else(|| serde_json::Value::Array(Vec::new())),
+                    field: field.clone(),
+                    next_index: items.len(),
+                    items: Box::new(stream),
+                });
+            }
+        }
+
+        Ok(value)
+    }
+}
+
+/// The remaining items of a list with the `@stream` directive, that are delivered after the
+/// initial response.
+pub(crate) struct PendingStream {
+    /// The path of the list field.
+    pub(crate) path: serde_json::Value,
+    pub(crate) field: Positioned<Field>,
+    /// The index of the next item in the list.
+    pub(crate) next_index: usize,
+    pub(crate) items: Box<dyn StreamItems>,
+}
+
+pub(crate) trait StreamItems: Send {
+    /// Wait for the next item of the stream and resolve it.
+    fn resolve_next<'a>(
+        &'a mut self,
+        ctx: &'a ContextSelectionSet<'a>,
+        field: &'a Positioned<Field>,
+    ) -> BoxFuture<'a, Option<Result<serde_json::Value>>>;
+}
+
+impl<T: OutputValueType + Send + Sync + 'static> StreamItems for BoxStream<'static, T> {
+    fn resolve_next<'a>(
+        &'a mut self,
+        ctx: &'a ContextSelectionSet<'a>,
+        field: &'a Positioned<Field>,
+    ) -> BoxFuture<'a, Option<Result<serde_json::Value>>> {
+        Box::pin(async move {
+            let item = self.next().await?;
+            Some(OutputValueType::resolve(&item, ctx, field).await)
+        })
+    }
+}
@@ -1,9 +1,9 @@
 use crate::parser::types::Field;
-use crate::registry::{MetaType, Registry};
+use crate::registry::{MetaField, MetaType, Registry};
 use crate::resolver_utils::{resolve_object, ObjectType};
 use crate::{
     CacheControl, Context, ContextSelectionSet, Error, GQLSimpleObject, GQLSubscription,
-    OutputValueType, Positioned, QueryError, Result, SubscriptionType, Type,
+    OutputValueType, Positioned, QueryError, Response, Result, SubscriptionType, Type,
 };
 use futures::{future::Either, stream, Stream, StreamExt};
 use indexmap::IndexMap;
@@ -40,7 +40,7 @@ impl<A: Type, B: Type> Type for MergedObject<A, B> {
                 ..
             }) = registry.types.remove(&*A::type_name())
             {
-                fields.extend(a_fields);
+                merge_fields(&mut fields, a_fields);
                 cc.merge(&a_cc);
             }
 
@@ -51,7 +51,7 @@ impl<A: Type, B: Type> Type for MergedObject<A, B> {
                 ..
             }) = registry.types.remove(&*B::type_name())
             {
-                fields.extend(b_fields);
+                merge_fields(&mut fields, b_fields);
                 cc.merge(&b_cc);
             }
 
@@ -67,6 +67,23 @@ impl<A: Type, B: Type> Type for MergedObject<A, B> {
     }
 }
 
+/// Merges the fields of an object into the merged object.
+///
+/// # Panics
+///
+/// Panics if a field with the same name was already merged, because only one of them could ever be resolved.
+fn merge_fields(fields: &mut IndexMap<String, MetaField>, other: IndexMap<String, MetaField>) {
+    for (name, field) in other {
+        if fields.contains_key(&name) {
+            panic!(
+                "Field \"{}\" is defined in more than one merged object.",
+                name
+            );
+        }
+        fields.insert(name, field);
+    }
+}
+
 #[async_trait::async_trait]
 impl<A, B> ObjectType for MergedObject<A, B>
 where
@@ -108,7 +125,7 @@ where
     fn create_field_stream<'a>(
         &'a self,
         ctx: &'a Context<'a>,
-    ) -> Pin<Box<dyn Stream<Item = Result<serde_json::Value>> + Send + 'a>> {
+    ) -> Pin<Box<dyn Stream<Item = Result<Response>> + Send + 'a>> {
         let left_stream = self.0.create_field_stream(ctx);
         let mut right_stream = Some(self.1.create_field_stream(ctx));
         Box::pin(left_stream.flat_map(move |res| match res {
@@ -3,24 +3,37 @@
 pub mod connection;
 
 mod any;
+mod base64;
+#[cfg(feature = "duration")]
+mod duration_seconds;
+mod dyn_interface;
 mod empty_mutation;
 mod empty_subscription;
 mod id;
 mod json;
+mod list_stream;
 mod maybe_undefined;
 mod merged_object;
 mod query_root;
+mod typed_json;
 mod upload;
 
 mod external;
 
+pub use self::base64::Base64;
 pub use any::Any;
+#[cfg(feature = "duration")]
+pub use duration_seconds::DurationSeconds;
+pub use dyn_interface::DynInterface;
 pub use empty_mutation::EmptyMutation;
 pub use empty_subscription::EmptySubscription;
 pub use id::ID;
 pub use json::{Json, OutputJson};
+pub use list_stream::ListStream;
 pub use maybe_undefined::MaybeUndefined;
 pub use merged_object::{MergedObject, MergedObjectSubscriptionTail, MergedObjectTail};
+pub use typed_json::TypedJson;
 pub use upload::Upload;
 
+pub(crate) use list_stream::PendingStream;
 pub(crate) use query_root::QueryRoot;
@@ -39,11 +39,13 @@ impl<T: Type> Type for QueryRoot<T> {
                     description: Some("Access the current type schema of this server."),
                     args: Default::default(),
                     ty: schema_type,
-                    deprecation: None,
+                    deprecation: Default::default(),
                     cache_control: Default::default(),
                     external: false,
                     requires: None,
                     provides: None,
+                    inherit_max_age: false,
+                    complexity: 1,
                 },
             );
 
@@ -62,16 +64,19 @@ impl<T: Type> Type for QueryRoot<T> {
                                 ty: "String!".to_string(),
                                 default_value: None,
                                 validator: None,
+                                is_secret: false,
                             },
                         );
                         args
                     },
                     ty: "__Type".to_string(),
-                    deprecation: None,
+                    deprecation: Default::default(),
                     cache_control: Default::default(),
                     external: false,
                     requires: None,
                     provides: None,
+                    inherit_max_age: false,
+                    complexity: 1,
                 },
             );
         }
@@ -108,6 +113,7 @@ impl<T: ObjectType + Send + Sync> ObjectType for QueryRoot<T> {
             .await;
         } else if ctx.item.node.name.node == "__type" {
             let type_name: String = ctx.param_value("name", None)?;
+            // Only named types can be looked up, wrapper types such as `[Int!]` resolve to null.
             let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
             return OutputValueType::resolve(
                 &ctx.schema_env
@@ -122,8 +128,32 @@ impl<T: ObjectType + Send + Sync> ObjectType for QueryRoot<T> {
         } else if ctx.item.node.name.node == "_entities" {
             let representations: Vec<Any> = ctx.param_value("representations", None)?;
             let mut res = Vec::new();
-            for item in representations {
-                res.push(self.inner.find_entity(ctx, &item.0).await?);
+            for (idx, item) in representations.into_iter().enumerate() {
+                let ctx_item = ctx.with_index(idx);
+                // The representations are resolved independently, a failed one is `null` and
+                // its error is returned with the data.
+                match self.inner.find_entity(&ctx_item, &item.0).await {
+                    Ok(entity) => res.push(entity),
+                    Err(err) => {
+                        let err = match err {
+                            Error::Query {
+                                pos,
+                                path: None,
+                                err,
+                            } => Error::Query {
+                                pos,
+                                path: ctx_item
+                                    .path_node
+                                    .as_ref()
+                                    .and_then(|path| serde_json::to_value(path).ok()),
+                                err,
+                            },
+                            err => err,
+                        };
+                        ctx.query_env.field_error.lock().get_or_insert(err);
+                        res.push(serde_json::Value::Null);
+                    }
+                }
             }
             return Ok(res.into());
         } else if ctx.item.node.name.node == "_service" {
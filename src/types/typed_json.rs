@@ -0,0 +1,150 @@
+use crate::parser::types::Field;
+use crate::registry::Registry;
+use crate::resolver_utils::{resolve_object, ObjectType};
+use crate::{Context, ContextSelectionSet, FieldError, OutputValueType, Positioned, Result, Type};
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+/// A JSON value of the interface or union `I`, together with the name of its object type.
+///
+/// This allows returning polymorphic values that only exist as JSON, such as the responses of
+/// another service. `__typename` returns the supplied type name, fragments are applied by it, and
+/// the other fields are looked up by name in the JSON object, or `null` if they are missing, which
+/// is an error for fields of a non-null type. Only fields of scalar and enum types, or lists of
+/// them, can have values, since the object types of nested values are not known. The type name
+/// must be an object type that is a possible type of `I`, otherwise resolving the value fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// #[derive(GQLSimpleObject)]
+/// struct Circle {
+///     radius: f32,
+/// }
+///
+/// #[derive(GQLSimpleObject)]
+/// struct Square {
+///     width: f32,
+/// }
+///
+/// #[derive(GQLUnion)]
+/// enum Shape {
+///     Circle(Circle),
+///     Square(Square),
+/// }
+///
+/// struct QueryRoot;
+///
+/// #[GQLObject]
+/// impl QueryRoot {
+///     async fn shape(&self) -> TypedJson<Shape> {
+///         TypedJson::new("Circle", serde_json::json!({ "radius": 2.0 }))
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ shape { __typename ... on Circle { radius } } }").await;
+///     assert_eq!(res.into_result().unwrap().data, serde_json::json!({
+///         "shape": { "__typename": "Circle", "radius": 2.0 },
+///     }));
+/// });
+/// ```
+pub struct TypedJson<I> {
+    typename: String,
+    value: serde_json::Value,
+    _marker: PhantomData<fn() -> I>,
+}
+
+impl<I> TypedJson<I> {
+    /// Create a value of the object type `typename`.
+    pub fn new(typename: impl Into<String>, value: serde_json::Value) -> Self {
+        Self {
+            typename: typename.into(),
+            value,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The name of the object type.
+    pub fn typename(&self) -> &str {
+        &self.typename
+    }
+
+    /// The JSON value.
+    pub fn value(&self) -> &serde_json::Value {
+        &self.value
+    }
+}
+
+impl<I: Type> Type for TypedJson<I> {
+    fn type_name() -> Cow<'static, str> {
+        I::type_name()
+    }
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        Cow::Owned(self.typename.clone())
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        I::create_type_info(registry)
+    }
+}
+
+#[async_trait::async_trait]
+impl<I: Type> ObjectType for TypedJson<I> {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> Result<serde_json::Value> {
+        let name = ctx.item.node.name.node.as_str();
+        let value = self.value.get(name).cloned().unwrap_or_default();
+        if !value.is_null() {
+            let registry = &ctx.schema_env.registry;
+            let is_leaf = registry
+                .types
+                .get(&self.typename)
+                .and_then(|ty| ty.field_by_name(name))
+                .and_then(|field| registry.concrete_type_by_name(&field.ty))
+                .map_or(true, |ty| ty.is_leaf());
+            if !is_leaf {
+                return Err(FieldError(
+                    format!(
+                        "The field \"{}\" of \"{}\" is not a scalar or an enum, so it can't be resolved from JSON",
+                        name, self.typename
+                    ),
+                    None,
+                )
+                .into_error_with_path(ctx.item.pos, ctx.path_node.as_ref()));
+            }
+        }
+        Ok(value)
+    }
+}
+
+#[async_trait::async_trait]
+impl<I: Type> OutputValueType for TypedJson<I> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> Result<serde_json::Value> {
+        let is_possible_type = ctx
+            .schema_env
+            .registry
+            .types
+            .get(I::type_name().as_ref())
+            .map_or(false, |ty| ty.is_possible_type(&self.typename));
+        if !is_possible_type {
+            return Err(FieldError(
+                format!(
+                    "\"{}\" is not an object type of \"{}\"",
+                    self.typename,
+                    I::type_name()
+                ),
+                None,
+            )
+            .into_error_with_path(field.pos, ctx.path_node.as_ref()));
+        }
+        resolve_object(ctx, self).await
+    }
+}
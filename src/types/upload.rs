@@ -12,6 +12,10 @@ use std::io::Read;
 /// Enable this feature by accepting an argument of type `Upload` (single file) or
 /// `Vec<Upload>` (multiple files) in your mutation like in the example blow.
 ///
+/// The content of each file is streamed from the request body into a temporary file while the
+/// request is received, so large uploads are never held in memory, and it can be read back with
+/// `into_read`.
+///
 ///
 /// # Example
 /// *[Full Example](<https://github.com/async-graphql/examples/blob/master/models/files/src/lib.rs>)*
@@ -11,9 +11,15 @@ mod visitors;
 use crate::parser::types::ExecutableDocument;
 use crate::registry::Registry;
 use crate::{CacheControl, Error, Result, Variables};
-use visitor::{visit, VisitorContext, VisitorNil};
+use visitor::{visit, VisitorNil};
+use visitors::Secrets;
 
-pub struct CheckResult {
+pub use visitor::{Visitor, VisitorContext};
+
+/// A custom validation rule, created for each validation.
+pub(crate) type BoxVisitor = Box<dyn for<'a> Visitor<'a>>;
+
+pub(crate) struct CheckResult {
     pub cache_control: CacheControl,
     pub complexity: usize,
     pub depth: usize,
@@ -27,13 +33,35 @@ pub enum ValidationMode {
 
     /// The executor itself also has error handling, so it can improve performance, but it can lose some error messages.
     Fast,
+
+    /// Only parse the query, and skip all the validation rules except the check for fragment
+    /// cycles, which the executor cannot recover from.
+    ///
+    /// This is intended for trusted, pre-validated queries such as persisted queries. Invalid
+    /// queries still fail with an error during execution. The complexity and depth limits are
+    /// still enforced, but cache control is not calculated.
+    OnlyParse,
+}
+
+/// Get the values of the secret arguments of a query, which must not be logged.
+pub(crate) fn secret_values(registry: &Registry, doc: &ExecutableDocument) -> Secrets {
+    let mut ctx = VisitorContext::new(registry, doc, None);
+    let mut secrets = Secrets::default();
+    visit(
+        &mut visitors::SecretValues::new(&mut secrets),
+        &mut ctx,
+        doc,
+    );
+    secrets.add_variable_defaults(doc);
+    secrets
 }
 
-pub fn check_rules(
+pub(crate) fn check_rules(
     registry: &Registry,
     doc: &ExecutableDocument,
     variables: Option<&Variables>,
     mode: ValidationMode,
+    custom_rules: &[Box<dyn Fn() -> BoxVisitor + Send + Sync>],
 ) -> Result<CheckResult> {
     let mut ctx = VisitorContext::new(registry, doc, variables);
     let mut cache_control = CacheControl::default();
@@ -67,9 +95,7 @@ pub fn check_rules(
                 .with(rules::KnownDirectives::default())
                 .with(rules::OverlappingFieldsCanBeMerged)
                 .with(rules::UploadFile)
-                .with(visitors::CacheControlCalculate {
-                    cache_control: &mut cache_control,
-                })
+                .with(visitors::CacheControlCalculate::new(&mut cache_control))
                 .with(visitors::ComplexityCalculate {
                     complexity: &mut complexity,
                 })
@@ -80,9 +106,16 @@ pub fn check_rules(
             let mut visitor = VisitorNil
                 .with(rules::NoFragmentCycles::default())
                 .with(rules::UploadFile)
-                .with(visitors::CacheControlCalculate {
-                    cache_control: &mut cache_control,
+                .with(visitors::CacheControlCalculate::new(&mut cache_control))
+                .with(visitors::ComplexityCalculate {
+                    complexity: &mut complexity,
                 })
+                .with(visitors::DepthCalculate::new(&mut depth));
+            visit(&mut visitor, &mut ctx, doc);
+        }
+        ValidationMode::OnlyParse => {
+            let mut visitor = VisitorNil
+                .with(rules::NoFragmentCycles::default())
                 .with(visitors::ComplexityCalculate {
                     complexity: &mut complexity,
                 })
@@ -91,6 +124,13 @@ pub fn check_rules(
         }
     }
 
+    // Custom rules only see queries that passed the built-in rules.
+    if ctx.errors.is_empty() && !matches!(mode, ValidationMode::OnlyParse) {
+        for rule in custom_rules {
+            visit(&mut *rule(), &mut ctx, doc);
+        }
+    }
+
     if !ctx.errors.is_empty() {
         return Err(Error::Rule { errors: ctx.errors });
     }
@@ -3,7 +3,7 @@ use crate::parser::types::{Directive, Field, Name, Value};
 use crate::registry::MetaInputValue;
 use crate::validation::utils::is_valid_input_value;
 use crate::validation::visitor::{Visitor, VisitorContext};
-use crate::{Positioned, QueryPathSegment};
+use crate::{Positioned, QueryPathSegment, RuleErrorKind};
 use indexmap::map::IndexMap;
 
 #[derive(Default)]
@@ -53,12 +53,23 @@ impl<'a> Visitor<'a> for ArgumentsOfCorrectType<'a> {
                 })
                 .ok();
 
+            // The reasons can contain the value, so they are left out for secret arguments.
+            let secret_error = || format!("Invalid value for argument \"{}\"", arg.name);
+            let invalid_value = || RuleErrorKind::InvalidArgumentValue {
+                arg_name: arg.name.to_string(),
+            };
+
             if let Some(validator) = &arg.validator {
                 if let Some(value) = &value {
                     if let Err(reason) = validator.is_valid(value) {
-                        ctx.report_error(
+                        ctx.report_error_with_kind(
                             vec![name.pos],
-                            format!("Invalid value for argument \"{}\", {}", arg.name, reason),
+                            if arg.is_secret {
+                                secret_error()
+                            } else {
+                                format!("Invalid value for argument \"{}\", {}", arg.name, reason)
+                            },
+                            invalid_value(),
                         );
                         return;
                     }
@@ -77,9 +88,14 @@ impl<'a> Visitor<'a> for ArgumentsOfCorrectType<'a> {
                     },
                 )
             }) {
-                ctx.report_error(
+                ctx.report_error_with_kind(
                     vec![name.pos],
-                    format!("Invalid value for argument {}", reason),
+                    if arg.is_secret {
+                        secret_error()
+                    } else {
+                        format!("Invalid value for argument {}", reason)
+                    },
+                    invalid_value(),
                 );
             }
         }
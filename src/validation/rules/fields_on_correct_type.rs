@@ -1,7 +1,7 @@
 use crate::parser::types::Field;
 use crate::validation::suggestion::make_suggestion;
 use crate::validation::visitor::{Visitor, VisitorContext};
-use crate::{registry, Positioned};
+use crate::{registry, Positioned, RuleErrorKind};
 
 #[derive(Default)]
 pub struct FieldsOnCorrectType;
@@ -27,24 +27,32 @@ impl<'a> Visitor<'a> for FieldsOnCorrectType {
                     .iter()
                     .any(|directive| directive.node.name.node == "ifdef")
             {
-                ctx.report_error(
+                ctx.report_error_with_kind(
                     vec![field.pos],
                     format!(
                         "Unknown field \"{}\" on type \"{}\".{}",
                         field.node.name,
                         parent_type.name(),
-                        make_suggestion(
-                            " Did you mean",
-                            parent_type
-                                .fields()
-                                .iter()
-                                .map(|fields| fields.keys())
-                                .flatten()
-                                .map(|s| s.as_str()),
-                            &field.node.name.node,
-                        )
-                        .unwrap_or_default()
+                        if ctx.registry.enable_suggestions {
+                            make_suggestion(
+                                " Did you mean",
+                                parent_type
+                                    .fields()
+                                    .iter()
+                                    .map(|fields| fields.keys())
+                                    .flatten()
+                                    .map(|s| s.as_str()),
+                                &field.node.name.node,
+                            )
+                            .unwrap_or_default()
+                        } else {
+                            String::new()
+                        }
                     ),
+                    RuleErrorKind::UnknownField {
+                        object: parent_type.name().to_string(),
+                        field_name: field.node.name.node.to_string(),
+                    },
                 );
             }
         }
@@ -2,7 +2,7 @@ use crate::parser::types::{Directive, Field, Name, Value};
 use crate::registry::MetaInputValue;
 use crate::validation::suggestion::make_suggestion;
 use crate::validation::visitor::{Visitor, VisitorContext};
-use crate::Positioned;
+use crate::{Positioned, RuleErrorKind};
 use indexmap::map::IndexMap;
 
 enum ArgsType<'a> {
@@ -19,7 +19,10 @@ pub struct KnownArgumentNames<'a> {
 }
 
 impl<'a> KnownArgumentNames<'a> {
-    fn get_suggestion(&self, name: &str) -> String {
+    fn get_suggestion(&self, ctx: &VisitorContext<'a>, name: &str) -> String {
+        if !ctx.registry.enable_suggestions {
+            return String::new();
+        }
         make_suggestion(
             " Did you mean",
             self.current_args
@@ -66,15 +69,20 @@ impl<'a> Visitor<'a> for KnownArgumentNames<'a> {
                         field_name,
                         type_name,
                     } => {
-                        ctx.report_error(
+                        ctx.report_error_with_kind(
                             vec![name.pos],
                             format!(
                                 "Unknown argument \"{}\" on field \"{}\" of type \"{}\".{}",
                                 name,
                                 field_name,
                                 type_name,
-                                self.get_suggestion(name.node.as_str())
+                                self.get_suggestion(ctx, name.node.as_str())
                             ),
+                            RuleErrorKind::UnknownArgument {
+                                object: type_name.to_string(),
+                                field_name: field_name.to_string(),
+                                arg_name: name.node.to_string(),
+                            },
                         );
                     }
                     ArgsType::Directive(directive_name) => {
@@ -84,7 +92,7 @@ impl<'a> Visitor<'a> for KnownArgumentNames<'a> {
                                 "Unknown argument \"{}\" on directive \"{}\".{}",
                                 name,
                                 directive_name,
-                                self.get_suggestion(name.node.as_str())
+                                self.get_suggestion(ctx, name.node.as_str())
                             ),
                         );
                     }
@@ -83,10 +83,16 @@ pub fn is_valid_input_value(
                 registry::MetaType::Enum {
                     enum_values,
                     name: enum_name,
+                    case_insensitive,
                     ..
                 } => match value {
                     ConstValue::Enum(name) => {
-                        if !enum_values.contains_key(name.as_str()) {
+                        let known = enum_values.contains_key(name.as_str())
+                            || (*case_insensitive
+                                && enum_values
+                                    .keys()
+                                    .any(|value| value.eq_ignore_ascii_case(name.as_str())));
+                        if !known {
                             Some(valid_error(
                                 &path_node,
                                 format!(
@@ -98,6 +104,24 @@ pub fn is_valid_input_value(
                             None
                         }
                     }
+                    ConstValue::Number(n)
+                        if enum_values.values().any(|value| value.int_value.is_some()) =>
+                    {
+                        if !enum_values
+                            .values()
+                            .any(|value| value.int_value.is_some() && value.int_value == n.as_i64())
+                        {
+                            Some(valid_error(
+                                &path_node,
+                                format!(
+                                    "enumeration type \"{}\" does not contain the value \"{}\"",
+                                    enum_name, n
+                                ),
+                            ))
+                        } else {
+                            None
+                        }
+                    }
                     _ => Some(valid_error(
                         &path_node,
                         format!("expected type \"{}\"", type_name),
@@ -106,9 +130,32 @@ pub fn is_valid_input_value(
                 registry::MetaType::InputObject {
                     input_fields,
                     name: object_name,
-                    ..
+                    one_of,
+                    deny_unknown_fields,
                 } => match value {
                     ConstValue::Object(values) => {
+                        if *one_of {
+                            if values.len() != 1 {
+                                return Some(valid_error(
+                                    &path_node,
+                                    format!(
+                                        "exactly one field of oneOf input object \"{}\" must be provided, but {} were provided",
+                                        object_name,
+                                        values.len()
+                                    ),
+                                ));
+                            }
+                            if let Some((name, ConstValue::Null)) = values.iter().next() {
+                                return Some(valid_error(
+                                    &path_node,
+                                    format!(
+                                        "field \"{}\" of oneOf input object \"{}\" must not be null",
+                                        name, object_name
+                                    ),
+                                ));
+                            }
+                        }
+
                         let mut input_names = values
                             .keys()
                             .map(|name| name.as_ref())
@@ -154,11 +201,16 @@ pub fn is_valid_input_value(
                             }
                         }
 
-                        if let Some(name) = input_names.iter().next() {
-                            return Some(valid_error(
-                                &path_node,
-                                format!("unknown field \"{}\" of type \"{}\"", name, object_name),
-                            ));
+                        if *deny_unknown_fields {
+                            if let Some(name) = input_names.iter().next() {
+                                return Some(valid_error(
+                                    &path_node,
+                                    format!(
+                                        "unknown field \"{}\" of type \"{}\"",
+                                        name, object_name
+                                    ),
+                                ));
+                            }
                         }
 
                         None
@@ -1,4 +1,4 @@
-use crate::error::RuleError;
+use crate::error::{RuleError, RuleErrorKind};
 use crate::parser::types::{
     Directive, ExecutableDefinition, ExecutableDocument, Field, FragmentDefinition, FragmentSpread,
     InlineFragment, Name, OperationDefinition, OperationType, Selection, SelectionSet,
@@ -8,9 +8,13 @@ use crate::registry::{self, MetaType, MetaTypeName};
 use crate::{Pos, Positioned, Variables};
 use std::collections::HashMap;
 
+/// The state of a validation pass, shared by all the visitors.
 pub struct VisitorContext<'a> {
+    /// The registry of the schema.
     pub registry: &'a registry::Registry,
+    /// The variables of the request, if they are known.
     pub variables: Option<&'a Variables>,
+    /// The errors reported so far.
     pub errors: Vec<RuleError>,
     type_stack: Vec<Option<&'a registry::MetaType>>,
     input_type: Vec<Option<MetaTypeName<'a>>>,
@@ -18,7 +22,7 @@ pub struct VisitorContext<'a> {
 }
 
 impl<'a> VisitorContext<'a> {
-    pub fn new(
+    pub(crate) fn new(
         registry: &'a registry::Registry,
         doc: &'a ExecutableDocument,
         variables: Option<&'a Variables>,
@@ -42,18 +46,26 @@ impl<'a> VisitorContext<'a> {
         }
     }
 
+    /// Report a validation error at the given positions.
     pub fn report_error<T: Into<String>>(&mut self, locations: Vec<Pos>, msg: T) {
-        self.errors.push(RuleError {
-            locations,
-            message: msg.into(),
-        })
+        self.errors.push(RuleError::new(locations, msg))
     }
 
-    pub fn append_errors(&mut self, errors: Vec<RuleError>) {
+    pub(crate) fn report_error_with_kind<T: Into<String>>(
+        &mut self,
+        locations: Vec<Pos>,
+        msg: T,
+        kind: RuleErrorKind,
+    ) {
+        self.errors
+            .push(RuleError::new(locations, msg).with_kind(kind))
+    }
+
+    pub(crate) fn append_errors(&mut self, errors: Vec<RuleError>) {
         self.errors.extend(errors);
     }
 
-    pub fn with_type<F: FnMut(&mut VisitorContext<'a>)>(
+    pub(crate) fn with_type<F: FnMut(&mut VisitorContext<'a>)>(
         &mut self,
         ty: Option<&'a registry::MetaType>,
         mut f: F,
@@ -63,7 +75,7 @@ impl<'a> VisitorContext<'a> {
         self.type_stack.pop();
     }
 
-    pub fn with_input_type<F: FnMut(&mut VisitorContext<'a>)>(
+    pub(crate) fn with_input_type<F: FnMut(&mut VisitorContext<'a>)>(
         &mut self,
         ty: Option<MetaTypeName<'a>>,
         mut f: F,
@@ -73,6 +85,7 @@ impl<'a> VisitorContext<'a> {
         self.input_type.pop();
     }
 
+    /// The type that the parent of the current node belongs to, e.g. the object type of a field.
     pub fn parent_type(&self) -> Option<&'a registry::MetaType> {
         if self.type_stack.len() >= 2 {
             self.type_stack
@@ -84,29 +97,41 @@ impl<'a> VisitorContext<'a> {
         }
     }
 
+    /// The type of the current node, e.g. the return type of a field.
     pub fn current_type(&self) -> Option<&'a registry::MetaType> {
         self.type_stack.last().copied().flatten()
     }
 
+    /// Returns `true` if the document defines a fragment with this name.
     pub fn is_known_fragment(&self, name: &str) -> bool {
         self.fragments.contains_key(name)
     }
 
+    /// Get a fragment definition of the document by name.
     pub fn fragment(&self, name: &str) -> Option<&'a Positioned<FragmentDefinition>> {
         self.fragments.get(name).copied()
     }
 }
 
+/// A validation rule that visits the nodes of a query document.
+///
+/// Every method has an empty default implementation, so a rule only implements the ones for the
+/// nodes it checks, and reports errors with `VisitorContext::report_error`. Custom rules are added
+/// with `SchemaBuilder::add_validation_rule`.
 pub trait Visitor<'a> {
+    /// Called when entering the document.
     fn enter_document(&mut self, _ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {}
+    /// Called when leaving the document.
     fn exit_document(&mut self, _ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {}
 
+    /// Called when entering an operation definition.
     fn enter_operation_definition(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
         _operation_definition: &'a Positioned<OperationDefinition>,
     ) {
     }
+    /// Called when leaving an operation definition.
     fn exit_operation_definition(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -114,12 +139,14 @@ pub trait Visitor<'a> {
     ) {
     }
 
+    /// Called when entering a fragment definition.
     fn enter_fragment_definition(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
         _fragment_definition: &'a Positioned<FragmentDefinition>,
     ) {
     }
+    /// Called when leaving a fragment definition.
     fn exit_fragment_definition(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -127,12 +154,14 @@ pub trait Visitor<'a> {
     ) {
     }
 
+    /// Called when entering a variable definition.
     fn enter_variable_definition(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
         _variable_definition: &'a Positioned<VariableDefinition>,
     ) {
     }
+    /// Called when leaving a variable definition.
     fn exit_variable_definition(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -140,12 +169,14 @@ pub trait Visitor<'a> {
     ) {
     }
 
+    /// Called when entering a directive.
     fn enter_directive(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
         _directive: &'a Positioned<Directive>,
     ) {
     }
+    /// Called when leaving a directive.
     fn exit_directive(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -153,6 +184,7 @@ pub trait Visitor<'a> {
     ) {
     }
 
+    /// Called when entering an argument.
     fn enter_argument(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -160,6 +192,7 @@ pub trait Visitor<'a> {
         _value: &'a Positioned<Value>,
     ) {
     }
+    /// Called when leaving an argument.
     fn exit_argument(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -168,12 +201,14 @@ pub trait Visitor<'a> {
     ) {
     }
 
+    /// Called when entering a selection set.
     fn enter_selection_set(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
         _selection_set: &'a Positioned<SelectionSet>,
     ) {
     }
+    /// Called when leaving a selection set.
     fn exit_selection_set(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -181,12 +216,14 @@ pub trait Visitor<'a> {
     ) {
     }
 
+    /// Called when entering a selection.
     fn enter_selection(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
         _selection: &'a Positioned<Selection>,
     ) {
     }
+    /// Called when leaving a selection.
     fn exit_selection(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -194,15 +231,19 @@ pub trait Visitor<'a> {
     ) {
     }
 
+    /// Called when entering a field.
     fn enter_field(&mut self, _ctx: &mut VisitorContext<'a>, _field: &'a Positioned<Field>) {}
+    /// Called when leaving a field.
     fn exit_field(&mut self, _ctx: &mut VisitorContext<'a>, _field: &'a Positioned<Field>) {}
 
+    /// Called when entering a fragment spread.
     fn enter_fragment_spread(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
         _fragment_spread: &'a Positioned<FragmentSpread>,
     ) {
     }
+    /// Called when leaving a fragment spread.
     fn exit_fragment_spread(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -210,12 +251,14 @@ pub trait Visitor<'a> {
     ) {
     }
 
+    /// Called when entering an inline fragment.
     fn enter_inline_fragment(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
         _inline_fragment: &'a Positioned<InlineFragment>,
     ) {
     }
+    /// Called when leaving an inline fragment.
     fn exit_inline_fragment(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -223,6 +266,7 @@ pub trait Visitor<'a> {
     ) {
     }
 
+    /// Called when entering an input value.
     fn enter_input_value(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -231,6 +275,7 @@ pub trait Visitor<'a> {
         _value: &'a Value,
     ) {
     }
+    /// Called when leaving an input value.
     fn exit_input_value(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -449,7 +494,7 @@ where
     }
 }
 
-pub fn visit<'a, V: Visitor<'a>>(
+pub fn visit<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     doc: &'a ExecutableDocument,
@@ -459,7 +504,7 @@ pub fn visit<'a, V: Visitor<'a>>(
     v.exit_document(ctx, doc);
 }
 
-fn visit_definitions<'a, V: Visitor<'a>>(
+fn visit_definitions<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     doc: &'a ExecutableDocument,
@@ -479,7 +524,7 @@ fn visit_definitions<'a, V: Visitor<'a>>(
     }
 }
 
-fn visit_operation_definition<'a, V: Visitor<'a>>(
+fn visit_operation_definition<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     operation: &'a Positioned<OperationDefinition>,
@@ -506,7 +551,7 @@ fn visit_operation_definition<'a, V: Visitor<'a>>(
     v.exit_operation_definition(ctx, operation);
 }
 
-fn visit_selection_set<'a, V: Visitor<'a>>(
+fn visit_selection_set<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     selection_set: &'a Positioned<SelectionSet>,
@@ -520,7 +565,7 @@ fn visit_selection_set<'a, V: Visitor<'a>>(
     }
 }
 
-fn visit_selection<'a, V: Visitor<'a>>(
+fn visit_selection<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     selection: &'a Positioned<Selection>,
@@ -560,7 +605,7 @@ fn visit_selection<'a, V: Visitor<'a>>(
     v.exit_selection(ctx, selection);
 }
 
-fn visit_field<'a, V: Visitor<'a>>(
+fn visit_field<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     field: &'a Positioned<Field>,
@@ -585,7 +630,7 @@ fn visit_field<'a, V: Visitor<'a>>(
     v.exit_field(ctx, field);
 }
 
-fn visit_input_value<'a, V: Visitor<'a>>(
+fn visit_input_value<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     pos: Pos,
@@ -643,7 +688,7 @@ fn visit_input_value<'a, V: Visitor<'a>>(
     v.exit_input_value(ctx, pos, &expected_ty, value);
 }
 
-fn visit_variable_definitions<'a, V: Visitor<'a>>(
+fn visit_variable_definitions<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     variable_definitions: &'a [Positioned<VariableDefinition>],
@@ -654,7 +699,7 @@ fn visit_variable_definitions<'a, V: Visitor<'a>>(
     }
 }
 
-fn visit_directives<'a, V: Visitor<'a>>(
+fn visit_directives<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     directives: &'a [Positioned<Directive>],
@@ -679,7 +724,7 @@ fn visit_directives<'a, V: Visitor<'a>>(
     }
 }
 
-fn visit_fragment_definition<'a, V: Visitor<'a>>(
+fn visit_fragment_definition<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     fragment: &'a Positioned<FragmentDefinition>,
@@ -690,7 +735,7 @@ fn visit_fragment_definition<'a, V: Visitor<'a>>(
     v.exit_fragment_definition(ctx, fragment);
 }
 
-fn visit_fragment_spread<'a, V: Visitor<'a>>(
+fn visit_fragment_spread<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     fragment_spread: &'a Positioned<FragmentSpread>,
@@ -700,7 +745,7 @@ fn visit_fragment_spread<'a, V: Visitor<'a>>(
     v.exit_fragment_spread(ctx, fragment_spread);
 }
 
-fn visit_inline_fragment<'a, V: Visitor<'a>>(
+fn visit_inline_fragment<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     inline_fragment: &'a Positioned<InlineFragment>,
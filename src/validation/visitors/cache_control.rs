@@ -4,7 +4,17 @@ use crate::validation::visitor::{Visitor, VisitorContext};
 use crate::{CacheControl, Positioned};
 
 pub struct CacheControlCalculate<'a> {
-    pub cache_control: &'a mut CacheControl,
+    cache_control: &'a mut CacheControl,
+    inherit_max_age: bool,
+}
+
+impl<'a> CacheControlCalculate<'a> {
+    pub fn new(cache_control: &'a mut CacheControl) -> Self {
+        Self {
+            cache_control,
+            inherit_max_age: false,
+        }
+    }
 }
 
 impl<'ctx, 'a> Visitor<'ctx> for CacheControlCalculate<'a> {
@@ -15,9 +25,19 @@ impl<'ctx, 'a> Visitor<'ctx> for CacheControlCalculate<'a> {
     ) {
         if let Some(current_type) = ctx.current_type() {
             if let MetaType::Object { cache_control, .. } = current_type {
-                self.cache_control.merge(cache_control);
+                if self.inherit_max_age {
+                    // The field inherits the max age of its parent, so only the scope is merged.
+                    self.cache_control.merge(&CacheControl {
+                        public: cache_control.public,
+                        max_age: 0,
+                        private_key: None,
+                    });
+                } else {
+                    self.cache_control.merge(cache_control);
+                }
             }
         }
+        self.inherit_max_age = false;
     }
 
     fn enter_field(&mut self, ctx: &mut VisitorContext<'_>, field: &Positioned<Field>) {
@@ -25,7 +45,28 @@ impl<'ctx, 'a> Visitor<'ctx> for CacheControlCalculate<'a> {
             .parent_type()
             .and_then(|parent| parent.field_by_name(&field.node.name.node))
         {
-            self.cache_control.merge(&registry_field.cache_control);
+            if registry_field.inherit_max_age {
+                self.cache_control.merge(&CacheControl {
+                    public: registry_field
+                        .cache_control
+                        .as_ref()
+                        .map_or(true, |cache_control| cache_control.public),
+                    max_age: 0,
+                    private_key: None,
+                });
+            } else if let Some(cache_control) = registry_field
+                .cache_control
+                .as_ref()
+                .or_else(|| ctx.registry.default_cache_control.as_ref())
+            {
+                // The default cache control only applies to the fields without their own.
+                self.cache_control.merge(cache_control);
+            }
+            self.inherit_max_age = registry_field.inherit_max_age;
         }
     }
+
+    fn exit_field(&mut self, _ctx: &mut VisitorContext<'_>, _field: &Positioned<Field>) {
+        self.inherit_max_age = false;
+    }
 }
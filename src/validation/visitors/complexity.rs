@@ -7,7 +7,10 @@ pub struct ComplexityCalculate<'a> {
 }
 
 impl<'ctx, 'a> Visitor<'ctx> for ComplexityCalculate<'a> {
-    fn enter_field(&mut self, _ctx: &mut VisitorContext<'_>, _field: &Positioned<Field>) {
-        *self.complexity += 1;
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'_>, field: &Positioned<Field>) {
+        *self.complexity += ctx
+            .parent_type()
+            .and_then(|ty| ty.field_by_name(&field.node.name.node))
+            .map_or(1, |schema_field| schema_field.complexity);
     }
 }
@@ -1,7 +1,9 @@
 mod cache_control;
 mod complexity;
 mod depth;
+mod secret_values;
 
 pub use cache_control::CacheControlCalculate;
 pub use complexity::ComplexityCalculate;
 pub use depth::DepthCalculate;
+pub use secret_values::{SecretValues, Secrets};
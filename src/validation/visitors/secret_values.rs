@@ -0,0 +1,233 @@
+use crate::parser::types::{
+    ConstValue, Directive, ExecutableDefinition, ExecutableDocument, Field, Name, Value,
+};
+use crate::registry::MetaInputValue;
+use crate::validation::utils::referenced_variables;
+use crate::validation::visitor::{Visitor, VisitorContext};
+use crate::{Pos, Positioned, Variables};
+use indexmap::map::IndexMap;
+use std::collections::HashSet;
+
+/// The values of the secret arguments of a query.
+#[derive(Default)]
+pub struct Secrets {
+    /// The positions of the values written in the query.
+    positions: Vec<Pos>,
+    /// The variables used in the values.
+    variables: HashSet<String>,
+}
+
+impl Secrets {
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty() && self.variables.is_empty()
+    }
+
+    /// Add the default values of the secret variables, which are written in the query too.
+    pub fn add_variable_defaults(&mut self, doc: &ExecutableDocument) {
+        for definition in &doc.definitions {
+            if let ExecutableDefinition::Operation(operation) = definition {
+                for variable in &operation.node.variable_definitions {
+                    if let Some(default_value) = &variable.node.default_value {
+                        if self.variables.contains(variable.node.name.node.as_str()) {
+                            self.positions.push(default_value.pos);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Replace the secret values in the query with `***`.
+    pub fn redact_query(&self, query: &str) -> String {
+        let mut spans = self
+            .positions
+            .iter()
+            .filter_map(|pos| {
+                let start = offset_of(query, *pos)?;
+                Some((start, start + value_len(&query[start..])))
+            })
+            .collect::<Vec<_>>();
+        spans.sort();
+        spans.dedup();
+
+        let mut redacted = String::with_capacity(query.len());
+        let mut last = 0;
+        for (start, end) in spans {
+            // A value nested in another secret value has already been replaced.
+            if start < last {
+                continue;
+            }
+            redacted.push_str(&query[last..start]);
+            redacted.push_str("***");
+            last = end;
+        }
+        redacted.push_str(&query[last..]);
+        redacted
+    }
+
+    /// Replace the values of the secret variables with `***`.
+    pub fn redact_variables(&self, variables: &Variables) -> Variables {
+        let mut variables = variables.clone();
+        for (name, value) in variables.0.iter_mut() {
+            if self.variables.contains(name.as_str()) {
+                *value = ConstValue::String("***".to_string());
+            }
+        }
+        variables
+    }
+}
+
+/// Get the byte offset of a position in the query, counted the same way as the parser does.
+fn offset_of(query: &str, pos: Pos) -> Option<usize> {
+    let mut line = 1;
+    let mut column = 1;
+    for (offset, c) in query.char_indices() {
+        if line == pos.line && column == pos.column {
+            return Some(offset);
+        }
+        match c {
+            '\r' => column = 1,
+            '\n' => {
+                line += 1;
+                column = 1;
+            }
+            _ => column += 1,
+        }
+    }
+    None
+}
+
+/// Get the length in bytes of the value at the start of `input`.
+fn value_len(input: &str) -> usize {
+    let bytes = input.as_bytes();
+    match bytes.first() {
+        Some(b'"') => string_len(input),
+        Some(b'[') | Some(b'{') => {
+            let mut depth = 0;
+            let mut i = 0;
+            while i < bytes.len() {
+                match bytes[i] {
+                    b'"' => {
+                        i += string_len(&input[i..]);
+                        continue;
+                    }
+                    b'[' | b'{' => depth += 1,
+                    b']' | b'}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return i + 1;
+                        }
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+            bytes.len()
+        }
+        _ => bytes
+            .iter()
+            .skip(1)
+            .position(|b| !(b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b'+' | b'.')))
+            .map_or(bytes.len(), |len| len + 1),
+    }
+}
+
+/// Get the length in bytes of the string or block string at the start of `input`.
+fn string_len(input: &str) -> usize {
+    if input.starts_with("\"\"\"") {
+        let mut i = 3;
+        while i < input.len() {
+            if input[i..].starts_with("\\\"\"\"") {
+                i += 4;
+            } else if input[i..].starts_with("\"\"\"") {
+                return i + 3;
+            } else {
+                i += 1;
+            }
+        }
+        input.len()
+    } else {
+        let bytes = input.as_bytes();
+        let mut i = 1;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\\' => i += 2,
+                b'"' => return i + 1,
+                _ => i += 1,
+            }
+        }
+        bytes.len()
+    }
+}
+
+pub struct SecretValues<'ctx, 'a> {
+    current_args: Option<&'ctx IndexMap<&'static str, MetaInputValue>>,
+    secrets: &'a mut Secrets,
+}
+
+impl<'ctx, 'a> SecretValues<'ctx, 'a> {
+    pub fn new(secrets: &'a mut Secrets) -> Self {
+        Self {
+            current_args: None,
+            secrets,
+        }
+    }
+}
+
+impl<'ctx, 'a> Visitor<'ctx> for SecretValues<'ctx, 'a> {
+    fn enter_directive(
+        &mut self,
+        ctx: &mut VisitorContext<'ctx>,
+        directive: &'ctx Positioned<Directive>,
+    ) {
+        self.current_args = ctx
+            .registry
+            .directives
+            .get(directive.node.name.node.as_str())
+            .map(|d| &d.args);
+    }
+
+    fn exit_directive(
+        &mut self,
+        _ctx: &mut VisitorContext<'ctx>,
+        _directive: &'ctx Positioned<Directive>,
+    ) {
+        self.current_args = None;
+    }
+
+    fn enter_argument(
+        &mut self,
+        _ctx: &mut VisitorContext<'ctx>,
+        name: &'ctx Positioned<Name>,
+        value: &'ctx Positioned<Value>,
+    ) {
+        let is_secret = self
+            .current_args
+            .and_then(|args| args.get(name.node.as_str()))
+            .map_or(false, |arg| arg.is_secret);
+        if !is_secret {
+            return;
+        }
+
+        // A variable on its own does not show the value in the query.
+        if !matches!(value.node, Value::Variable(_)) {
+            self.secrets.positions.push(value.pos);
+        }
+        self.secrets.variables.extend(
+            referenced_variables(&value.node)
+                .into_iter()
+                .map(ToString::to_string),
+        );
+    }
+
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'ctx>, field: &'ctx Positioned<Field>) {
+        self.current_args = ctx
+            .parent_type()
+            .and_then(|p| p.field_by_name(&field.node.name.node))
+            .map(|f| &f.args);
+    }
+
+    fn exit_field(&mut self, _ctx: &mut VisitorContext<'ctx>, _field: &'ctx Positioned<Field>) {
+        self.current_args = None;
+    }
+}
@@ -0,0 +1,109 @@
+use crate::validators::InputValueValidator;
+use crate::Value;
+
+/// Float range validator
+pub struct FloatRange {
+    /// Minimum value, including this value.
+    pub min: f64,
+
+    /// Maximum value, including this value.
+    pub max: f64,
+}
+
+impl InputValueValidator for FloatRange {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::Number(n) = value {
+            if let Some(n) = n.as_f64() {
+                if n < self.min || n > self.max {
+                    return Err(format!(
+                        "the value is {}, must be between {} and {}",
+                        n, self.min, self.max
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Float less then validator
+pub struct FloatLessThan {
+    /// Less then this value.
+    pub value: f64,
+}
+
+impl InputValueValidator for FloatLessThan {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::Number(n) = value {
+            if let Some(n) = n.as_f64() {
+                if n >= self.value {
+                    return Err(format!(
+                        "the value is {}, must be less than {}",
+                        n, self.value
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Float greater then validator
+pub struct FloatGreaterThan {
+    /// Greater then this value.
+    pub value: f64,
+}
+
+impl InputValueValidator for FloatGreaterThan {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::Number(n) = value {
+            if let Some(n) = n.as_f64() {
+                if n <= self.value {
+                    return Err(format!(
+                        "the value is {}, must be greater than {}",
+                        n, self.value
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Number multiple of validator, for both integers and floats
+pub struct MultipleOf {
+    /// The value must be a multiple of this value.
+    pub value: f64,
+}
+
+impl InputValueValidator for MultipleOf {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::Number(n) = value {
+            if self.value == 0.0 {
+                return Err("the divisor of MultipleOf must not be zero".to_string());
+            }
+            // Integers are checked exactly, they may be too large to be represented as a float.
+            if let (Some(n), true) = (n.as_i64(), self.value.fract() == 0.0) {
+                if self.value.abs() < i64::MAX as f64 {
+                    if n.checked_rem(self.value as i64).unwrap_or(0) != 0 {
+                        return Err(format!(
+                            "the value is {}, must be a multiple of {}",
+                            n, self.value
+                        ));
+                    }
+                    return Ok(());
+                }
+            }
+            if let Some(n) = n.as_f64() {
+                let quotient = n / self.value;
+                if (quotient - quotient.round()).abs() > 1e-9 {
+                    return Err(format!(
+                        "the value is {}, must be a multiple of {}",
+                        n, self.value
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,11 +1,13 @@
 //! Input value validators
 
+mod float_validators;
 mod int_validators;
 mod list_validators;
 mod string_validators;
 
 use crate::Value;
 
+pub use float_validators::{FloatGreaterThan, FloatLessThan, FloatRange, MultipleOf};
 pub use int_validators::{IntEqual, IntGreaterThan, IntLessThan, IntNonZero, IntRange};
 pub use list_validators::{ListMaxLength, ListMinLength};
 pub use string_validators::{Email, StringMaxLength, StringMinLength, MAC};
@@ -18,7 +20,7 @@ pub use string_validators::{Email, StringMaxLength, StringMinLength, MAC};
 ///
 /// ```no_run
 /// use async_graphql::*;
-/// use async_graphql::validators::{Email, MAC, IntRange};
+/// use async_graphql::validators::{Email, MAC, IntNonZero, IntRange, FloatRange, MultipleOf};
 ///
 /// struct QueryRoot;
 ///
@@ -38,6 +40,21 @@ pub use string_validators::{Email, StringMaxLength, StringMinLength, MAC};
 ///     async fn value3(&self, #[arg(validator(IntRange(min = "100", max = "200")))] value: i32) -> i32 {
 ///         unimplemented!()
 ///     }
+///
+///     // Input is a list of email addresses
+///     async fn value4(&self, #[arg(validator(list(Email)))] emails: Vec<String>) -> i32 {
+///         unimplemented!()
+///     }
+///
+///     // Input is a non-zero integer outside of 100 to 200
+///     async fn value5(&self, #[arg(validator(and(IntNonZero, not(IntRange(min = "100", max = "200")))))] value: i32) -> i32 {
+///         unimplemented!()
+///     }
+///
+///     // Input is a float between 0 and 1, in steps of 0.25
+///     async fn value6(&self, #[arg(validator(and(FloatRange(min = "0", max = "1"), MultipleOf(value = "0.25"))))] value: f64) -> i32 {
+///         unimplemented!()
+///     }
 /// }
 /// ```
 pub trait InputValueValidator
@@ -58,10 +75,22 @@ pub trait InputValueValidatorExt: InputValueValidator + Sized {
     }
 
     /// Merge two validators, and return None when either validator verifies successfully.
+    ///
+    /// If both validators fail, the error messages of both are returned.
     fn or<R: InputValueValidator>(self, other: R) -> Or<Self, R> {
         Or(self, other)
     }
 
+    /// Invert the validator, and return None only if the validator fails.
+    fn not(self) -> Not<Self> {
+        Not(self)
+    }
+
+    /// Apply the validator to each element of a list, and return None only if all elements are valid.
+    fn list(self) -> ListValidator<Self> {
+        ListValidator(self)
+    }
+
     /// Changes the error message
     fn map_err<F: Fn(String) -> String>(self, f: F) -> MapErr<Self, F> {
         MapErr(self, f)
@@ -93,10 +122,54 @@ where
     B: InputValueValidator,
 {
     fn is_valid(&self, value: &Value) -> Result<(), String> {
-        if self.0.is_valid(value).is_err() {
-            self.1.is_valid(value)
-        } else {
-            Ok(())
+        match self.0.is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err1) => self
+                .1
+                .is_valid(value)
+                .map_err(|err2| format!("{} or {}", err1, err2)),
+        }
+    }
+}
+
+/// Invalidator for `InputValueValidatorExt::not`
+pub struct Not<A>(A);
+
+impl<A> InputValueValidator for Not<A>
+where
+    A: InputValueValidator,
+{
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        match self.0.is_valid(value) {
+            Ok(()) => Err("the value must not pass the validation".to_string()),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+/// Invalidator for `InputValueValidatorExt::list`
+pub struct ListValidator<A>(A);
+
+impl<A> InputValueValidator for ListValidator<A>
+where
+    A: InputValueValidator,
+{
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        match value {
+            Value::List(values) => {
+                for (idx, value) in values.iter().enumerate() {
+                    self.0.is_valid(value).map_err(|err| {
+                        format!("the element at index {} is invalid, {}", idx, err)
+                    })?;
+                }
+                Ok(())
+            }
+            Value::Null => Ok(()),
+            // A single value is coerced to a list with one element.
+            value => self
+                .0
+                .is_valid(value)
+                .map_err(|err| format!("the element at index 0 is invalid, {}", err)),
         }
     }
 }
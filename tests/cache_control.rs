@@ -0,0 +1,408 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_cache_control_inherit_max_age() {
+    struct Item;
+
+    #[GQLObject(cache_control(max_age = 10))]
+    impl Item {
+        async fn value(&self) -> i32 {
+            1
+        }
+    }
+
+    #[derive(GQLSimpleObject)]
+    #[graphql(cache_control(max_age = 20))]
+    struct PrivateItem {
+        #[field(cache_control(private))]
+        value: i32,
+    }
+
+    struct Query;
+
+    #[GQLObject(cache_control(max_age = 60))]
+    impl Query {
+        #[field(cache_control(inherit_max_age))]
+        async fn inherited(&self) -> Item {
+            Item
+        }
+
+        async fn item(&self) -> Item {
+            Item
+        }
+
+        #[field(cache_control(inherit_max_age))]
+        async fn private_item(&self) -> PrivateItem {
+            PrivateItem { value: 2 }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute("{ inherited { value } }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl {
+            public: true,
+            max_age: 60,
+            private_key: None,
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ item { value } }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl {
+            public: true,
+            max_age: 10,
+            private_key: None,
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ inherited { value } item { value } }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl {
+            public: true,
+            max_age: 10,
+            private_key: None,
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ privateItem { value } }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl {
+            public: false,
+            max_age: 60,
+            private_key: None,
+        }
+    );
+}
+
+#[async_std::test]
+pub async fn test_cache_control_private_key() {
+    #[derive(GQLSimpleObject)]
+    #[graphql(cache_control(max_age = 30))]
+    struct Account {
+        #[field(cache_control(private, max_age = 10))]
+        balance: i32,
+        name: String,
+    }
+
+    struct User;
+
+    #[GQLObject]
+    impl User {
+        async fn account(&self) -> Account {
+            Account {
+                balance: 100,
+                name: "checking".to_string(),
+            }
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject(cache_control(max_age = 60))]
+    impl Query {
+        async fn user(&self) -> User {
+            User
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let key = || PrivateCacheKey("user-1".to_string());
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ user { account { balance } } }").data(key()))
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl {
+            public: false,
+            max_age: 10,
+            private_key: Some("user-1".to_string()),
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ user { account { name } } }").data(key()))
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl {
+            public: true,
+            max_age: 30,
+            private_key: None,
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ user { account { balance } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl {
+            public: false,
+            max_age: 10,
+            private_key: None,
+        }
+    );
+
+    // A key in the schema data would be shared by all users, so it is ignored.
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(key())
+        .finish();
+    let response = schema
+        .execute("{ user { account { name balance } } }")
+        .await
+        .into_result()
+        .unwrap();
+    assert_eq!(
+        response.cache_control,
+        CacheControl {
+            public: false,
+            max_age: 10,
+            private_key: None,
+        }
+    );
+    assert_eq!(
+        response.cache_control.header_value().as_deref(),
+        Some("max-age=10, private")
+    );
+
+    let response = schema
+        .execute(Request::new("{ user { account { name balance } } }").data(key()))
+        .await
+        .into_result()
+        .unwrap();
+    assert_eq!(
+        response.cache_control.private_key.as_deref(),
+        Some("user-1")
+    );
+}
+
+#[async_std::test]
+pub async fn test_cache_control_header_value() {
+    #[derive(GQLSimpleObject)]
+    #[graphql(cache_control(max_age = 30))]
+    struct Inner {
+        value: i32,
+    }
+
+    #[derive(GQLSimpleObject)]
+    #[graphql(cache_control(max_age = 120))]
+    struct Outer {
+        #[field(cache_control(max_age = 90))]
+        name: String,
+        inner: Inner,
+    }
+
+    struct Query;
+
+    #[GQLObject(cache_control(max_age = 60))]
+    impl Query {
+        async fn outer(&self) -> Outer {
+            Outer {
+                name: "abc".to_string(),
+                inner: Inner { value: 1 },
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute("{ outer { name } }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control
+            .header_value()
+            .as_deref(),
+        Some("max-age=60")
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ outer { name inner { value } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control
+            .header_value()
+            .as_deref(),
+        Some("max-age=30")
+    );
+}
+
+#[async_std::test]
+pub async fn test_default_cache_control() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value1(&self) -> i32 {
+            1
+        }
+
+        #[field(cache_control(max_age = 10))]
+        async fn value2(&self) -> i32 {
+            2
+        }
+
+        #[field(cache_control(private))]
+        async fn value3(&self) -> i32 {
+            3
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .default_cache_control(CacheControl {
+            public: true,
+            max_age: 30,
+            private_key: None,
+        })
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ value1 }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl {
+            public: true,
+            max_age: 30,
+            private_key: None,
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value1 value2 }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl {
+            public: true,
+            max_age: 10,
+            private_key: None,
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value1 value3 }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl {
+            public: false,
+            max_age: 30,
+            private_key: None,
+        }
+    );
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ value1 }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl::default()
+    );
+}
+
+#[async_std::test]
+pub async fn test_explicit_cache_control_overrides_default() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value1(&self) -> i32 {
+            1
+        }
+
+        #[field(cache_control(max_age = 0))]
+        async fn value2(&self) -> i32 {
+            2
+        }
+
+        #[field(cache_control(public))]
+        async fn value3(&self) -> i32 {
+            3
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .default_cache_control(CacheControl {
+            public: false,
+            max_age: 30,
+            private_key: None,
+        })
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ value1 }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl {
+            public: false,
+            max_age: 30,
+            private_key: None,
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value2 }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl::default()
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value3 }")
+            .await
+            .into_result()
+            .unwrap()
+            .cache_control,
+        CacheControl::default()
+    );
+}
@@ -0,0 +1,88 @@
+use async_graphql::extensions::Extension;
+use async_graphql::*;
+use futures::StreamExt;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+fn query_hash(query: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    query.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[async_std::test]
+pub async fn test_cached_response() {
+    struct EdgeCache {
+        responses: Arc<HashMap<u64, serde_json::Value>>,
+    }
+
+    impl Extension for EdgeCache {
+        fn cached_response(&mut self, request: &Request) -> Option<Response> {
+            self.responses
+                .get(&query_hash(&request.query))
+                .map(|data| Response::new(data.clone()))
+        }
+    }
+
+    struct Query {
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            10
+        }
+    }
+
+    let mut responses = HashMap::new();
+    responses.insert(query_hash("{ value }"), serde_json::json!({ "value": 100 }));
+    let responses = Arc::new(responses);
+    let calls = Arc::new(AtomicUsize::default());
+    let schema = Schema::build(
+        Query {
+            calls: calls.clone(),
+        },
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .extension(move || EdgeCache {
+        responses: responses.clone(),
+    })
+    .on_response(|response| response.extensions(Some(serde_json::json!({ "hooked": true }))))
+    .finish();
+
+    let response = schema.execute("{ value }").await.into_result().unwrap();
+    assert_eq!(response.data, serde_json::json!({ "value": 100 }));
+    assert_eq!(
+        response.extensions,
+        Some(serde_json::json!({ "hooked": true }))
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    assert_eq!(
+        schema
+            .execute_stream("{ value }")
+            .map(|response| response.into_result().unwrap().data)
+            .collect::<Vec<_>>()
+            .await,
+        vec![serde_json::json!({ "value": 100 })]
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    // Queries that are not cached are executed.
+    assert_eq!(
+        schema
+            .execute("{ value value2: value }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "value": 10, "value2": 10 })
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
@@ -134,3 +134,295 @@ pub async fn test_complexity_and_depth() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_query_bytes() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_query_bytes(16)
+        .finish();
+    assert_eq!(
+        schema
+            .execute("{ value }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "value": 10 })
+    );
+
+    // The query is rejected before it is parsed, so the syntax error is not reported.
+    let query = format!("{{ value {}", " ".repeat(100));
+    assert_eq!(
+        schema
+            .execute(query.as_str())
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos::default(),
+            path: None,
+            err: QueryError::TooLarge,
+        }
+    );
+    assert!(matches!(
+        Schema::new(Query, EmptyMutation, EmptySubscription)
+            .execute(query.as_str())
+            .await
+            .into_result(),
+        Err(Error::Parse(_))
+    ));
+}
+
+#[async_std::test]
+pub async fn test_limit_parse() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self, input: Option<Json<serde_json::Value>>) -> bool {
+            input.is_some()
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_value_depth(3)
+        .limit_tokens(20)
+        .finish();
+    assert_eq!(
+        schema
+            .execute("{ value(input: [{ a: [1] }]) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "value": true })
+    );
+
+    // A nesting deep enough to overflow the stack of the parser.
+    let query = format!(
+        "{{ value(input: {}1{}) }}",
+        "[".repeat(100_000),
+        "]".repeat(100_000)
+    );
+    assert_eq!(
+        schema
+            .execute(query.as_str())
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Parse(parser::Error::new(
+            "The values are nested more than 3 levels deep",
+            Pos {
+                line: 1,
+                column: 19
+            }
+        ))
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ a: value b: value c: value d: value e: value f: value g: value }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Parse(parser::Error::new(
+            "The query has more than 20 tokens",
+            Pos {
+                line: 1,
+                column: 58
+            }
+        ))
+    );
+}
+
+#[async_std::test]
+pub async fn test_complexity_report() {
+    struct Query;
+
+    struct MyObj;
+
+    #[GQLObject]
+    impl MyObj {
+        async fn a(&self) -> i32 {
+            1
+        }
+
+        async fn b(&self) -> i32 {
+            2
+        }
+
+        async fn c(&self) -> MyObj {
+            MyObj
+        }
+    }
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            1
+        }
+
+        async fn obj(&self) -> MyObj {
+            MyObj
+        }
+    }
+
+    let query = r#"
+        {
+            value # 0
+            obj { # 0
+                a c { # 1
+                    a b # 2
+                }
+            }
+        }"#;
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .with_complexity_report()
+        .finish();
+    let response = schema.execute(query).await.into_result().unwrap();
+    assert_eq!(
+        response.data,
+        serde_json::json!({
+            "value": 1,
+            "obj": { "a": 1, "c": { "a": 1, "b": 2 } },
+        })
+    );
+    assert_eq!(
+        response.extensions,
+        Some(serde_json::json!({
+            "complexityReport": { "complexity": 6, "depth": 2 },
+        }))
+    );
+
+    let response = Schema::new(Query, EmptyMutation, EmptySubscription)
+        .execute(query)
+        .await
+        .into_result()
+        .unwrap();
+    assert_eq!(response.extensions, None);
+}
+
+#[async_std::test]
+pub async fn test_field_complexity() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            1
+        }
+
+        #[field(complexity = 5)]
+        async fn expensive(&self) -> i32 {
+            2
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_complexity(5)
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ a: value b: value c: value d: value }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "a": 1, "b": 1, "c": 1, "d": 1 })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value expensive }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 0, column: 0 },
+            path: None,
+            err: QueryError::TooComplex,
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ expensive }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "expensive": 2 })
+    );
+}
+
+#[async_std::test]
+pub async fn test_interface_field_complexity() {
+    struct MyObj;
+
+    #[GQLObject]
+    impl MyObj {
+        async fn value(&self) -> i32 {
+            1
+        }
+
+        #[field(complexity = 5)]
+        async fn expensive(&self) -> i32 {
+            2
+        }
+    }
+
+    #[derive(GQLInterface)]
+    #[graphql(
+        field(name = "value", type = "i32"),
+        field(name = "expensive", type = "i32", complexity = 5)
+    )]
+    enum MyInterface {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn obj(&self) -> MyInterface {
+            MyObj.into()
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_complexity(5)
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ obj { a: value b: value c: value d: value } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "obj": { "a": 1, "b": 1, "c": 1, "d": 1 } })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ obj { expensive } }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 0, column: 0 },
+            path: None,
+            err: QueryError::TooComplex,
+        }
+    );
+}
@@ -92,3 +92,221 @@ pub async fn test_connection_additional_fields() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_connection_custom_cursor() {
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+    struct TimestampCursor {
+        timestamp: i64,
+        id: i32,
+    }
+
+    impl CursorType for TimestampCursor {
+        type Error = &'static str;
+
+        fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+            let s = base64::decode(s).map_err(|_| "Invalid cursor")?;
+            let s = std::str::from_utf8(&s).map_err(|_| "Invalid cursor")?;
+            let mut parts = s.splitn(2, ':');
+            match (
+                parts.next().and_then(|s| s.parse().ok()),
+                parts.next().and_then(|s| s.parse().ok()),
+            ) {
+                (Some(timestamp), Some(id)) => Ok(TimestampCursor { timestamp, id }),
+                _ => Err("Invalid cursor"),
+            }
+        }
+
+        fn encode_cursor(&self) -> String {
+            base64::encode(format!("{}:{}", self.timestamp, self.id))
+        }
+    }
+
+    let cursor = TimestampCursor {
+        timestamp: 1600000000,
+        id: 42,
+    };
+    assert_eq!(
+        TimestampCursor::decode_cursor(&cursor.encode_cursor()),
+        Ok(cursor)
+    );
+
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {
+        async fn items(
+            &self,
+            after: Option<String>,
+            before: Option<String>,
+            first: Option<i32>,
+            last: Option<i32>,
+        ) -> FieldResult<Connection<TimestampCursor, i32>> {
+            connection::query(
+                after,
+                before,
+                first,
+                last,
+                |after: Option<TimestampCursor>, _before, first, _last| async move {
+                    let items = (1..=5).map(|id| TimestampCursor {
+                        timestamp: 1600000000 + id as i64 * 10,
+                        id,
+                    });
+                    let items = items
+                        .filter(|cursor| after.map_or(true, |after| *cursor > after))
+                        .take(first.unwrap_or(5))
+                        .collect::<Vec<_>>();
+                    let mut connection = Connection::new(
+                        after.is_some(),
+                        items.last().map_or(false, |cursor| cursor.id < 5),
+                    );
+                    connection.append(items.into_iter().map(|cursor| Edge::new(cursor, cursor.id)));
+                    Ok(connection)
+                },
+            )
+            .await
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let after = TimestampCursor {
+        timestamp: 1600000020,
+        id: 2,
+    }
+    .encode_cursor();
+
+    assert_eq!(
+        schema
+            .execute(format!(
+                r#"{{ items(after: "{}", first: 2) {{ edges {{ cursor node }} pageInfo {{ hasNextPage }} }} }}"#,
+                after
+            ))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "items": {
+                "edges": [
+                    {
+                        "cursor": base64::encode("1600000030:3"),
+                        "node": 3,
+                    },
+                    {
+                        "cursor": base64::encode("1600000040:4"),
+                        "node": 4,
+                    },
+                ],
+                "pageInfo": { "hasNextPage": true },
+            },
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ items(after: "abc") { edges { node } } }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["items"])),
+            err: QueryError::FieldError {
+                err: "Invalid cursor".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+}
+
+#[async_std::test]
+pub async fn test_connection_resolved_fields() {
+    struct QueryRoot;
+
+    struct ConnectionFields {
+        values: Vec<i32>,
+    }
+
+    #[GQLObject]
+    impl ConnectionFields {
+        async fn total_count(&self) -> usize {
+            self.values.len()
+        }
+
+        async fn facets(&self, divisor: i32) -> Vec<i32> {
+            let mut facets: Vec<_> = self.values.iter().map(|n| n % divisor).collect();
+            facets.sort();
+            facets.dedup();
+            facets
+        }
+    }
+
+    struct EdgeFields {
+        value: i32,
+    }
+
+    #[GQLObject]
+    impl EdgeFields {
+        async fn is_even(&self) -> bool {
+            self.value % 2 == 0
+        }
+    }
+
+    #[GQLObject]
+    impl QueryRoot {
+        async fn numbers(
+            &self,
+            after: Option<String>,
+            before: Option<String>,
+            first: Option<i32>,
+            last: Option<i32>,
+        ) -> FieldResult<Connection<usize, i32, ConnectionFields, EdgeFields>> {
+            connection::query(
+                after,
+                before,
+                first,
+                last,
+                |after, _before, first, _last| async move {
+                    let values = vec![3, 4, 5, 6, 7];
+                    let start = after.map(|after| after + 1).unwrap_or(0);
+                    let end = first.map_or(values.len(), |first| (start + first).min(values.len()));
+                    let mut connection = Connection::with_additional_fields(
+                        start > 0,
+                        end < values.len(),
+                        ConnectionFields {
+                            values: values.clone(),
+                        },
+                    );
+                    connection.append((start..end).map(|n| {
+                        Edge::with_additional_fields(n, values[n], EdgeFields { value: values[n] })
+                    }));
+                    Ok(connection)
+                },
+            )
+            .await
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(
+                "{ numbers(first: 2) { totalCount facets(divisor: 3) edges { node isEven } } }"
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "numbers": {
+                "totalCount": 5,
+                "facets": [0, 1, 2],
+                "edges": [
+                    {"node": 3, "isEven": false},
+                    {"node": 4, "isEven": true},
+                ]
+            },
+        })
+    );
+}
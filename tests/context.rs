@@ -0,0 +1,413 @@
+use async_graphql::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[async_std::test]
+pub async fn test_cache_get_or_init() {
+    struct InitCount(Arc<AtomicUsize>);
+
+    struct CurrentUser {
+        name: String,
+    }
+
+    async fn current_user(ctx: &Context<'_>) -> Arc<CurrentUser> {
+        ctx.cache_get_or_init(|| async move {
+            ctx.data_unchecked::<InitCount>()
+                .0
+                .fetch_add(1, Ordering::SeqCst);
+            CurrentUser {
+                name: "sunli".to_string(),
+            }
+        })
+        .await
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn name(&self, ctx: &Context<'_>) -> String {
+            current_user(ctx).await.name.clone()
+        }
+
+        async fn greeting(&self, ctx: &Context<'_>) -> String {
+            format!("Hello, {}!", current_user(ctx).await.name)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    for _ in 0..2 {
+        let count = Arc::new(AtomicUsize::new(0));
+        assert_eq!(
+            schema
+                .execute(Request::new("{ name greeting }").data(InitCount(count.clone())))
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            serde_json::json!({
+                "name": "sunli",
+                "greeting": "Hello, sunli!",
+            })
+        );
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+}
+
+#[async_std::test]
+pub async fn test_request_data_shadows_schema_data() {
+    struct Name(&'static str);
+
+    struct Version(i32);
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn name(&self, ctx: &Context<'_>) -> &'static str {
+            ctx.data_unchecked::<Name>().0
+        }
+
+        async fn version(&self, ctx: &Context<'_>) -> FieldResult<i32> {
+            Ok(ctx.data::<Version>()?.0)
+        }
+
+        async fn missing(&self, ctx: &Context<'_>) -> bool {
+            ctx.data_opt::<String>().is_some()
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(Name("schema"))
+        .data(Version(1))
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ name version missing }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "name": "schema",
+            "version": 1,
+            "missing": false,
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ name version missing }").data(Name("request")))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "name": "request",
+            "version": 1,
+            "missing": false,
+        })
+    );
+
+    // The request data does not leak into other requests.
+    assert_eq!(
+        schema.execute("{ name }").await.into_result().unwrap().data,
+        serde_json::json!({ "name": "schema" })
+    );
+}
+
+#[async_std::test]
+pub async fn test_raw_body() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct Mutation;
+
+    #[GQLObject]
+    impl Mutation {
+        async fn webhook(&self, ctx: &Context<'_>) -> Option<String> {
+            ctx.raw_body()
+                .map(|body| String::from_utf8_lossy(body).into_owned())
+        }
+    }
+
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(Request::new("mutation { webhook }").raw_body(b"signed payload".to_vec()))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "webhook": "signed payload" })
+    );
+
+    assert_eq!(
+        schema
+            .execute("mutation { webhook }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "webhook": null })
+    );
+}
+
+#[async_std::test]
+pub async fn test_context_field() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn echo(
+            &self,
+            ctx: &Context<'_>,
+            _name: Option<String>,
+            _values: Option<Vec<i32>>,
+        ) -> FieldResult<OutputJson<serde_json::Value>> {
+            let field = ctx.field();
+            let arguments = field
+                .arguments()?
+                .into_iter()
+                .map(|(name, value)| Ok((name.to_string(), value.into_json()?)))
+                .collect::<FieldResult<serde_json::Map<_, _>>>()?;
+            Ok(OutputJson(serde_json::json!({
+                "name": field.name(),
+                "alias": field.alias(),
+                "arguments": arguments,
+            })))
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"query($values: [Int!]) {
+        echo(name: "abc")
+        other: echo(values: $values)
+    }"#;
+    assert_eq!(
+        schema
+            .execute(Request::new(query).variables(Variables::from_json(
+                serde_json::json!({ "values": [1, 2, 3] })
+            )))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "echo": {
+                "name": "echo",
+                "alias": null,
+                "arguments": { "name": "abc" },
+            },
+            "other": {
+                "name": "echo",
+                "alias": "other",
+                "arguments": { "values": [1, 2, 3] },
+            },
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_context_field_directives() {
+    fn to_json(
+        directives: impl Iterator<Item = SelectionDirective<'_>>,
+    ) -> FieldResult<serde_json::Value> {
+        directives
+            .map(|directive| {
+                let arguments = directive
+                    .arguments()?
+                    .into_iter()
+                    .map(|(name, value)| Ok((name.to_string(), value.into_json()?)))
+                    .collect::<FieldResult<serde_json::Map<_, _>>>()?;
+                Ok(serde_json::json!({ "name": directive.name(), "arguments": arguments }))
+            })
+            .collect()
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn directives(
+            &self,
+            ctx: &Context<'_>,
+        ) -> FieldResult<OutputJson<serde_json::Value>> {
+            let field = ctx.field();
+            Ok(OutputJson(serde_json::json!({
+                "field": to_json(field.directives())?,
+                "fragments": to_json(field.fragment_directives())?,
+            })))
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"query($show: Boolean!) {
+        a: directives @include(if: true)
+        ... @include(if: $show) {
+            b: directives
+            ... F @skip(if: false)
+        }
+    }
+
+    fragment F on Query {
+        c: directives
+    }"#;
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(query)
+                    .variables(Variables::from_json(serde_json::json!({ "show": true })))
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "a": {
+                "field": [{ "name": "include", "arguments": { "if": true } }],
+                "fragments": [],
+            },
+            "b": {
+                "field": [],
+                "fragments": [{ "name": "include", "arguments": { "if": true } }],
+            },
+            "c": {
+                "field": [],
+                "fragments": [
+                    { "name": "include", "arguments": { "if": true } },
+                    { "name": "skip", "arguments": { "if": false } },
+                ],
+            },
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_context_custom_directive() {
+    use async_graphql::registry::{__DirectiveLocation, MetaDirective, MetaInputValue};
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn secret(&self, ctx: &Context<'_>) -> FieldResult<String> {
+            let field = ctx.field();
+            let role = field
+                .directives()
+                .chain(field.fragment_directives())
+                .filter(|directive| directive.name() == "authz")
+                .map(|directive| directive.arguments())
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .flatten()
+                .find(|(name, _)| *name == "role")
+                .map(|(_, value)| value);
+            match role {
+                Some(Value::String(role)) => Ok(format!("secret for {}", role)),
+                _ => Err("Forbidden".into()),
+            }
+        }
+    }
+
+    // Custom directives are unknown until they are registered.
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert!(matches!(
+        schema
+            .execute(r#"{ secret @authz(role: "admin") }"#)
+            .await
+            .into_result(),
+        Err(Error::Rule { .. })
+    ));
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_directive(MetaDirective {
+            name: "authz",
+            description: None,
+            locations: vec![
+                __DirectiveLocation::FIELD,
+                __DirectiveLocation::INLINE_FRAGMENT,
+            ],
+            args: {
+                let mut args = indexmap::IndexMap::new();
+                args.insert(
+                    "role",
+                    MetaInputValue {
+                        name: "role",
+                        description: None,
+                        ty: "String!".to_string(),
+                        default_value: None,
+                        validator: None,
+                        is_secret: false,
+                    },
+                );
+                args
+            },
+        })
+        .finish();
+
+    let query = r#"query($role: String!) {
+        a: secret @authz(role: "admin")
+        ... @authz(role: $role) {
+            b: secret
+        }
+    }"#;
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(query)
+                    .variables(Variables::from_json(serde_json::json!({ "role": "user" })))
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "a": "secret for admin",
+            "b": "secret for user",
+        })
+    );
+
+    // The arguments and locations of the directive are validated.
+    for query in &[
+        "{ secret @authz }",
+        "{ secret @authz(role: 1) }",
+        r#"query @authz(role: "admin") { secret }"#,
+    ] {
+        assert!(matches!(
+            schema.execute(*query).await.into_result(),
+            Err(Error::Rule { .. })
+        ));
+    }
+}
+
+#[test]
+#[should_panic(expected = "Directive \"skip\" is already registered.")]
+pub fn test_context_custom_directive_clash() {
+    use async_graphql::registry::MetaDirective;
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            1
+        }
+    }
+
+    Schema::build(Query, EmptyMutation, EmptySubscription).register_directive(MetaDirective {
+        name: "skip",
+        description: None,
+        locations: Vec::new(),
+        args: Default::default(),
+    });
+}
@@ -102,3 +102,70 @@ pub async fn test_default_value_inputobject() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_default_value_from_context() {
+    struct Locale(String);
+
+    fn default_locale(ctx: &Context<'_>) -> String {
+        ctx.data_unchecked::<Locale>().0.clone()
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn greeting(&self, #[arg(default_ctx = "default_locale")] locale: String) -> String {
+            format!("hello ({})", locale)
+        }
+
+        async fn page_size(
+            &self,
+            #[arg(default_ctx = "|ctx| ctx.data_unchecked::<Locale>().0.len() as i32")] size: i32,
+        ) -> i32 {
+            size
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(Locale("en-US".to_string()))
+        .finish();
+    assert_eq!(
+        schema
+            .execute(
+                r#"{ a: greeting b: greeting(locale: null) c: greeting(locale: "fr") pageSize }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "a": "hello (en-US)",
+            "b": "hello (en-US)",
+            "c": "hello (fr)",
+            "pageSize": 5,
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(Request::new(r#"{ greeting }"#).data(Locale("de".to_string())))
+            .await
+            .data,
+        serde_json::json!({ "greeting": "hello (de)" })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Query") { fields { args { name type { kind name } defaultValue } } } }"#)
+            .await
+            .data["__type"]["fields"][0],
+        serde_json::json!({
+            "args": [{
+                "name": "locale",
+                "type": { "kind": "SCALAR", "name": "String" },
+                "defaultValue": null,
+            }],
+        })
+    );
+}
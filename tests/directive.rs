@@ -115,3 +115,149 @@ pub async fn test_directive_ifdef() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_directive_include_false_is_absent() {
+    #[derive(GQLSimpleObject)]
+    struct MyObj {
+        a: i32,
+        b: Option<i32>,
+    }
+
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {
+        pub async fn value(&self) -> Option<i32> {
+            Some(10)
+        }
+
+        pub async fn obj(&self) -> MyObj {
+            MyObj { a: 1, b: None }
+        }
+    }
+
+    struct MutationRoot;
+
+    #[GQLObject]
+    impl MutationRoot {
+        pub async fn action(&self) -> Option<i32> {
+            Some(10)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, MutationRoot, EmptySubscription);
+    let resp = schema
+        .execute(
+            r#"
+            {
+                value @include(if: false)
+                obj {
+                    a
+                    b @include(if: false)
+                    ... on MyObj @include(if: false) {
+                        c: a
+                    }
+                    ...Fields @include(if: false)
+                }
+            }
+
+            fragment Fields on MyObj {
+                d: a
+            }
+        "#,
+        )
+        .await;
+    assert_eq!(
+        resp.data,
+        serde_json::json!({
+            "obj": {
+                "a": 1,
+            },
+        })
+    );
+
+    let resp = schema
+        .execute(
+            r#"
+            mutation {
+                action1: action @include(if: false)
+                action2: action
+            }
+        "#,
+        )
+        .await;
+    assert_eq!(
+        resp.data,
+        serde_json::json!({
+            "action2": 10,
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_directive_skip_fragments() {
+    #[derive(GQLSimpleObject)]
+    struct MyObj {
+        a: i32,
+        b: i32,
+    }
+
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {
+        pub async fn obj(&self) -> MyObj {
+            MyObj { a: 1, b: 2 }
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let query = r#"
+        query($skip: Boolean!) {
+            obj {
+                ...Frag @skip(if: true)
+                ... on MyObj @skip(if: $skip) {
+                    c: a
+                }
+                ... @skip(if: false) {
+                    b
+                }
+            }
+        }
+
+        fragment Frag on MyObj {
+            a
+        }
+    "#;
+    let resp = schema
+        .execute(
+            Request::new(query)
+                .variables(Variables::from_json(serde_json::json!({ "skip": true }))),
+        )
+        .await;
+    assert_eq!(
+        resp.into_result().unwrap().data,
+        serde_json::json!({
+            "obj": {
+                "b": 2,
+            },
+        })
+    );
+
+    let resp = schema
+        .execute(
+            Request::new(query)
+                .variables(Variables::from_json(serde_json::json!({ "skip": false }))),
+        )
+        .await;
+    assert_eq!(
+        resp.into_result().unwrap().data,
+        serde_json::json!({
+            "obj": {
+                "c": 1,
+                "b": 2,
+            },
+        })
+    );
+}
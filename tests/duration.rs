@@ -0,0 +1,65 @@
+#![cfg(all(feature = "duration", feature = "chrono"))]
+
+use async_graphql::*;
+use std::time::Duration;
+
+#[async_std::test]
+pub async fn test_duration_formats() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn timeout(&self, value: Duration) -> Duration {
+            value
+        }
+
+        async fn timeout_seconds(&self, value: DurationSeconds) -> DurationSeconds {
+            value
+        }
+
+        async fn offset(&self, value: chrono::Duration) -> chrono::Duration {
+            value
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ timeout(value: "PT90M") timeoutSeconds(value: 90) offset(value: -90) }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "timeout": "PT1H30M",
+            "timeoutSeconds": 90,
+            "offset": "-PT1M30S",
+        })
+    );
+
+    // The scalars have distinct names, since they don't accept the same values.
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    a: __type(name: "Duration") { kind }
+                    b: __type(name: "DurationSeconds") { kind }
+                    c: __type(name: "SignedDuration") { kind }
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "a": { "kind": "SCALAR" },
+            "b": { "kind": "SCALAR" },
+            "c": { "kind": "SCALAR" },
+        })
+    );
+
+    assert!(schema
+        .execute(r#"{ timeout(value: "-PT90M") }"#)
+        .await
+        .is_err());
+}
@@ -70,3 +70,250 @@ pub async fn test_enum_derive_and_item_attributes() {
         TestStruct { value: Test::Real }
     );
 }
+
+#[async_std::test]
+pub async fn test_enum_as_int() {
+    #[derive(GQLEnum, Copy, Clone, Eq, PartialEq)]
+    #[graphql(as_int)]
+    enum MyEnum {
+        A = 1,
+        B = 5,
+    }
+
+    struct Root;
+
+    #[GQLObject]
+    impl Root {
+        async fn value(&self) -> MyEnum {
+            MyEnum::A
+        }
+
+        async fn test_arg(&self, input: MyEnum) -> MyEnum {
+            input
+        }
+    }
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    let query = Request::new(
+        r#"
+            query QueryWithVariables($input: MyEnum!) {
+                value
+                testArg(input: $input)
+                other: testArg(input: B)
+            }
+        "#,
+    )
+    .variables(Variables::from_json(serde_json::json!({
+        "input": 5,
+    })));
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "value": 1,
+            "testArg": 5,
+            "other": 5,
+        })
+    );
+
+    let query = Request::new(
+        r#"
+            query QueryWithVariables($input: MyEnum!) {
+                testArg(input: $input)
+            }
+        "#,
+    )
+    .variables(Variables::from_json(serde_json::json!({
+        "input": 3,
+    })));
+    assert!(schema.execute(query).await.is_err());
+}
+
+#[async_std::test]
+pub async fn test_enum_deprecation() {
+    #[derive(GQLEnum, Copy, Clone, Eq, PartialEq)]
+    enum Color {
+        Red,
+        #[item(deprecation = "Use RED")]
+        Crimson,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn color(&self) -> Color {
+            Color::Crimson
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_federation()
+        .finish();
+    let data = schema
+        .execute(
+            r#"{
+                color
+                all: __type(name: "Color") {
+                    enumValues(includeDeprecated: true) { name isDeprecated deprecationReason }
+                }
+                current: __type(name: "Color") { enumValues { name } }
+                _service { sdl }
+            }"#,
+        )
+        .await
+        .into_result()
+        .unwrap()
+        .data;
+    assert_eq!(data["color"], serde_json::json!("CRIMSON"));
+    assert_eq!(
+        data["all"]["enumValues"],
+        serde_json::json!([
+            { "name": "RED", "isDeprecated": false, "deprecationReason": null },
+            { "name": "CRIMSON", "isDeprecated": true, "deprecationReason": "Use RED" },
+        ])
+    );
+    assert_eq!(
+        data["current"]["enumValues"],
+        serde_json::json!([{ "name": "RED" }])
+    );
+    assert!(data["_service"]["sdl"]
+        .as_str()
+        .unwrap()
+        .contains("enum Color {\n\tRED\n\tCRIMSON @deprecated(reason: \"Use RED\")\n}"));
+}
+
+#[async_std::test]
+pub async fn test_enum_deprecation_reason_escaped() {
+    #[derive(GQLEnum, Copy, Clone, Eq, PartialEq)]
+    enum Color {
+        Red,
+        #[item(deprecation = "Use \"RED\"\nor C:\\RED")]
+        Crimson,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn color(&self) -> Color {
+            Color::Red
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_federation()
+        .finish();
+    let data = schema
+        .execute("{ _service { sdl } }")
+        .await
+        .into_result()
+        .unwrap()
+        .data;
+    assert!(data["_service"]["sdl"]
+        .as_str()
+        .unwrap()
+        .contains(r#"CRIMSON @deprecated(reason: "Use \"RED\"\nor C:\\RED")"#));
+}
+
+#[async_std::test]
+pub async fn test_enum_case_insensitive() {
+    #[derive(GQLEnum, Copy, Clone, Eq, PartialEq, Debug)]
+    #[graphql(case_insensitive)]
+    enum Loose {
+        One,
+        Two,
+    }
+
+    #[derive(GQLEnum, Copy, Clone, Eq, PartialEq, Debug)]
+    enum Strict {
+        One,
+        Two,
+    }
+
+    assert_eq!(
+        Loose::parse(Some(Value::String("one".to_string()))).unwrap(),
+        Loose::One
+    );
+    assert!(Strict::parse(Some(Value::String("one".to_string()))).is_err());
+    assert_eq!(
+        Strict::parse(Some(Value::String("ONE".to_string()))).unwrap(),
+        Strict::One
+    );
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn loose(&self, input: Loose) -> Loose {
+            input
+        }
+
+        async fn strict(&self, input: Strict) -> Strict {
+            input
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = Request::new(
+        r#"query($input: Loose!) {
+            a: loose(input: one)
+            b: loose(input: $input)
+        }"#,
+    )
+    .variables(Variables::from_json(serde_json::json!({ "input": "two" })));
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({ "a": "ONE", "b": "TWO" })
+    );
+
+    assert!(schema.execute("{ strict(input: one) }").await.is_err());
+    let query = Request::new(r#"query($input: Strict!) { strict(input: $input) }"#)
+        .variables(Variables::from_json(serde_json::json!({ "input": "two" })));
+    assert!(schema.execute(query).await.is_err());
+}
+
+#[async_std::test]
+pub async fn test_enum_invalid_value() {
+    #[derive(GQLEnum, Copy, Clone, Eq, PartialEq)]
+    enum MyEnum {
+        A,
+        B,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self, input: MyEnum) -> MyEnum {
+            input
+        }
+    }
+
+    // Skip the validation, so the value is rejected when it is parsed.
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .validation_mode(ValidationMode::OnlyParse)
+        .finish();
+    let err = schema
+        .execute("{ value(input: C) }")
+        .await
+        .into_result()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Error::Query {
+            pos: Pos {
+                line: 1,
+                column: 16
+            },
+            path: None,
+            err: QueryError::InvalidEnumValue {
+                ty: "MyEnum".to_string(),
+                value: "C".to_string(),
+            },
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        r#"Query error: Failed to parse input value: Enumeration type "MyEnum" does not contain the value "C""#
+    );
+}
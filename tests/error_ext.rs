@@ -0,0 +1,41 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_error_retriable() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> FieldResult<i32> {
+            Err(FieldError::from("Database unavailable")
+                .extend_with(|_| serde_json::json!({ "code": "DB_UNAVAILABLE" }))
+                .retriable())
+        }
+
+        async fn value2(&self) -> FieldResult<i32> {
+            Ok("abc".parse::<i32>().map_err(|err| err.retriable())?)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        serde_json::to_value(&schema.execute("{ value }").await).unwrap(),
+        serde_json::json!({
+            "errors": [{
+                "message": "Database unavailable",
+                "locations": [{ "line": 1, "column": 3 }],
+                "path": ["value"],
+                "extensions": {
+                    "code": "DB_UNAVAILABLE",
+                    "retriable": true,
+                },
+            }]
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(&schema.execute("{ value2 }").await).unwrap()["errors"][0]
+            ["extensions"],
+        serde_json::json!({ "retriable": true })
+    );
+}
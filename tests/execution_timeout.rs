@@ -0,0 +1,147 @@
+use async_graphql::*;
+use std::time::Duration;
+
+struct Item;
+
+#[GQLObject]
+impl Item {
+    async fn fast(&self) -> i32 {
+        1
+    }
+
+    async fn slow(&self) -> Option<i32> {
+        async_std::task::sleep(Duration::from_secs(5)).await;
+        Some(2)
+    }
+
+    async fn slow_non_null(&self) -> i32 {
+        async_std::task::sleep(Duration::from_secs(5)).await;
+        3
+    }
+}
+
+struct Query;
+
+#[GQLObject]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+
+    async fn item(&self) -> Item {
+        Item
+    }
+}
+
+#[async_std::test]
+pub async fn test_execution_timeout() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .execution_timeout(Duration::from_millis(100))
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ value item { fast } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "value": 10, "item": { "fast": 1 } })
+    );
+
+    let resp = schema.execute("{ value item { fast slow } }").await;
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        serde_json::json!({
+            "data": { "value": 10, "item": { "fast": 1, "slow": null } },
+            "errors": [{
+                "message": "Execution timed out after 100ms",
+                "locations": [{ "line": 1, "column": 21 }],
+                "path": ["item", "slow"],
+                "extensions": { "code": "EXECUTION_TIMEOUT" },
+            }],
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_request_timeout() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .execution_timeout(Duration::from_secs(10))
+        .finish();
+
+    let resp = schema
+        .execute(Request::new("{ value item { slow } }").timeout(Duration::from_millis(100)))
+        .await;
+    assert_eq!(
+        resp.data,
+        serde_json::json!({ "value": 10, "item": { "slow": null } })
+    );
+    assert_eq!(
+        resp.error,
+        Some(Error::Query {
+            pos: Pos {
+                line: 1,
+                column: 16
+            },
+            path: Some(serde_json::json!(["item", "slow"])),
+            err: QueryError::FieldError {
+                err: "Execution timed out after 100ms".to_string(),
+                extended_error: Some(serde_json::json!({ "code": "EXECUTION_TIMEOUT" })),
+            },
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_execution_timeout_non_null() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .execution_timeout(Duration::from_millis(100))
+        .finish();
+
+    // A non-null field can't be resolved to `null`, so the timeout fails the query.
+    let resp = schema.execute("{ value item { fast slowNonNull } }").await;
+    assert_eq!(resp.data, serde_json::Value::Null);
+    assert_eq!(
+        resp.error,
+        Some(Error::Query {
+            pos: Pos {
+                line: 1,
+                column: 21
+            },
+            path: Some(serde_json::json!(["item", "slowNonNull"])),
+            err: QueryError::FieldError {
+                err: "Execution timed out after 100ms".to_string(),
+                extended_error: Some(serde_json::json!({ "code": "EXECUTION_TIMEOUT" })),
+            },
+        })
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+pub async fn test_execution_timeout_tokio() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .execution_timeout(Duration::from_millis(100))
+        .finish();
+
+    let resp = schema.execute("{ value item { fast slow } }").await;
+    assert_eq!(
+        resp.data,
+        serde_json::json!({ "value": 10, "item": { "fast": 1, "slow": null } })
+    );
+    assert_eq!(
+        resp.error,
+        Some(Error::Query {
+            pos: Pos {
+                line: 1,
+                column: 21
+            },
+            path: Some(serde_json::json!(["item", "slow"])),
+            err: QueryError::FieldError {
+                err: "Execution timed out after 100ms".to_string(),
+                extended_error: Some(serde_json::json!({ "code": "EXECUTION_TIMEOUT" })),
+            },
+        })
+    );
+}
@@ -87,3 +87,217 @@ pub async fn test_federation() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_find_entity_errors() {
+    struct MyObj {
+        id: i32,
+        name: String,
+    }
+
+    #[GQLObject]
+    impl MyObj {
+        async fn id(&self) -> i32 {
+            self.id
+        }
+
+        async fn name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        #[entity]
+        async fn find_obj_by_id(&self, id: i32) -> FieldResult<MyObj> {
+            if id > 100 {
+                return Err("Not found".into());
+            }
+            Ok(MyObj {
+                id,
+                name: format!("obj{}", id),
+            })
+        }
+
+        #[entity]
+        async fn find_obj_by_name(&self, name: String) -> MyObj {
+            MyObj {
+                id: name.len() as i32,
+                name,
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let query = r#"{
+            _entities(representations: [{__typename: "MyObj", id: 1}, {__typename: "MyObj", name: "abc"}]) {
+                __typename
+                ... on MyObj {
+                    id
+                    name
+                }
+            }
+        }"#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "_entities": [
+                {"__typename": "MyObj", "id": 1, "name": "obj1"},
+                {"__typename": "MyObj", "id": 3, "name": "abc"},
+            ]
+        })
+    );
+
+    // A failed representation is null, and the others are still resolved.
+    let query = r#"{
+            _entities(representations: [{__typename: "MyObj", id: 1}, {__typename: "MyObj", id: 200}]) {
+                __typename
+            }
+        }"#;
+    let response = schema.execute(query).await;
+    assert_eq!(
+        response.data,
+        serde_json::json!({ "_entities": [{ "__typename": "MyObj" }, null] })
+    );
+    assert_eq!(
+        response.error,
+        Some(Error::Query {
+            pos: Pos {
+                line: 2,
+                column: 13
+            },
+            path: Some(serde_json::json!(["_entities", 1])),
+            err: QueryError::FieldError {
+                err: "Not found".to_string(),
+                extended_error: None,
+            },
+        })
+    );
+
+    let query = r#"{
+            _entities(representations: [{__typename: "MyObj", value: 1}, {__typename: "MyObj", id: 1}]) {
+                __typename
+            }
+        }"#;
+    let response = schema.execute(query).await;
+    assert_eq!(
+        response.data,
+        serde_json::json!({ "_entities": [null, { "__typename": "MyObj" }] })
+    );
+    assert_eq!(
+        response.error,
+        Some(Error::Query {
+            pos: Pos {
+                line: 2,
+                column: 13
+            },
+            path: Some(serde_json::json!(["_entities", 0])),
+            err: QueryError::EntityNotFound,
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_federation_sdl_descriptions() {
+    #[derive(GQLSimpleObject)]
+    #[graphql(desc = "A product.\n\nProducts are sold in the store.")]
+    struct Item {
+        #[field(desc = "The \"unique\" code")]
+        code: String,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn item(&self) -> Item {
+            Item {
+                code: "abc".to_string(),
+            }
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_federation()
+        .finish();
+    let data = schema
+        .execute("{ _service { sdl } }")
+        .await
+        .into_result()
+        .unwrap()
+        .data;
+    let sdl = data["_service"]["sdl"].as_str().unwrap();
+    assert!(sdl.contains(concat!(
+        "\"\"\"\n",
+        "A product.\n",
+        "\n",
+        "Products are sold in the store.\n",
+        "\"\"\"\n",
+        "type Item {\n",
+        "\t\"The \\\"unique\\\" code\"\n",
+        "\tcode: String!\n",
+        "}\n",
+    )));
+}
+
+#[async_std::test]
+pub async fn test_sdl_indent() {
+    /// A state of an item.
+    #[derive(GQLEnum, Copy, Clone, Eq, PartialEq)]
+    enum State {
+        #[item(desc = "The item is sold.\n\nIt can't be ordered anymore.")]
+        Sold,
+    }
+
+    #[derive(GQLSimpleObject)]
+    struct Item {
+        #[field(desc = "The state\nof the item")]
+        state: State,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn item(&self) -> Item {
+            Item { state: State::Sold }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let sdl = schema.sdl();
+    assert!(sdl.contains(concat!(
+        "type Item {\n",
+        "\t\"\"\"\n",
+        "\tThe state\n",
+        "\tof the item\n",
+        "\t\"\"\"\n",
+        "\tstate: State!\n",
+        "}\n",
+    )));
+
+    let sdl = schema.sdl_with_options(SdlOptions::default().indent("  "));
+    assert!(sdl.contains(concat!(
+        "type Item {\n",
+        "  \"\"\"\n",
+        "  The state\n",
+        "  of the item\n",
+        "  \"\"\"\n",
+        "  state: State!\n",
+        "}\n",
+    )));
+    assert!(sdl.contains(concat!(
+        "\"A state of an item.\"\n",
+        "enum State {\n",
+        "  \"\"\"\n",
+        "  The item is sold.\n",
+        "\n",
+        "  It can't be ordered anymore.\n",
+        "  \"\"\"\n",
+        "  SOLD\n",
+        "}\n",
+    )));
+}
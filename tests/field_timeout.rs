@@ -0,0 +1,120 @@
+use async_graphql::*;
+use std::time::Duration;
+
+#[async_std::test]
+pub async fn test_field_timeout() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        #[field(timeout = "100ms")]
+        async fn slow(&self) -> Option<i32> {
+            async_std::task::sleep(Duration::from_secs(5)).await;
+            Some(10)
+        }
+
+        #[field(timeout = "100ms")]
+        async fn slow_non_null(&self) -> i32 {
+            async_std::task::sleep(Duration::from_secs(5)).await;
+            10
+        }
+
+        #[field(timeout = "2s")]
+        async fn fast(&self) -> i32 {
+            20
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute("{ fast }").await.into_result().unwrap().data,
+        serde_json::json!({ "fast": 20 })
+    );
+
+    let resp = schema.execute("{ fast slow }").await;
+    assert_eq!(resp.data, serde_json::json!({ "fast": 20, "slow": null }));
+    assert_eq!(
+        resp.error,
+        Some(Error::Query {
+            pos: Pos { line: 1, column: 8 },
+            path: Some(serde_json::json!(["slow"])),
+            err: QueryError::FieldError {
+                err: "Field timed out after 100ms".to_string(),
+                extended_error: None,
+            },
+        })
+    );
+
+    // A non-null field can't be resolved to `null`, so the timeout fails the query.
+    assert_eq!(
+        schema
+            .execute("{ fast slowNonNull }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 8 },
+            path: Some(serde_json::json!(["slowNonNull"])),
+            err: QueryError::FieldError {
+                err: "Field timed out after 100ms".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+pub async fn test_field_timeout_tokio() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        #[field(timeout = "100ms")]
+        async fn slow(&self) -> i32 {
+            tokio::time::delay_for(Duration::from_secs(5)).await;
+            10
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute("{ slow }").await.into_result().unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["slow"])),
+            err: QueryError::FieldError {
+                err: "Field timed out after 100ms".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+}
+
+#[cfg(feature = "async-std")]
+#[async_std::test]
+pub async fn test_field_timeout_async_std() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        #[field(timeout = "100ms")]
+        async fn slow(&self) -> i32 {
+            async_std::task::sleep(Duration::from_secs(5)).await;
+            10
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute("{ slow }").await.into_result().unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["slow"])),
+            err: QueryError::FieldError {
+                err: "Field timed out after 100ms".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+}
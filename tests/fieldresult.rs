@@ -65,3 +65,124 @@ pub async fn test_fieldresult() {
         }
     );
 }
+
+#[async_std::test]
+pub async fn test_first_error_is_reported() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn error1(&self) -> FieldResult<i32> {
+            Err("Error1".into())
+        }
+
+        async fn error2(&self) -> FieldResult<i32> {
+            Err("Error2".into())
+        }
+    }
+
+    struct Mutation;
+
+    #[GQLObject]
+    impl Mutation {
+        async fn error1(&self) -> FieldResult<i32> {
+            Err("Error1".into())
+        }
+
+        async fn count(&self, ctx: &Context<'_>) -> i32 {
+            let mut count = ctx
+                .data_unchecked::<std::sync::Arc<std::sync::Mutex<i32>>>()
+                .lock()
+                .unwrap();
+            *count += 1;
+            *count
+        }
+    }
+
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+    // Execution stops on the first field error, and only that error is returned.
+    assert_eq!(
+        schema
+            .execute("{ error1 error2 }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["error1"])),
+            err: QueryError::FieldError {
+                err: "Error1".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+
+    // Mutation fields after the failing one are not executed.
+    let count = std::sync::Arc::new(std::sync::Mutex::new(0));
+    let response = schema
+        .execute(Request::new("mutation { error1 count }").data(count.clone()))
+        .await;
+    assert!(response.is_err());
+    assert_eq!(*count.lock().unwrap(), 0);
+}
+
+#[async_std::test]
+pub async fn test_anyhow_result() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self, input: String) -> anyhow::Result<i32> {
+            Ok(input.parse()?)
+        }
+
+        async fn opt_value(&self) -> Option<anyhow::Result<i32>> {
+            Some(Err(anyhow::anyhow!("TestError")))
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(r#"{ value(input: "10") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "value": 10 })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ value(input: "abc") }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["value"])),
+            err: QueryError::FieldError {
+                err: "invalid digit found in string".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ optValue }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["optValue"])),
+            err: QueryError::FieldError {
+                err: "TestError".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+}
@@ -82,3 +82,66 @@ pub async fn test_field_object_merge() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_field_merge_order() {
+    #[derive(GQLSimpleObject)]
+    struct MyObject {
+        a: i32,
+        b: i32,
+        c: i32,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value1(&self) -> i32 {
+            1
+        }
+
+        async fn value2(&self) -> i32 {
+            2
+        }
+
+        async fn obj(&self) -> MyObject {
+            MyObject { a: 1, b: 2, c: 3 }
+        }
+
+        async fn objs(&self) -> Vec<MyObject> {
+            vec![MyObject { a: 1, b: 2, c: 3 }, MyObject { a: 4, b: 5, c: 6 }]
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"
+        {
+            z: value1
+            ... { value2 obj { c } }
+            a: value2
+            ... A
+            obj { a }
+            objs { b }
+        }
+
+        fragment A on Query {
+            value1
+            z: value1
+            obj { b c }
+            objs { a }
+        }
+    "#;
+    let data = schema.execute(query).await.into_result().unwrap().data;
+    assert_eq!(
+        serde_json::to_string(&data).unwrap(),
+        serde_json::to_string(&serde_json::json!({
+            "z": 1,
+            "value2": 2,
+            "obj": { "c": 3, "b": 2, "a": 1 },
+            "a": 2,
+            "value1": 1,
+            "objs": [{ "a": 1, "b": 2 }, { "a": 4, "b": 5 }],
+        }))
+        .unwrap()
+    );
+}
@@ -0,0 +1,448 @@
+use async_graphql::*;
+
+trait DisplayName {
+    fn first_name(&self) -> &str;
+
+    fn last_name(&self) -> &str;
+
+    fn display_name(&self) -> String {
+        format!("{} {}", self.first_name(), self.last_name())
+    }
+}
+
+struct DisplayNameFields<'a, T>(&'a T);
+
+#[GQLObject(name = "DisplayNameFields")]
+impl<'a, T: DisplayName + Send + Sync> DisplayNameFields<'a, T> {
+    async fn display_name(&self) -> String {
+        self.0.display_name()
+    }
+}
+
+struct User {
+    id: i32,
+}
+
+impl DisplayName for User {
+    fn first_name(&self) -> &str {
+        "Jane"
+    }
+
+    fn last_name(&self) -> &str {
+        "Doe"
+    }
+}
+
+#[GQLObject]
+impl User {
+    async fn id(&self) -> i32 {
+        self.id
+    }
+
+    #[field(flatten)]
+    async fn display_name_fields(&self) -> DisplayNameFields<'_, Self> {
+        DisplayNameFields(self)
+    }
+}
+
+struct Group;
+
+impl DisplayName for Group {
+    fn first_name(&self) -> &str {
+        "Admin"
+    }
+
+    fn last_name(&self) -> &str {
+        "Group"
+    }
+
+    fn display_name(&self) -> String {
+        self.first_name().to_string()
+    }
+}
+
+#[GQLObject]
+impl Group {
+    async fn members(&self) -> Vec<User> {
+        vec![User { id: 1 }, User { id: 2 }]
+    }
+
+    #[field(flatten)]
+    async fn display_name_fields(&self) -> DisplayNameFields<'_, Self> {
+        DisplayNameFields(self)
+    }
+}
+
+struct Query;
+
+#[GQLObject]
+impl Query {
+    async fn user(&self) -> User {
+        User { id: 10 }
+    }
+
+    async fn group(&self) -> Group {
+        Group
+    }
+}
+
+#[async_std::test]
+pub async fn test_flatten() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = "{ user { id displayName } group { displayName members { id displayName } } }";
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "user": {
+                "id": 10,
+                "displayName": "Jane Doe",
+            },
+            "group": {
+                "displayName": "Admin",
+                "members": [
+                    { "id": 1, "displayName": "Jane Doe" },
+                    { "id": 2, "displayName": "Jane Doe" },
+                ],
+            },
+        })
+    );
+
+    assert!(matches!(
+        schema
+            .execute("{ user { displayNameFields } }")
+            .await
+            .into_result(),
+        Err(Error::Rule { .. })
+    ));
+}
+
+#[async_std::test]
+pub async fn test_flatten_introspection() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        user: __type(name: "User") { fields { name } }
+        group: __type(name: "Group") { fields { name } }
+        wrapper: __type(name: "DisplayNameFields") { name }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "user": {
+                "fields": [{ "name": "id" }, { "name": "displayName" }],
+            },
+            "group": {
+                "fields": [{ "name": "members" }, { "name": "displayName" }],
+            },
+            "wrapper": null,
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_flatten_arguments() {
+    #[derive(GQLInputObject)]
+    struct Pagination {
+        offset: i32,
+        #[field(default = 10)]
+        limit: i32,
+        reverse: Option<bool>,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn items(&self, prefix: String, #[arg(flatten)] page: Pagination) -> String {
+            format!(
+                "{} {} {} {:?}",
+                prefix, page.offset, page.limit, page.reverse
+            )
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = Request::new(
+        r#"query($limit: Int!) {
+            a: items(prefix: "a", offset: 1, limit: $limit, reverse: true)
+            b: items(prefix: "b", offset: 2)
+        }"#,
+    )
+    .variables(Variables::from_json(serde_json::json!({ "limit": 5 })));
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "a": "a 1 5 Some(true)",
+            "b": "b 2 10 None",
+        })
+    );
+
+    let query = r#"{
+        __type(name: "Query") { fields { args { name } } }
+        pagination: __type(name: "Pagination") { name }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "__type": {
+                "fields": [{
+                    "args": [
+                        { "name": "prefix" },
+                        { "name": "offset" },
+                        { "name": "limit" },
+                        { "name": "reverse" },
+                    ],
+                }],
+            },
+            "pagination": null,
+        })
+    );
+
+    assert!(schema.execute("{ items(prefix: \"a\") }").await.is_err());
+}
+
+#[async_std::test]
+pub async fn test_flatten_arguments_and_input_object() {
+    #[derive(GQLInputObject)]
+    struct Pagination {
+        offset: i32,
+        limit: i32,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn page(&self, page: Pagination) -> i32 {
+            page.offset + page.limit
+        }
+
+        async fn items(&self, #[arg(flatten)] page: Pagination) -> i32 {
+            page.offset * page.limit
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ page(page: { offset: 2, limit: 3 }) items(offset: 2, limit: 3) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "page": 5, "items": 6 })
+    );
+
+    // The input object is still used by the `page` argument.
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Pagination") { inputFields { name } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": { "inputFields": [{ "name": "offset" }, { "name": "limit" }] },
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = r#"Argument "limit" of field "items" is defined more than once."#)]
+pub fn test_flatten_arguments_clash() {
+    #[derive(GQLInputObject)]
+    struct Pagination {
+        offset: i32,
+        limit: i32,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn items(&self, #[arg(flatten)] page: Pagination, limit: i32) -> i32 {
+            page.offset * limit
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[async_std::test]
+pub async fn test_flatten_guard_and_cache_control() {
+    struct Admin;
+
+    struct AdminGuard;
+
+    #[async_trait::async_trait]
+    impl guard::Guard for AdminGuard {
+        async fn check(&self, ctx: &Context<'_>) -> FieldResult<()> {
+            if ctx.data_opt::<Admin>().is_some() {
+                Ok(())
+            } else {
+                Err("Forbidden".into())
+            }
+        }
+    }
+
+    struct SecretFields;
+
+    #[GQLObject]
+    impl SecretFields {
+        async fn secret(&self) -> &str {
+            "secret"
+        }
+    }
+
+    struct PublicFields;
+
+    #[GQLObject]
+    impl PublicFields {
+        async fn name(&self) -> &str {
+            "public"
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        #[field(flatten, guard("AdminGuard"))]
+        async fn secret_fields(&self) -> SecretFields {
+            SecretFields
+        }
+
+        #[field(flatten, cache_control(max_age = 30))]
+        async fn public_fields(&self) -> PublicFields {
+            PublicFields
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    // The guard only applies to the fields of its own flattened object.
+    let response = schema.execute("{ name }").await;
+    assert_eq!(response.cache_control.max_age, 30);
+    assert_eq!(
+        response.into_result().unwrap().data,
+        serde_json::json!({ "name": "public" })
+    );
+
+    match schema
+        .execute("{ secret }")
+        .await
+        .into_result()
+        .unwrap_err()
+    {
+        Error::Query {
+            err: QueryError::FieldError { err, .. },
+            ..
+        } => assert_eq!(err, "Forbidden"),
+        err => panic!("unexpected error: {}", err),
+    }
+    assert_eq!(
+        schema
+            .execute(Request::new("{ secret }").data(Admin))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "secret": "secret" })
+    );
+}
+
+#[test]
+#[should_panic(expected = r#"Field "displayName" of object "Query" is defined more than once."#)]
+pub fn test_flatten_fields_clash() {
+    struct Person;
+
+    impl DisplayName for Person {
+        fn first_name(&self) -> &str {
+            "Jane"
+        }
+
+        fn last_name(&self) -> &str {
+            "Doe"
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        #[field(flatten)]
+        async fn display_name_fields(&self) -> DisplayNameFields<'_, Person> {
+            DisplayNameFields(&Person)
+        }
+
+        async fn display_name(&self) -> String {
+            "Query".to_string()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[async_std::test]
+pub async fn test_flatten_referenced_object() {
+    #[derive(GQLSimpleObject)]
+    struct Address {
+        city: String,
+    }
+
+    struct Person;
+
+    #[GQLObject]
+    impl Person {
+        async fn name(&self) -> &str {
+            "Jane"
+        }
+
+        #[field(flatten)]
+        async fn address(&self) -> Address {
+            Address {
+                city: "Paris".to_string(),
+            }
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        // Registered before the object is flattened into `Person`.
+        async fn office(&self) -> Address {
+            Address {
+                city: "London".to_string(),
+            }
+        }
+
+        async fn person(&self) -> Person {
+            Person
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ office { city } person { name city } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "office": { "city": "London" },
+            "person": { "name": "Jane", "city": "Paris" },
+        })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Address") { fields { name } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": { "fields": [{ "name": "city" }] },
+        })
+    );
+}
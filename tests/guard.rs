@@ -2,7 +2,7 @@ use async_graphql::guard::Guard;
 use async_graphql::*;
 use futures::{Stream, StreamExt};
 
-#[derive(Eq, PartialEq, Copy, Clone)]
+#[derive(Eq, PartialEq, Copy, Clone, Hash)]
 enum Role {
     Admin,
     Guest,
@@ -253,6 +253,81 @@ pub async fn test_multiple_guards() {
     );
 }
 
+#[async_std::test]
+pub async fn test_stacked_guards() {
+    type Log = std::sync::Arc<std::sync::Mutex<Vec<String>>>;
+
+    struct LogGuard {
+        name: String,
+        pass: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl Guard for LogGuard {
+        async fn check(&self, ctx: &Context<'_>) -> FieldResult<()> {
+            ctx.data_unchecked::<Log>()
+                .lock()
+                .unwrap()
+                .push(self.name.clone());
+            if self.pass {
+                Ok(())
+            } else {
+                Err(format!("{} failed", self.name).into())
+            }
+        }
+    }
+
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {
+        #[field(
+            guard(LogGuard(name = r#""a""#, pass = "true")),
+            guard(LogGuard(name = r#""b""#, pass = "false")),
+            guard(LogGuard(name = r#""c""#, pass = "false"))
+        )]
+        async fn value1(&self) -> i32 {
+            1
+        }
+
+        #[field(guard(LogGuard(name = r#""a""#, pass = "true")))]
+        #[field(guard(LogGuard(name = r#""b""#, pass = "true")))]
+        async fn value2(&self) -> i32 {
+            2
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    let log = Log::default();
+    assert_eq!(
+        schema
+            .execute(Request::new("{ value1 }").data(log.clone()))
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["value1"])),
+            err: QueryError::FieldError {
+                err: "b failed".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+    assert_eq!(*log.lock().unwrap(), vec!["a", "b"]);
+
+    let log = Log::default();
+    assert_eq!(
+        schema
+            .execute(Request::new("{ value2 }").data(log.clone()))
+            .await
+            .data,
+        serde_json::json!({"value2": 2})
+    );
+    assert_eq!(*log.lock().unwrap(), vec!["a", "b"]);
+}
+
 #[async_std::test]
 pub async fn test_guard_forward_arguments() {
     struct UserGuard {
@@ -308,3 +383,199 @@ pub async fn test_guard_forward_arguments() {
         }
     );
 }
+
+#[async_std::test]
+pub async fn test_cached_guard() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct CheckCount(Arc<AtomicUsize>);
+
+    #[derive(Clone, Eq, PartialEq, Hash)]
+    struct CountedGuard {
+        role: Role,
+    }
+
+    #[async_trait::async_trait]
+    impl Guard for CountedGuard {
+        async fn check(&self, ctx: &Context<'_>) -> FieldResult<()> {
+            ctx.data_unchecked::<CheckCount>()
+                .0
+                .fetch_add(1, Ordering::SeqCst);
+            if ctx.data_opt::<Role>() == Some(&self.role) {
+                Ok(())
+            } else {
+                Err("Forbidden".into())
+            }
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        #[field(guard(cached(CountedGuard(role = "Role::Admin"))))]
+        async fn value1(&self) -> i32 {
+            1
+        }
+
+        #[field(guard(cached(CountedGuard(role = "Role::Admin"))))]
+        async fn value2(&self) -> i32 {
+            2
+        }
+
+        #[field(guard(cached(CountedGuard(role = "Role::Guest"))))]
+        async fn value3(&self) -> i32 {
+            3
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let query = "{ value1 value2 a: value1 }";
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(query)
+                    .data(Role::Admin)
+                    .data(CheckCount(count.clone()))
+            )
+            .await
+            .data,
+        serde_json::json!({
+            "value1": 1,
+            "value2": 2,
+            "a": 1,
+        })
+    );
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let query = "{ value1 value2 value3 }";
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(query)
+                    .data(Role::Admin)
+                    .data(CheckCount(count.clone()))
+            )
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos {
+                line: 1,
+                column: 17
+            },
+            path: Some(serde_json::json!(["value3"])),
+            err: QueryError::FieldError {
+                err: "Forbidden".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[async_std::test]
+pub async fn test_guard_error_extensions() {
+    struct ForbiddenGuard;
+
+    #[async_trait::async_trait]
+    impl Guard for ForbiddenGuard {
+        async fn check(&self, _ctx: &Context<'_>) -> FieldResult<()> {
+            Err(FieldError::from("Forbidden")
+                .extend_with(|_| serde_json::json!({ "code": "FORBIDDEN" })))
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        #[field(guard(ForbiddenGuard()))]
+        async fn value(&self) -> i32 {
+            1
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let resp = schema.execute("{ value }").await;
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap()["errors"][0]["extensions"]["code"],
+        serde_json::json!("FORBIDDEN")
+    );
+    assert_eq!(
+        resp.into_result().unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["value"])),
+            err: QueryError::FieldError {
+                err: "Forbidden".to_string(),
+                extended_error: Some(serde_json::json!({ "code": "FORBIDDEN" })),
+            },
+        }
+    );
+}
+
+#[async_std::test]
+pub async fn test_data_present_guard() {
+    use async_graphql::guard::DataPresent;
+
+    struct CurrentUser(String);
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        #[field(guard("DataPresent::<CurrentUser>::new()"))]
+        async fn name(&self, ctx: &Context<'_>) -> String {
+            ctx.data_unchecked::<CurrentUser>().0.clone()
+        }
+
+        #[field(guard("DataPresent::<CurrentUser>::with_message(\"Unauthorized\")"))]
+        async fn value(&self) -> i32 {
+            1
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ name value }").data(CurrentUser("sunli".to_string())))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "name": "sunli",
+            "value": 1,
+        })
+    );
+
+    assert_eq!(
+        schema.execute("{ name }").await.into_result().unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["name"])),
+            err: QueryError::FieldError {
+                err: "Forbidden".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+
+    assert_eq!(
+        schema.execute("{ value }").await.into_result().unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["value"])),
+            err: QueryError::FieldError {
+                err: "Unauthorized".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+}
@@ -0,0 +1,136 @@
+use async_graphql::http::{parse_query_string, HttpHeaders};
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_parse_query_string() {
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {
+        async fn add(&self, a: i32, b: i32) -> i32 {
+            a + b
+        }
+    }
+
+    let request = parse_query_string(
+        "query=query%20Add%28%24a%3A%20Int%21%29%20%7B%20add%28a%3A%20%24a%2C%20b%3A%202%29%20%7D\
+         &operationName=Add&variables=%7B%22a%22%3A%2010%7D",
+    )
+    .unwrap();
+    assert_eq!(request.query, "query Add($a: Int!) { add(a: $a, b: 2) }");
+    assert_eq!(request.operation_name.as_deref(), Some("Add"));
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        serde_json::json!({ "add": 12 })
+    );
+
+    let request = parse_query_string("query=%7B%20add%28a%3A%201%2C%20b%3A%202%29%20%7D").unwrap();
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        serde_json::json!({ "add": 3 })
+    );
+
+    assert!(matches!(
+        parse_query_string("query=%7B%20add%20%7D&variables=%7Ba"),
+        Err(ParseRequestError::InvalidRequest(_))
+    ));
+}
+
+#[async_std::test]
+pub async fn test_parse_query_string_rejects_mutation() {
+    assert!(matches!(
+        parse_query_string("query=mutation%20%7B%20action%20%7D"),
+        Err(ParseRequestError::MutationInGetRequest)
+    ));
+
+    // The operation name selects which operation of the document would be executed.
+    let query_string = "query=query%20A%20%7B%20value%20%7D%20mutation%20B%20%7B%20action%20%7D";
+    assert!(parse_query_string(&format!("{}&operationName=A", query_string)).is_ok());
+    assert!(matches!(
+        parse_query_string(&format!("{}&operationName=B", query_string)),
+        Err(ParseRequestError::MutationInGetRequest)
+    ));
+}
+
+#[async_std::test]
+pub async fn test_parse_query_string_nested_values() {
+    let query = format!(
+        "{{ value(a: {}1{}) }}",
+        "[".repeat(100_000),
+        "]".repeat(100_000)
+    );
+    let query_string = serde_urlencoded::to_string(&[("query", query)]).unwrap();
+    assert!(matches!(
+        parse_query_string(&query_string),
+        Err(ParseRequestError::QueryLimitsExceeded(_))
+    ));
+
+    let query = format!("{{ value(a: {}1{}) }}", "[".repeat(64), "]".repeat(64));
+    let query_string = serde_urlencoded::to_string(&[("query", query)]).unwrap();
+    assert!(parse_query_string(&query_string).is_ok());
+}
+
+#[async_std::test]
+pub async fn test_http_headers() {
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {
+        async fn user_agent(&self, ctx: &Context<'_>) -> FieldResult<Option<String>> {
+            Ok(ctx
+                .data::<HttpHeaders>()?
+                .get("User-Agent")
+                .map(ToString::to_string))
+        }
+
+        async fn accept(&self, ctx: &Context<'_>) -> FieldResult<Vec<String>> {
+            Ok(ctx
+                .data::<HttpHeaders>()?
+                .get_all("accept")
+                .map(ToString::to_string)
+                .collect())
+        }
+    }
+
+    let mut headers = HttpHeaders::new();
+    headers.insert("user-agent", "test");
+    headers.insert("Accept", "application/json");
+    headers.insert("ACCEPT", "text/html");
+    assert_eq!(headers.get("USER-AGENT"), Some("test"));
+    assert!(!headers.contains("authorization"));
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(Request::new("{ userAgent accept }").data(headers))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "userAgent": "test",
+            "accept": ["application/json", "text/html"],
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ userAgent }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["userAgent"])),
+            err: QueryError::FieldError {
+                err: format!(
+                    "Data `{}` does not exist.",
+                    std::any::type_name::<HttpHeaders>()
+                ),
+                extended_error: None,
+            },
+        }
+    );
+}
@@ -295,3 +295,172 @@ pub async fn test_inputobject_flatten_multiple() {
         .unwrap()
     );
 }
+
+#[async_std::test]
+pub async fn test_input_object_one_of() {
+    #[derive(GQLInputObject, Debug, Eq, PartialEq)]
+    #[graphql(one_of)]
+    struct UserBy {
+        id: Option<i32>,
+        email: Option<String>,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn user(&self, by: UserBy) -> String {
+            match by {
+                UserBy { id: Some(id), .. } => format!("id:{}", id),
+                UserBy {
+                    email: Some(email), ..
+                } => format!("email:{}", email),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_federation()
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ a: user(by: { id: 1 }) b: user(by: { email: \"a@b.c\" }) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "a": "id:1", "b": "email:a@b.c" })
+    );
+
+    // Zero or several fields, or a null field, are rejected when the query is validated.
+    for query in &[
+        "{ user(by: {}) }",
+        "{ user(by: { id: 1, email: \"a@b.c\" }) }",
+        "{ user(by: { email: \"a@b.c\", id: null }) }",
+        "{ user(by: { id: null }) }",
+    ] {
+        assert!(matches!(
+            schema.execute(*query).await.into_result(),
+            Err(Error::Rule { .. })
+        ));
+    }
+
+    // Variables are checked when the input object is parsed.
+    let query = "query($by: UserBy!) { user(by: $by) }";
+    for by in &[
+        serde_json::json!({}),
+        serde_json::json!({ "id": 1, "email": "a@b.c" }),
+        serde_json::json!({ "id": 1, "email": null }),
+        serde_json::json!({ "id": null }),
+    ] {
+        let request =
+            Request::new(query).variables(Variables::from_json(serde_json::json!({ "by": by })));
+        assert!(schema.execute(request).await.is_err());
+    }
+    let request = Request::new(query).variables(Variables::from_json(
+        serde_json::json!({ "by": { "id": 1 } }),
+    ));
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        serde_json::json!({ "user": "id:1" })
+    );
+
+    assert!(UserBy::parse(Some(Value::from_json(serde_json::json!({})).unwrap())).is_err());
+    assert!(UserBy::parse(Some(
+        Value::from_json(serde_json::json!({ "id": null })).unwrap()
+    ))
+    .is_err());
+    assert_eq!(
+        UserBy {
+            id: None,
+            email: Some("a@b.c".to_string()),
+        }
+        .to_value(),
+        Value::from_json(serde_json::json!({ "email": "a@b.c" })).unwrap()
+    );
+
+    let data = schema
+        .execute(r#"{ __type(name: "UserBy") { isOneOf } _service { sdl } }"#)
+        .await
+        .into_result()
+        .unwrap()
+        .data;
+    assert_eq!(data["__type"], serde_json::json!({ "isOneOf": true }));
+    assert!(data["_service"]["sdl"]
+        .as_str()
+        .unwrap()
+        .contains("input UserBy @oneOf {"));
+}
+
+#[async_std::test]
+pub async fn test_input_object_unknown_fields() {
+    #[derive(GQLInputObject)]
+    struct Strict {
+        a: i32,
+    }
+
+    #[derive(GQLInputObject)]
+    #[graphql(deny_unknown_fields = false)]
+    struct Lenient {
+        a: i32,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn strict(&self, input: Strict) -> i32 {
+            input.a
+        }
+
+        async fn lenient(&self, input: Lenient) -> i32 {
+            input.a
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute("{ strict(input: { a: 1, b: 2 }) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Rule {
+            errors: vec![RuleError {
+                locations: vec![Pos {
+                    line: 1,
+                    column: 10
+                }],
+                message:
+                    "Invalid value for argument \"input\", unknown field \"b\" of type \"Strict\""
+                        .to_string(),
+                extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+                kind: Some(RuleErrorKind::InvalidArgumentValue {
+                    arg_name: "input".to_string(),
+                }),
+            }]
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ lenient(input: { a: 1, b: 2 }) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "lenient": 1 })
+    );
+
+    // Unknown fields in variables are ignored too.
+    let request = Request::new("query($input: Lenient!) { lenient(input: $input) }").variables(
+        Variables::from_json(serde_json::json!({ "input": { "a": 3, "b": 4 } })),
+    );
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        serde_json::json!({ "lenient": 3 })
+    );
+}
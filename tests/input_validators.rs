@@ -1,9 +1,12 @@
+use async_graphql::extensions::Extension;
 use async_graphql::validators::{
-    Email, IntEqual, IntGreaterThan, IntLessThan, IntNonZero, IntRange, ListMaxLength,
-    ListMinLength, StringMaxLength, StringMinLength, MAC,
+    Email, FloatGreaterThan, FloatLessThan, FloatRange, InputValueValidator, IntEqual,
+    IntGreaterThan, IntLessThan, IntNonZero, IntRange, ListMaxLength, ListMinLength, MultipleOf,
+    StringMaxLength, StringMinLength, MAC,
 };
 use async_graphql::*;
 use async_graphql_parser::types::Name;
+use std::sync::{Arc, Mutex};
 
 #[async_std::test]
 pub async fn test_input_validator_string_min_length() {
@@ -71,7 +74,13 @@ pub async fn test_input_validator_string_min_length() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "id".to_string(),
+                        }),
                     })
                 }
             );
@@ -88,7 +97,13 @@ pub async fn test_input_validator_string_min_length() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -181,7 +196,13 @@ pub async fn test_input_validator_string_max_length() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "id".to_string(),
+                        }),
                     })
                 }
             );
@@ -198,7 +219,13 @@ pub async fn test_input_validator_string_max_length() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -318,7 +345,13 @@ pub async fn test_input_validator_string_email() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "id".to_string(),
+                        }),
                     })
                 }
             );
@@ -336,7 +369,13 @@ pub async fn test_input_validator_string_email() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -466,7 +505,11 @@ pub async fn test_input_validator_string_mac() {
                         line: 1,
                         column: 17
                     }),
-                    message: field_error_msg.clone()
+                    message: field_error_msg.clone(),
+                    extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+                    kind: Some(RuleErrorKind::InvalidArgumentValue {
+                        arg_name: "mac".to_string(),
+                    }),
                 })
             }
         );
@@ -484,7 +527,11 @@ pub async fn test_input_validator_string_mac() {
                         line: 1,
                         column: 14
                     }),
-                    message: object_error_msg.clone()
+                    message: object_error_msg.clone(),
+                    extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+                    kind: Some(RuleErrorKind::InvalidArgumentValue {
+                        arg_name: "input".to_string(),
+                    }),
                 })
             }
         );
@@ -501,7 +548,11 @@ pub async fn test_input_validator_string_mac() {
                         line: 1,
                         column: 17
                     }),
-                    message: field_error_msg
+                    message: field_error_msg,
+                    extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+                    kind: Some(RuleErrorKind::InvalidArgumentValue {
+                        arg_name: "mac".to_string(),
+                    }),
                 })
             }
         );
@@ -519,7 +570,11 @@ pub async fn test_input_validator_string_mac() {
                         line: 1,
                         column: 14
                     }),
-                    message: object_error_msg
+                    message: object_error_msg,
+                    extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+                    kind: Some(RuleErrorKind::InvalidArgumentValue {
+                        arg_name: "input".to_string(),
+                    }),
                 })
             }
         );
@@ -576,7 +631,13 @@ pub async fn test_input_validator_string_mac() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "mac".to_string(),
+                        }),
                     })
                 }
             );
@@ -594,7 +655,13 @@ pub async fn test_input_validator_string_mac() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -635,7 +702,13 @@ pub async fn test_input_validator_string_mac() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "mac".to_string(),
+                        }),
                     })
                 }
             );
@@ -653,7 +726,13 @@ pub async fn test_input_validator_string_mac() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -713,7 +792,13 @@ pub async fn test_input_validator_int_range() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "id".to_string(),
+                        }),
                     })
                 }
             );
@@ -730,7 +815,13 @@ pub async fn test_input_validator_int_range() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -818,7 +909,13 @@ pub async fn test_input_validator_int_less_than() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "id".to_string(),
+                        }),
                     })
                 }
             );
@@ -835,7 +932,13 @@ pub async fn test_input_validator_int_less_than() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -925,7 +1028,13 @@ pub async fn test_input_validator_int_greater_than() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "id".to_string(),
+                        }),
                     })
                 }
             );
@@ -942,7 +1051,13 @@ pub async fn test_input_validator_int_greater_than() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -1025,7 +1140,13 @@ pub async fn test_input_validator_int_nonzero() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "id".to_string(),
+                        }),
                     })
                 }
             );
@@ -1042,7 +1163,13 @@ pub async fn test_input_validator_int_nonzero() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -1126,7 +1253,13 @@ pub async fn test_input_validator_int_equal() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "id".to_string(),
+                        }),
                     })
                 }
             );
@@ -1143,7 +1276,13 @@ pub async fn test_input_validator_int_equal() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -1242,7 +1381,13 @@ pub async fn test_input_validator_list_max_length() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "id".to_string(),
+                        }),
                     })
                 }
             );
@@ -1259,7 +1404,13 @@ pub async fn test_input_validator_list_max_length() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -1358,7 +1509,13 @@ pub async fn test_input_validator_list_min_length() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "id".to_string(),
+                        }),
                     })
                 }
             );
@@ -1375,7 +1532,13 @@ pub async fn test_input_validator_list_min_length() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -1467,9 +1630,11 @@ pub async fn test_input_validator_operator_or() {
             );
 
             let field_error_msg =
-                "Invalid value for argument \"id\", invalid MAC format".to_owned();
+                "Invalid value for argument \"id\", invalid email format or invalid MAC format"
+                    .to_owned();
             let object_error_msg =
-                "Invalid value for argument \"input.id\", invalid MAC format".to_owned();
+                "Invalid value for argument \"input.id\", invalid email format or invalid MAC format"
+                    .to_owned();
             assert_eq!(
                 schema
                     .execute(&field_query)
@@ -1482,7 +1647,13 @@ pub async fn test_input_validator_operator_or() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "id".to_string(),
+                        }),
                     })
                 }
             );
@@ -1499,7 +1670,13 @@ pub async fn test_input_validator_operator_or() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -1599,7 +1776,13 @@ pub async fn test_input_validator_operator_and() {
                             line: 1,
                             column: 17
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "email".to_string(),
+                        }),
                     })
                 }
             );
@@ -1616,7 +1799,13 @@ pub async fn test_input_validator_operator_and() {
                             line: 1,
                             column: 14
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -1649,6 +1838,286 @@ pub async fn test_input_validator_operator_and() {
     }
 }
 
+#[async_std::test]
+pub async fn test_input_validator_operator_not() {
+    struct QueryRoot;
+
+    #[derive(GQLInputObject)]
+    struct InputNotValidator {
+        #[field(validator(not(IntRange(min = "100", max = "200"))))]
+        pub value: i32,
+    }
+
+    #[GQLObject]
+    impl QueryRoot {
+        async fn field_parameter(
+            &self,
+            #[arg(validator(not(IntRange(min = "100", max = "200"))))] _value: i32,
+        ) -> bool {
+            true
+        }
+
+        async fn input_object(&self, _input: InputNotValidator) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let test_cases = [
+        (-1, false),
+        (99, false),
+        (100, true),
+        (150, true),
+        (201, false),
+    ];
+
+    for (case, should_fail) in &test_cases {
+        let field_query = format!("{{fieldParameter(value: {})}}", case);
+        let object_query = format!("{{inputObject(input: {{value: {}}})}}", case);
+        if *should_fail {
+            let should_fail_msg =
+                format!("NOT operator case {} should have failed, but did not", case);
+
+            assert_eq!(
+                schema
+                    .execute(&field_query)
+                    .await
+                    .into_result()
+                    .expect_err(&should_fail_msg[..]),
+                Error::Rule {
+                    errors: vec!(RuleError {
+                        locations: vec!(Pos {
+                            line: 1,
+                            column: 17
+                        }),
+                        message: "Invalid value for argument \"value\", the value must not pass the validation".to_owned(),
+                        extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+kind: Some(RuleErrorKind::InvalidArgumentValue {
+ arg_name: "value".to_string(),
+ }),
+})
+                }
+            );
+
+            assert_eq!(
+                schema
+                    .execute(&object_query)
+                    .await
+                    .into_result()
+                    .expect_err(&should_fail_msg[..]),
+                Error::Rule {
+                    errors: vec!(RuleError {
+                        locations: vec!(Pos {
+                            line: 1,
+                            column: 14
+                        }),
+                        message: "Invalid value for argument \"input.value\", the value must not pass the validation".to_owned(),
+                        extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+kind: Some(RuleErrorKind::InvalidArgumentValue {
+ arg_name: "input".to_string(),
+ }),
+})
+                }
+            );
+        } else {
+            let error_msg = format!("Schema returned error with test case = {}", case);
+            assert_eq!(
+                schema
+                    .execute(&field_query)
+                    .await
+                    .into_result()
+                    .expect(&error_msg[..])
+                    .data,
+                serde_json::json!({"fieldParameter": true}),
+                "Failed to validate {} with NOT operator",
+                case
+            );
+
+            assert_eq!(
+                schema
+                    .execute(&object_query)
+                    .await
+                    .into_result()
+                    .expect(&error_msg[..])
+                    .data,
+                serde_json::json!({"inputObject": true}),
+                "Failed to validate {} with NOT operator",
+                case
+            );
+        }
+    }
+}
+
+#[async_std::test]
+pub async fn test_input_validator_operator_list() {
+    struct QueryRoot;
+
+    #[derive(GQLInputObject)]
+    struct InputListValidator {
+        #[field(validator(and(ListMinLength(length = "1"), list(Email))))]
+        pub emails: Vec<String>,
+    }
+
+    #[GQLObject]
+    impl QueryRoot {
+        async fn field_parameter(
+            &self,
+            #[arg(validator(and(ListMinLength(length = "1"), list(Email))))] _emails: Vec<String>,
+        ) -> bool {
+            true
+        }
+
+        async fn input_object(&self, _input: InputListValidator) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let test_cases: [(&[&str], Option<&str>); 4] = [
+        (
+            &[],
+            Some("the value length is 0, must be greater than or equal to 1"),
+        ),
+        (&["email@example.com"], None),
+        (&["email@example.com", "email@subdomain.example.com"], None),
+        (
+            &["email@example.com", "plainaddress"],
+            Some("the element at index 1 is invalid, invalid email format"),
+        ),
+    ];
+
+    for (case, error) in &test_cases {
+        let field_query = format!("{{fieldParameter(emails: {:?})}}", case);
+        let object_query = format!("{{inputObject(input: {{emails: {:?}}})}}", case);
+        if let Some(error) = error {
+            let should_fail_msg = format!(
+                "LIST operator case {:?} should have failed, but did not",
+                case
+            );
+
+            assert_eq!(
+                schema
+                    .execute(&field_query)
+                    .await
+                    .into_result()
+                    .expect_err(&should_fail_msg[..]),
+                Error::Rule {
+                    errors: vec!(RuleError {
+                        locations: vec!(Pos {
+                            line: 1,
+                            column: 17
+                        }),
+                        message: format!("Invalid value for argument \"emails\", {}", error),
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "emails".to_string(),
+                        }),
+                    })
+                }
+            );
+
+            assert_eq!(
+                schema
+                    .execute(&object_query)
+                    .await
+                    .into_result()
+                    .expect_err(&should_fail_msg[..]),
+                Error::Rule {
+                    errors: vec!(RuleError {
+                        locations: vec!(Pos {
+                            line: 1,
+                            column: 14
+                        }),
+                        message: format!("Invalid value for argument \"input.emails\", {}", error),
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
+                    })
+                }
+            );
+        } else {
+            let error_msg = format!("Schema returned error with test case = {:?}", case);
+            assert_eq!(
+                schema
+                    .execute(&field_query)
+                    .await
+                    .into_result()
+                    .expect(&error_msg[..])
+                    .data,
+                serde_json::json!({"fieldParameter": true}),
+                "Failed to validate {:?} with LIST operator",
+                case
+            );
+
+            assert_eq!(
+                schema
+                    .execute(&object_query)
+                    .await
+                    .into_result()
+                    .expect(&error_msg[..])
+                    .data,
+                serde_json::json!({"inputObject": true}),
+                "Failed to validate {:?} with LIST operator",
+                case
+            );
+        }
+    }
+}
+
+#[async_std::test]
+pub async fn test_input_validator_operator_list_coerced() {
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {
+        async fn field_parameter(
+            &self,
+            #[arg(validator(list(Email)))] emails: Vec<String>,
+        ) -> usize {
+            emails.len()
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    // A single value is coerced to a list, and validated as its element.
+    assert_eq!(
+        schema
+            .execute(r#"{fieldParameter(emails: "email@example.com")}"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({"fieldParameter": 1})
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{fieldParameter(emails: "plainaddress")}"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Rule {
+            errors: vec!(RuleError {
+                locations: vec!(Pos {
+                    line: 1,
+                    column: 17
+                }),
+                message: "Invalid value for argument \"emails\", the element at index 0 is invalid, invalid email format".to_string(),
+                extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+kind: Some(RuleErrorKind::InvalidArgumentValue {
+ arg_name: "emails".to_string(),
+ }),
+})
+        }
+    );
+}
+
 #[async_std::test]
 pub async fn test_input_validator_variable() {
     struct QueryRoot;
@@ -1722,7 +2191,13 @@ pub async fn test_input_validator_variable() {
                             line: 1,
                             column: 37
                         }),
-                        message: field_error_msg
+                        message: field_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "id".to_string(),
+                        }),
                     })
                 }
             );
@@ -1739,7 +2214,13 @@ pub async fn test_input_validator_variable() {
                             line: 1,
                             column: 34
                         }),
-                        message: object_error_msg
+                        message: object_error_msg,
+                        extensions: Some(
+                            serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })
+                        ),
+                        kind: Some(RuleErrorKind::InvalidArgumentValue {
+                            arg_name: "input".to_string(),
+                        }),
                     })
                 }
             );
@@ -1771,3 +2252,312 @@ pub async fn test_input_validator_variable() {
         }
     }
 }
+
+#[async_std::test]
+pub async fn test_input_validator_float_range() {
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {
+        async fn range(
+            &self,
+            #[arg(validator(FloatRange(min = "-1.5", max = "2.5")))] _value: f64,
+        ) -> bool {
+            true
+        }
+
+        async fn less_than(
+            &self,
+            #[arg(validator(FloatLessThan(value = "2.5")))] _value: f64,
+        ) -> bool {
+            true
+        }
+
+        async fn greater_than(
+            &self,
+            #[arg(validator(FloatGreaterThan(value = "-1.5")))] _value: f64,
+        ) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let is_valid = |query: String| {
+        let schema = &schema;
+        async move { schema.execute(&query).await.into_result().is_ok() }
+    };
+
+    // The range includes its boundaries.
+    for (value, valid) in &[
+        ("-1.6", false),
+        ("-1.5", true),
+        ("0", true),
+        ("2.5", true),
+        ("2.6", false),
+    ] {
+        assert_eq!(
+            is_valid(format!("{{ range(value: {}) }}", value)).await,
+            *valid,
+            "FloatRange case {}",
+            value
+        );
+    }
+
+    // Less than and greater than exclude their boundaries.
+    for (value, valid) in &[("2.4", true), ("2.5", false), ("3", false)] {
+        assert_eq!(
+            is_valid(format!("{{ lessThan(value: {}) }}", value)).await,
+            *valid,
+            "FloatLessThan case {}",
+            value
+        );
+    }
+    for (value, valid) in &[("-1.6", false), ("-1.5", false), ("-1.4", true)] {
+        assert_eq!(
+            is_valid(format!("{{ greaterThan(value: {}) }}", value)).await,
+            *valid,
+            "FloatGreaterThan case {}",
+            value
+        );
+    }
+
+    assert_eq!(
+        schema
+            .execute("{ range(value: 3.5) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Rule {
+            errors: vec![RuleError {
+                locations: vec![Pos { line: 1, column: 9 }],
+                message: "Invalid value for argument \"value\", the value is 3.5, must be between -1.5 and 2.5".to_string(),
+                extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+kind: Some(RuleErrorKind::InvalidArgumentValue {
+ arg_name: "value".to_string(),
+ }),
+}]
+        }
+    );
+}
+
+#[async_std::test]
+pub async fn test_input_validator_multiple_of() {
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {
+        async fn int_value(&self, #[arg(validator(MultipleOf(value = "3")))] _value: i32) -> bool {
+            true
+        }
+
+        async fn float_value(
+            &self,
+            #[arg(validator(MultipleOf(value = "0.25")))] _value: f64,
+        ) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let is_valid = |query: String| {
+        let schema = &schema;
+        async move { schema.execute(&query).await.into_result().is_ok() }
+    };
+
+    for (value, valid) in &[
+        ("-6", true),
+        ("-1", false),
+        ("0", true),
+        ("3", true),
+        ("4", false),
+        ("9", true),
+    ] {
+        assert_eq!(
+            is_valid(format!("{{ intValue(value: {}) }}", value)).await,
+            *valid,
+            "MultipleOf case {}",
+            value
+        );
+    }
+
+    for (value, valid) in &[("0.5", true), ("0.3", false), ("1.75", true), ("2", true)] {
+        assert_eq!(
+            is_valid(format!("{{ floatValue(value: {}) }}", value)).await,
+            *valid,
+            "MultipleOf case {}",
+            value
+        );
+    }
+
+    assert_eq!(
+        schema
+            .execute("{ intValue(value: 4) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Rule {
+            errors: vec![RuleError {
+                locations: vec![Pos {
+                    line: 1,
+                    column: 12
+                }],
+                message:
+                    "Invalid value for argument \"value\", the value is 4, must be a multiple of 3"
+                        .to_string(),
+                extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+                kind: Some(RuleErrorKind::InvalidArgumentValue {
+                    arg_name: "value".to_string(),
+                }),
+            }]
+        }
+    );
+}
+
+#[test]
+pub fn test_input_validator_multiple_of_integers() {
+    // 2^53 + 1 can't be represented as a float, which would round it to 2^53.
+    let value = Value::Number(9_007_199_254_740_993_i64.into());
+    assert!(MultipleOf { value: 3.0 }.is_valid(&value).is_ok());
+    assert!(MultipleOf { value: 2.0 }.is_valid(&value).is_err());
+    assert!(MultipleOf { value: -3.0 }
+        .is_valid(&Value::Number(i64::MIN.into()))
+        .is_err());
+    assert!(MultipleOf { value: -1.0 }
+        .is_valid(&Value::Number(i64::MIN.into()))
+        .is_ok());
+}
+
+#[test]
+pub fn test_input_validator_multiple_of_zero() {
+    assert_eq!(
+        MultipleOf { value: 0.0 }.is_valid(&Value::Number(0.into())),
+        Err("the divisor of MultipleOf must not be zero".to_string())
+    );
+    assert!(MultipleOf { value: 0.0 }
+        .is_valid(&Value::Number(serde_json::Number::from_f64(1.5).unwrap()))
+        .is_err());
+}
+
+#[test]
+pub fn test_input_validator_number_non_number() {
+    // Other types are left to the type checks.
+    let values = [
+        Value::Null,
+        Value::Boolean(true),
+        Value::String("abc".to_string()),
+    ];
+    for value in &values {
+        assert!(FloatRange { min: 0.0, max: 1.0 }.is_valid(value).is_ok());
+        assert!(FloatLessThan { value: 0.0 }.is_valid(value).is_ok());
+        assert!(FloatGreaterThan { value: 0.0 }.is_valid(value).is_ok());
+        assert!(MultipleOf { value: 3.0 }.is_valid(value).is_ok());
+    }
+}
+
+#[async_std::test]
+pub async fn test_input_validator_secret_argument() {
+    struct NotCommon;
+
+    impl InputValueValidator for NotCommon {
+        fn is_valid(&self, value: &Value) -> std::result::Result<(), String> {
+            match value {
+                Value::String(s) if s == "password" => {
+                    Err(format!("\"{}\" is a common password", s))
+                }
+                _ => Ok(()),
+            }
+        }
+    }
+
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {
+        async fn login(&self, #[arg(secret, validator(NotCommon))] _password: String) -> bool {
+            true
+        }
+    }
+
+    // Extensions are given the query and the variables without the secret values.
+    struct Redacted(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl Extension for Redacted {
+        fn redacted_query(&mut self, query_source: &str, variables: &Variables) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((query_source.to_string(), variables.to_string()));
+        }
+    }
+
+    let redacted = Arc::new(Mutex::new(Vec::new()));
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .extension({
+            let redacted = redacted.clone();
+            move || Redacted(redacted.clone())
+        })
+        .finish();
+    let expected = Error::Rule {
+        errors: vec![RuleError {
+            locations: vec![Pos { line: 1, column: 9 }],
+            message: "Invalid value for argument \"password\"".to_string(),
+            extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+            kind: Some(RuleErrorKind::InvalidArgumentValue {
+                arg_name: "password".to_string(),
+            }),
+        }],
+    };
+
+    assert_eq!(
+        schema
+            .execute(r#"{ login(password: "password") }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        expected
+    );
+
+    let errors = match schema
+        .execute(
+            Request::new(r#"query($password: String!) { login(password: $password) }"#).variables(
+                Variables::from_json(serde_json::json!({ "password": "password" })),
+            ),
+        )
+        .await
+        .into_result()
+        .unwrap_err()
+    {
+        Error::Rule { errors } => errors,
+        err => panic!("unexpected error: {}", err),
+    };
+    assert_eq!(
+        errors
+            .iter()
+            .map(|error| error.message.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Invalid value for argument \"password\""]
+    );
+
+    // Short values do not affect the rest of the query.
+    assert!(schema.execute(r#"{ login(password: "a") }"#).await.is_ok());
+    assert!(schema
+        .execute(r#"query($password: String! = "a") { login(password: $password) }"#)
+        .await
+        .is_ok());
+
+    assert_eq!(
+        *redacted.lock().unwrap(),
+        vec![
+            (r#"{ login(password: ***) }"#.to_string(), "{}".to_string()),
+            (
+                r#"query($password: String!) { login(password: $password) }"#.to_string(),
+                r#"{password: "***"}"#.to_string()
+            ),
+            (r#"{ login(password: ***) }"#.to_string(), "{}".to_string()),
+            (
+                r#"query($password: String! = ***) { login(password: $password) }"#.to_string(),
+                "{}".to_string()
+            ),
+        ]
+    );
+}
@@ -331,3 +331,183 @@ pub async fn test_interface_field_method() {
         })
     );
 }
+
+#[test]
+#[should_panic(
+    expected = r#"Object "MyObj" does not implement the field "id" of interface "Node"."#
+)]
+pub fn test_interface_missing_field() {
+    #[derive(GQLSimpleObject)]
+    struct MyObj {
+        #[field(name = "identifier")]
+        id: i32,
+    }
+
+    #[derive(GQLInterface)]
+    #[graphql(field(name = "id", type = "&i32"))]
+    enum Node {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn node(&self) -> Node {
+            MyObj { id: 33 }.into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[async_std::test]
+pub async fn test_dyn_interface() {
+    #[derive(GQLInterface)]
+    #[graphql(field(name = "name", type = "String"))]
+    enum Plugin {}
+
+    struct Greeter;
+
+    #[GQLObject]
+    impl Greeter {
+        async fn name(&self) -> String {
+            "greeter".to_string()
+        }
+
+        async fn greeting(&self) -> &str {
+            "Hello"
+        }
+    }
+
+    struct Counter(i32);
+
+    #[GQLObject]
+    impl Counter {
+        async fn name(&self) -> String {
+            "counter".to_string()
+        }
+
+        async fn count(&self) -> i32 {
+            self.0
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn plugins(&self) -> Vec<DynInterface<Plugin>> {
+            vec![DynInterface::new(Greeter), DynInterface::new(Counter(3))]
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_implementor::<Plugin, Greeter>()
+        .register_implementor::<Plugin, Counter>()
+        .finish();
+    let query = r#"{
+            plugins {
+                __typename
+                name
+                ... on Greeter { greeting }
+                ... on Counter { count }
+            }
+        }"#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "plugins": [
+                { "__typename": "Greeter", "name": "greeter", "greeting": "Hello" },
+                { "__typename": "Counter", "name": "counter", "count": 3 },
+            ]
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Plugin") { possibleTypes { name } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": {
+                "possibleTypes": [{ "name": "Greeter" }, { "name": "Counter" }]
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_dyn_interface_unregistered() {
+    #[derive(GQLInterface)]
+    #[graphql(field(name = "name", type = "String"))]
+    enum Plugin {}
+
+    struct Greeter;
+
+    #[GQLObject]
+    impl Greeter {
+        async fn name(&self) -> String {
+            "greeter".to_string()
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn plugin(&self) -> DynInterface<Plugin> {
+            DynInterface::new(Greeter)
+        }
+
+        async fn greeter(&self) -> Greeter {
+            Greeter
+        }
+    }
+
+    // `Greeter` is part of the schema, but it is not registered as an implementor of `Plugin`.
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    match schema
+        .execute("{ plugin { name } }")
+        .await
+        .into_result()
+        .unwrap_err()
+    {
+        Error::Query {
+            err: QueryError::FieldError { err, .. },
+            ..
+        } => assert_eq!(
+            err,
+            "\"Greeter\" is not registered as an implementor of \"Plugin\""
+        ),
+        err => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+#[should_panic(expected = "\"Query\" is not an interface.")]
+pub fn test_dyn_interface_register_not_interface() {
+    struct Greeter;
+
+    #[GQLObject]
+    impl Greeter {
+        async fn name(&self) -> String {
+            "greeter".to_string()
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_implementor::<Query, Greeter>()
+        .finish();
+}
@@ -504,6 +504,73 @@ pub async fn test_introspection_deprecation() {
     assert_eq!(res, res_json);
 }
 
+#[async_std::test]
+pub async fn test_introspection_deprecation_default() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    // Deprecated fields and enum values are excluded when `includeDeprecated` is omitted.
+    let query = r#"
+        {
+            square: __type(name: "Square") {
+                fields {
+                    name
+                }
+            }
+            testEnum: __type(name: "TestEnum") {
+                enumValues {
+                    name
+                }
+            }
+        }
+    "#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "square": {
+                "fields": []
+            },
+            "testEnum": {
+                "enumValues": [
+                    { "name": "KIND_1" }
+                ]
+            }
+        })
+    );
+
+    let query = r#"
+        {
+            square: __type(name: "Square") {
+                fields(includeDeprecated: true) {
+                    name
+                    deprecationReason
+                }
+            }
+            testEnum: __type(name: "TestEnum") {
+                enumValues(includeDeprecated: true) {
+                    name
+                    deprecationReason
+                }
+            }
+        }
+    "#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "square": {
+                "fields": [
+                    { "name": "scale", "deprecationReason": "Field scale is deprecated" }
+                ]
+            },
+            "testEnum": {
+                "enumValues": [
+                    { "name": "KIND_1", "deprecationReason": null },
+                    { "name": "KIND_2", "deprecationReason": "Kind 2 deprecated" }
+                ]
+            }
+        })
+    );
+}
+
 #[async_std::test]
 pub async fn test_introspection_type_kind() {
     let schema = Schema::new(Query, Mutation, EmptySubscription);
@@ -1181,3 +1248,114 @@ pub async fn test_introspection_subscription() {
 //
 //     assert_eq!(res, res_json)
 // }
+
+#[async_std::test]
+pub async fn test_introspection_type_wrapper_name() {
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+    let get_type_query = |name| {
+        format!(
+            r#"{{
+              __type(name: "{}") {{
+                  name
+                  kind
+              }}
+          }}"#,
+            name
+        )
+    };
+
+    assert_eq!(
+        schema
+            .execute(&get_type_query("Int"))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": {
+                "name": "Int",
+                "kind": "SCALAR"
+            }
+        })
+    );
+
+    for name in &["Int!", "[Int!]", "[Int]!", "[SimpleObject!]!"] {
+        assert_eq!(
+            schema
+                .execute(&get_type_query(*name))
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            serde_json::json!({ "__type": null })
+        );
+    }
+}
+
+#[async_std::test]
+pub async fn test_introspection_deprecation_without_reason() {
+    #[derive(GQLEnum, Copy, Clone, Eq, PartialEq)]
+    enum Color {
+        Red,
+        #[item(deprecation)]
+        Crimson,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        #[field(deprecation)]
+        async fn old_color(&self) -> Color {
+            Color::Crimson
+        }
+    }
+
+    let query = r#"{
+        query: __type(name: "Query") {
+            fields(includeDeprecated: true) { name isDeprecated deprecationReason }
+        }
+        color: __type(name: "Color") {
+            enumValues(includeDeprecated: true) { name isDeprecated deprecationReason }
+        }
+    }"#;
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "query": {
+                "fields": [
+                    { "name": "oldColor", "isDeprecated": true, "deprecationReason": null },
+                ]
+            },
+            "color": {
+                "enumValues": [
+                    { "name": "RED", "isDeprecated": false, "deprecationReason": null },
+                    { "name": "CRIMSON", "isDeprecated": true, "deprecationReason": null },
+                ]
+            },
+        })
+    );
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .default_deprecation_reason("Deprecated")
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "query": {
+                "fields": [
+                    { "name": "oldColor", "isDeprecated": true, "deprecationReason": "Deprecated" },
+                ]
+            },
+            "color": {
+                "enumValues": [
+                    { "name": "RED", "isDeprecated": false, "deprecationReason": null },
+                    { "name": "CRIMSON", "isDeprecated": true, "deprecationReason": "Deprecated" },
+                ]
+            },
+        })
+    );
+}
@@ -53,3 +53,246 @@ pub async fn test_json_scalar() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_json_object_scalar() {
+    use std::collections::BTreeMap;
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn metadata(&self) -> BTreeMap<String, String> {
+            let mut metadata = BTreeMap::new();
+            metadata.insert("author".to_string(), "sunli".to_string());
+            metadata.insert("content-type".to_string(), "text/plain".to_string());
+            metadata
+        }
+
+        async fn counts(&self) -> HashMap<String, Vec<i32>> {
+            let mut counts = HashMap::new();
+            counts.insert("a".to_string(), vec![1, 2]);
+            counts.insert("b".to_string(), vec![]);
+            counts
+        }
+
+        async fn sum(&self, values: BTreeMap<String, i32>) -> i32 {
+            values.values().sum()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ metadata counts sum(values: { a: 1, b: 2 }) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "metadata": { "author": "sunli", "content-type": "text/plain" },
+            "counts": { "a": [1, 2], "b": [] },
+            "sum": 3,
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "JSONObject") { kind } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "__type": { "kind": "SCALAR" } })
+    );
+}
+
+#[async_std::test]
+pub async fn test_typed_json() {
+    #[derive(GQLSimpleObject)]
+    struct Circle {
+        radius: f32,
+    }
+
+    #[derive(GQLSimpleObject)]
+    struct Square {
+        width: f32,
+        height: Option<f32>,
+    }
+
+    #[derive(GQLUnion)]
+    enum Shape {
+        Circle(Circle),
+        Square(Square),
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn shapes(&self) -> Vec<TypedJson<Shape>> {
+            vec![
+                TypedJson::new("Circle", serde_json::json!({ "radius": 2.0 })),
+                TypedJson::new("Square", serde_json::json!({ "width": 3.0 })),
+            ]
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        shapes {
+            __typename
+            ... on Circle { radius }
+            ... on Square { w: width height }
+        }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "shapes": [
+                { "__typename": "Circle", "radius": 2.0 },
+                { "__typename": "Square", "w": 3.0, "height": null },
+            ]
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_typed_json_non_null_field() {
+    #[derive(GQLSimpleObject)]
+    struct Person {
+        name: String,
+        nickname: Option<String>,
+    }
+
+    #[derive(GQLUnion)]
+    enum Node {
+        Person(Person),
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn node(&self) -> TypedJson<Node> {
+            TypedJson::new("Person", serde_json::json!({ "nickname": "abc" }))
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ node { ... on Person { nickname } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "node": { "nickname": "abc" } })
+    );
+
+    let err = schema
+        .execute("{ node { ... on Person { name } } }")
+        .await
+        .into_result()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Error::Query {
+            pos: Pos {
+                line: 1,
+                column: 26
+            },
+            path: Some(serde_json::json!(["node", "name"])),
+            err: QueryError::NullValueForNonNullField {
+                object: "Person".to_string(),
+                field_name: "name".to_string(),
+            },
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "Query error: Cannot return null for non-nullable field Person.name."
+    );
+}
+
+#[async_std::test]
+pub async fn test_typed_json_errors() {
+    #[derive(GQLSimpleObject)]
+    struct Point {
+        x: f32,
+    }
+
+    #[derive(GQLSimpleObject)]
+    struct Circle {
+        radius: f32,
+        center: Option<Point>,
+    }
+
+    #[derive(GQLSimpleObject)]
+    struct Square {
+        width: f32,
+    }
+
+    #[derive(GQLUnion)]
+    enum Shape {
+        Circle(Circle),
+        Square(Square),
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn shape(
+            &self,
+            typename: String,
+            value: Json<serde_json::Value>,
+        ) -> TypedJson<Shape> {
+            TypedJson::new(typename, value.0)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let execute = |typename: &str, value: serde_json::Value| {
+        let request = Request::new(
+            "query($typename: String!, $value: JSON!) { shape(typename: $typename, value: $value) { ... on Circle { radius center { x } } } }",
+        )
+        .variables(Variables::from_json(
+            serde_json::json!({ "typename": typename, "value": value }),
+        ));
+        schema.execute(request)
+    };
+    let error_message = |response: Response| match response.into_result().unwrap_err() {
+        Error::Query {
+            err: QueryError::FieldError { err, .. },
+            ..
+        } => err,
+        err => panic!("unexpected error: {}", err),
+    };
+
+    // Nested objects are null or an error.
+    assert_eq!(
+        execute("Circle", serde_json::json!({ "radius": 1.0 }))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "shape": { "radius": 1.0, "center": null } })
+    );
+    assert_eq!(
+        error_message(
+            execute(
+                "Circle",
+                serde_json::json!({ "radius": 1.0, "center": { "x": 1.0 } })
+            )
+            .await
+        ),
+        "The field \"center\" of \"Circle\" is not a scalar or an enum, so it can't be resolved from JSON"
+    );
+
+    // The type name must be a possible type of the union.
+    assert_eq!(
+        error_message(execute("Point", serde_json::json!({ "x": 1.0 })).await),
+        "\"Point\" is not an object type of \"Shape\""
+    );
+}
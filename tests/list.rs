@@ -117,3 +117,61 @@ pub async fn test_list_type() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_list_error_path() {
+    struct User {
+        id: i32,
+    }
+
+    #[GQLObject]
+    impl User {
+        async fn id(&self) -> i32 {
+            self.id
+        }
+
+        async fn email(&self) -> FieldResult<String> {
+            if self.id == 2 {
+                Err("Email is hidden".into())
+            } else {
+                Ok(format!("user{}@example.com", self.id))
+            }
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn users(&self) -> Vec<User> {
+            (0..4).map(|id| User { id }).collect()
+        }
+
+        async fn values(&self) -> Vec<FieldResult<i32>> {
+            vec![Ok(0), Ok(1), Err("Bad value".into())]
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        serde_json::to_value(&schema.execute("{ users { id email } }").await).unwrap(),
+        serde_json::json!({
+            "errors": [{
+                "message": "Email is hidden",
+                "locations": [{ "line": 1, "column": 14 }],
+                "path": ["users", 2, "email"],
+            }]
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(&schema.execute("{ values }").await).unwrap(),
+        serde_json::json!({
+            "errors": [{
+                "message": "Bad value",
+                "locations": [{ "line": 1, "column": 3 }],
+                "path": ["values", 2],
+            }]
+        })
+    );
+}
@@ -0,0 +1,134 @@
+use async_graphql::*;
+use futures::StreamExt;
+
+struct User {
+    id: i32,
+}
+
+#[GQLObject]
+impl User {
+    async fn id(&self) -> i32 {
+        self.id
+    }
+
+    async fn email(&self) -> FieldResult<String> {
+        if self.id == 3 {
+            Err("Email is hidden".into())
+        } else {
+            Ok(format!("user{}@example.com", self.id))
+        }
+    }
+}
+
+struct Query;
+
+#[GQLObject]
+impl Query {
+    async fn values(&self) -> ListStream<i32> {
+        ListStream::new(futures::stream::iter(0..5))
+    }
+
+    async fn users(&self) -> ListStream<User> {
+        ListStream::new(futures::stream::iter((0..5).map(|id| User { id })))
+    }
+}
+
+async fn execute_stream(
+    schema: &Schema<Query, EmptyMutation, EmptySubscription>,
+    query: &str,
+) -> Vec<serde_json::Value> {
+    schema
+        .execute_stream(query)
+        .map(|response| serde_json::to_value(&response).unwrap())
+        .collect()
+        .await
+}
+
+#[async_std::test]
+pub async fn test_list_stream_as_list() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ values }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "values": [0, 1, 2, 3, 4] })
+    );
+
+    // `Schema::execute` does not deliver responses incrementally.
+    assert_eq!(
+        schema
+            .execute("{ values @stream(initialCount: 2) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "values": [0, 1, 2, 3, 4] })
+    );
+
+    assert_eq!(
+        execute_stream(&schema, "{ values }").await,
+        vec![serde_json::json!({ "data": { "values": [0, 1, 2, 3, 4] } })]
+    );
+}
+
+#[async_std::test]
+pub async fn test_list_stream_incremental() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        execute_stream(&schema, "{ values @stream(initialCount: 2) }").await,
+        vec![
+            serde_json::json!({ "data": { "values": [0, 1] }, "hasNext": true }),
+            serde_json::json!({ "data": 2, "path": ["values", 2], "hasNext": true }),
+            serde_json::json!({ "data": 3, "path": ["values", 3], "hasNext": true }),
+            serde_json::json!({ "data": 4, "path": ["values", 4], "hasNext": true }),
+            serde_json::json!({ "hasNext": false }),
+        ]
+    );
+
+    assert_eq!(
+        execute_stream(&schema, "{ users @stream(initialCount: 4) { id } }").await,
+        vec![
+            serde_json::json!({
+                "data": { "users": [{ "id": 0 }, { "id": 1 }, { "id": 2 }, { "id": 3 }] },
+                "hasNext": true,
+            }),
+            serde_json::json!({ "data": { "id": 4 }, "path": ["users", 4], "hasNext": true }),
+            serde_json::json!({ "hasNext": false }),
+        ]
+    );
+}
+
+#[async_std::test]
+pub async fn test_list_stream_error() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        execute_stream(&schema, "{ users @stream(initialCount: 1) { id email } }").await,
+        vec![
+            serde_json::json!({
+                "data": { "users": [{ "id": 0, "email": "user0@example.com" }] },
+                "hasNext": true,
+            }),
+            serde_json::json!({
+                "data": { "id": 1, "email": "user1@example.com" },
+                "path": ["users", 1],
+                "hasNext": true,
+            }),
+            serde_json::json!({
+                "data": { "id": 2, "email": "user2@example.com" },
+                "path": ["users", 2],
+                "hasNext": true,
+            }),
+            serde_json::json!({
+                "errors": [{
+                    "message": "Email is hidden",
+                    "locations": [{ "line": 1, "column": 39 }],
+                    "path": ["users", 3, "email"],
+                }],
+                "hasNext": false,
+            }),
+        ]
+    );
+}
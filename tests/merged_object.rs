@@ -111,6 +111,37 @@ pub async fn test_merged_object_derive() {
     );
 }
 
+#[async_std::test]
+pub async fn test_merged_object_typename() {
+    #[derive(GQLMergedObject)]
+    #[graphql(name = "MergedObj")]
+    struct MyObj(Object1, Object2, Object3);
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn obj(&self) -> MyObj {
+            MyObj(Object1 { a: 10 }, Object2 { b: 20 }, Object3 { c: 30 })
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = "{ obj { a __typename c ... on MergedObj { t: __typename b } } }";
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "obj": {
+                "a": 10,
+                "__typename": "MergedObj",
+                "c": 30,
+                "t": "MergedObj",
+                "b": 20,
+            }
+        })
+    );
+}
+
 #[async_std::test]
 pub async fn test_merged_object_default() {
     mod a {
@@ -221,3 +252,37 @@ pub async fn test_merged_subscription() {
         assert!(stream.next().await.is_none());
     }
 }
+
+#[test]
+#[should_panic(expected = r#"Field "events" is defined in more than one merged object."#)]
+pub fn test_merged_subscription_field_clash() {
+    #[derive(Default)]
+    struct Subscription1;
+
+    #[GQLSubscription]
+    impl Subscription1 {
+        async fn events(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..10)
+        }
+    }
+
+    #[derive(Default)]
+    struct Subscription2;
+
+    #[GQLSubscription]
+    impl Subscription2 {
+        async fn events(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(10..20)
+        }
+    }
+
+    #[derive(GQLMergedSubscription, Default)]
+    struct Subscription(Subscription1, Subscription2);
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {}
+
+    Schema::new(Query, EmptyMutation, Subscription::default());
+}
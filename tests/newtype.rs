@@ -0,0 +1,82 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_newtype() {
+    fn validate_email(value: &str) -> Result<(), &'static str> {
+        if value.contains('@') {
+            Ok(())
+        } else {
+            Err("Invalid email")
+        }
+    }
+
+    /// An email address
+    #[derive(GQLNewType)]
+    #[graphql(validate = "validate_email")]
+    struct Email(String);
+
+    #[derive(GQLNewType)]
+    #[graphql(name = "Count")]
+    struct MyCount(i32);
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn email(&self, email: Email) -> Email {
+            email
+        }
+
+        async fn count(&self) -> MyCount {
+            MyCount(10)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ email(email: "a@example.com") count }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "email": "a@example.com",
+            "count": 10,
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ email(email: "abc") }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos {
+                line: 1,
+                column: 16
+            },
+            path: None,
+            err: QueryError::ParseInputValue {
+                reason: "Invalid email".to_string()
+            },
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Email") { kind description } count: __type(name: "Count") { kind } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": {
+                "kind": "SCALAR",
+                "description": "An email address",
+            },
+            "count": { "kind": "SCALAR" },
+        })
+    );
+}
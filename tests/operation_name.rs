@@ -0,0 +1,167 @@
+use async_graphql::*;
+use futures::StreamExt;
+
+#[async_std::test]
+pub async fn test_operation_name() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+
+        async fn value2(&self) -> i32 {
+            20
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = "query A { value } query B { value2 }";
+
+    assert_eq!(
+        schema
+            .execute(Request::new(query).operation_name("A"))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "value": 10 })
+    );
+    assert_eq!(
+        schema
+            .execute(Request::new(query).operation_name("B"))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "value2": 20 })
+    );
+    assert_eq!(
+        schema
+            .execute(Request::new(query).operation_name("C"))
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos::default(),
+            path: None,
+            err: QueryError::UnknownOperationNamed {
+                name: "C".to_string()
+            },
+        }
+    );
+
+    // A single operation doesn't need to be named.
+    assert_eq!(
+        schema
+            .execute("query A { value }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "value": 10 })
+    );
+}
+
+#[async_std::test]
+pub async fn test_operation_name_required() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("query A { value } query B { value }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos::default(),
+            path: None,
+            err: QueryError::OperationNameRequired,
+        }
+    );
+}
+
+#[async_std::test]
+pub async fn test_response_operation_name() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let resp = schema
+        .execute(Request::new("query A { value } query B { value }").operation_name("B"))
+        .await;
+    assert_eq!(resp.operation_name.as_deref(), Some("B"));
+
+    let resp = schema.execute("query GetValue { value }").await;
+    assert_eq!(resp.operation_name.as_deref(), Some("GetValue"));
+
+    let resp = schema.execute("{ value }").await;
+    assert_eq!(resp.operation_name, None);
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        serde_json::json!({ "data": { "value": 10 } })
+    );
+}
+
+#[async_std::test]
+pub async fn test_response_operation_name_stream() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn values(&self) -> ListStream<i32> {
+            ListStream::new(futures::stream::iter(0..3))
+        }
+    }
+
+    struct Subscription;
+
+    #[GQLSubscription]
+    impl Subscription {
+        async fn values(&self) -> impl futures::Stream<Item = i32> {
+            futures::stream::iter(0..3)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, Subscription);
+    let operation_names = |query: &'static str| {
+        let schema = &schema;
+        async move {
+            schema
+                .execute_stream(query)
+                .map(|response| response.operation_name)
+                .collect::<Vec<_>>()
+                .await
+        }
+    };
+
+    // Every response of an incrementally delivered query has the name.
+    assert_eq!(
+        operation_names("query GetValues { values @stream(initialCount: 1) }").await,
+        vec![Some("GetValues".to_string()); 4]
+    );
+    assert_eq!(
+        operation_names("subscription OnValues { values }").await,
+        vec![Some("OnValues".to_string()); 3]
+    );
+
+    let resp = schema.execute("subscription OnValues { values }").await;
+    assert!(resp.is_err());
+    assert_eq!(resp.operation_name.as_deref(), Some("OnValues"));
+}
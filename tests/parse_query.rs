@@ -0,0 +1,24 @@
+use async_graphql::ast::ExecutableDefinition;
+use async_graphql::*;
+
+#[test]
+pub fn test_parse_query() {
+    let document = parse_query("query A { a } fragment B on Query { b }").unwrap();
+    assert_eq!(document.definitions.len(), 2);
+    assert!(matches!(
+        document.definitions[0],
+        ExecutableDefinition::Operation(_)
+    ));
+    assert!(matches!(
+        document.definitions[1],
+        ExecutableDefinition::Fragment(_)
+    ));
+
+    match parse_query("{ a(b: ) }").unwrap_err() {
+        Error::Parse(err) => {
+            assert_eq!(err.pos.line, 1);
+            assert!(!err.message.is_empty());
+        }
+        err => panic!("expected a parse error, got {:?}", err),
+    }
+}
@@ -336,3 +336,51 @@ pub async fn test_post_guard_generic() {
         }
     );
 }
+
+#[async_std::test]
+pub async fn test_stacked_post_guards() {
+    #[derive(GQLSimpleObject)]
+    struct Query {
+        #[field(post_guard(RoleGuard(role = "Role::Admin")))]
+        #[field(post_guard(UserGuard(username = r#""test""#, value = "10")))]
+        value: i32,
+    }
+
+    let schema = Schema::new(Query { value: 10 }, EmptyMutation, EmptySubscription);
+
+    let query = "{ value }";
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(query)
+                    .data(Role::Admin)
+                    .data(Username("test".to_string()))
+            )
+            .await
+            .data,
+        serde_json::json!({"value": 10})
+    );
+
+    // Both guards are checked, not only the one of the last attribute.
+    for (role, username) in &[(Role::Guest, "test"), (Role::Admin, "test1")] {
+        assert_eq!(
+            schema
+                .execute(
+                    Request::new(query)
+                        .data(*role)
+                        .data(Username(username.to_string()))
+                )
+                .await
+                .into_result()
+                .unwrap_err(),
+            Error::Query {
+                pos: Pos { line: 1, column: 3 },
+                path: Some(serde_json::json!(["value"])),
+                err: QueryError::FieldError {
+                    err: "Forbidden".to_string(),
+                    extended_error: None,
+                },
+            }
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_query_path() {
+    struct Post;
+
+    #[GQLObject]
+    impl Post {
+        async fn title(&self, ctx: &Context<'_>) -> String {
+            ctx.path_node.as_ref().unwrap().to_string_path().join(".")
+        }
+
+        async fn indices(&self, ctx: &Context<'_>) -> Vec<i32> {
+            let mut indices = Vec::new();
+            for segment in ctx.path_node.as_ref().unwrap() {
+                if let QueryPathSegment::Index(idx) = segment {
+                    indices.push(*idx as i32);
+                }
+            }
+            indices
+        }
+    }
+
+    struct User;
+
+    #[GQLObject]
+    impl User {
+        async fn posts(&self) -> Vec<Post> {
+            vec![Post]
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn users(&self) -> Vec<User> {
+            vec![User, User, User]
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let data = schema
+        .execute("{ users { posts { title indices } } }")
+        .await
+        .into_result()
+        .unwrap()
+        .data;
+    assert_eq!(
+        data["users"][2]["posts"][0]["title"],
+        serde_json::json!("users.2.posts.0.title")
+    );
+    assert_eq!(
+        data["users"][0]["posts"][0]["title"],
+        serde_json::json!("users.0.posts.0.title")
+    );
+    assert_eq!(
+        data["users"][2]["posts"][0]["indices"],
+        serde_json::json!([2, 0])
+    );
+}
@@ -0,0 +1,86 @@
+use async_graphql::extensions::{RateLimit, RateLimiter};
+use async_graphql::*;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+#[async_std::test]
+pub async fn test_rate_limit() {
+    struct ApiKey(String);
+
+    struct MyLimiter {
+        limit: usize,
+        counts: Mutex<HashMap<(String, Option<String>), usize>>,
+    }
+
+    impl RateLimiter for MyLimiter {
+        type Key = ApiKey;
+
+        fn check(&self, key: Option<&ApiKey>, operation_name: Option<&str>) -> bool {
+            let key = match key {
+                Some(key) => key.0.clone(),
+                None => return false,
+            };
+            let mut counts = self.counts.lock().unwrap();
+            let count = counts
+                .entry((key, operation_name.map(ToString::to_string)))
+                .or_default();
+            *count += 1;
+            *count <= self.limit
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let limiter = Arc::new(MyLimiter {
+        limit: 2,
+        counts: Default::default(),
+    });
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(move || RateLimit::new(limiter.clone()))
+        .finish();
+    let request = |key: &str, query: &str| Request::new(query).data(ApiKey(key.to_string()));
+
+    for _ in 0..2 {
+        assert_eq!(
+            schema
+                .execute(request("a", "query A { value }"))
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            serde_json::json!({ "value": 10 })
+        );
+    }
+    assert_eq!(
+        schema
+            .execute(request("a", "query A { value }"))
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos::default(),
+            path: None,
+            err: QueryError::TooManyRequests,
+        }
+    );
+
+    // Limits are kept per caller and operation.
+    assert!(schema
+        .execute(request("b", "query A { value }"))
+        .await
+        .is_ok());
+    assert!(schema
+        .execute(request("a", "query B { value }"))
+        .await
+        .is_ok());
+
+    // Requests without a caller identifier are rejected by this limiter.
+    assert!(schema.execute("{ value }").await.is_err());
+}
@@ -0,0 +1,72 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_scalar_asymmetric_input_and_output() {
+    /// An amount of money, which is input as a decimal string and output as a number of cents.
+    struct Cents(i64);
+
+    #[GQLScalar]
+    impl ScalarType for Cents {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            if let Value::String(s) = &value {
+                let amount: f64 = s.parse()?;
+                Ok(Cents((amount * 100.0).round() as i64))
+            } else {
+                Err(InputValueError::ExpectedType(value))
+            }
+        }
+
+        fn is_valid(value: &Value) -> bool {
+            matches!(value, Value::String(_))
+        }
+
+        fn to_value(&self) -> Value {
+            Value::Number(self.0.into())
+        }
+    }
+
+    #[derive(GQLInputObject)]
+    struct Payment {
+        amount: Cents,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn double(&self, value: Cents) -> Cents {
+            Cents(value.0 * 2)
+        }
+
+        async fn total(&self, payments: Vec<Payment>) -> Cents {
+            Cents(payments.iter().map(|payment| payment.amount.0).sum())
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = Request::new(
+        r#"query($amount: Cents!) {
+            a: double(value: "1.25")
+            b: double(value: $amount)
+            total(payments: [{ amount: "2" }, { amount: $amount }])
+        }"#,
+    )
+    .variables(Variables::from_json(serde_json::json!({ "amount": "0.5" })));
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({ "a": 250, "b": 100, "total": 250 })
+    );
+
+    // The output format is not accepted as an input.
+    assert!(schema.execute("{ double(value: 125) }").await.is_err());
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Cents") { kind } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "__type": { "kind": "SCALAR" } })
+    );
+}
@@ -9,3 +9,920 @@ pub async fn test_schema_default() {
 
     let _schema = MySchema::default();
 }
+
+#[async_std::test]
+pub async fn test_disable_suggestions() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self, input: i32) -> i32 {
+            input
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute("{ valu }").await.into_result().unwrap_err(),
+        Error::Rule {
+            errors: vec![RuleError {
+                locations: vec![Pos { line: 1, column: 3 }],
+                message: r#"Unknown field "valu" on type "Query". Did you mean "value"?"#
+                    .to_string(),
+                extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+                kind: Some(RuleErrorKind::UnknownField {
+                    object: "Query".to_string(),
+                    field_name: "valu".to_string(),
+                }),
+            }]
+        }
+    );
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .disable_suggestions()
+        .finish();
+    assert_eq!(
+        schema.execute("{ valu }").await.into_result().unwrap_err(),
+        Error::Rule {
+            errors: vec![RuleError {
+                locations: vec![Pos { line: 1, column: 3 }],
+                message: r#"Unknown field "valu" on type "Query"."#.to_string(),
+                extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+                kind: Some(RuleErrorKind::UnknownField {
+                    object: "Query".to_string(),
+                    field_name: "valu".to_string(),
+                }),
+            }]
+        }
+    );
+    assert_eq!(
+        schema
+            .execute("{ value(input: 1, inpt: 1) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Rule {
+            errors: vec![RuleError {
+                locations: vec![Pos {
+                    line: 1,
+                    column: 19
+                }],
+                message: r#"Unknown argument "inpt" on field "value" of type "Query"."#.to_string(),
+                extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+                kind: Some(RuleErrorKind::UnknownArgument {
+                    object: "Query".to_string(),
+                    field_name: "value".to_string(),
+                    arg_name: "inpt".to_string(),
+                }),
+            }]
+        }
+    );
+}
+
+#[async_std::test]
+pub async fn test_schema_snapshot() {
+    #[derive(GQLSimpleObject)]
+    struct B {
+        value: i32,
+    }
+
+    #[derive(GQLSimpleObject)]
+    struct A {
+        value: i32,
+    }
+
+    #[derive(GQLUnion)]
+    enum Item {
+        B(B),
+        A(A),
+    }
+
+    #[derive(GQLEnum, Copy, Clone, Eq, PartialEq)]
+    enum Color {
+        Red,
+        #[item(deprecation = "Use Red")]
+        Blue,
+    }
+
+    struct Query;
+
+    #[GQLObject(desc = "The query root")]
+    impl Query {
+        async fn item(&self) -> Item {
+            A { value: 1 }.into()
+        }
+
+        #[field(deprecation = "Use item")]
+        async fn color(&self, #[arg(default = 10)] n: i32) -> Vec<Color> {
+            vec![Color::Red; n as usize]
+        }
+    }
+
+    let snapshot = Schema::new(Query, EmptyMutation, EmptySubscription).snapshot();
+    assert_eq!(
+        serde_json::to_string_pretty(&snapshot).unwrap(),
+        serde_json::to_string_pretty(
+            &Schema::new(Query, EmptyMutation, EmptySubscription).snapshot()
+        )
+        .unwrap()
+    );
+
+    assert_eq!(snapshot.query_type, "Query");
+    assert_eq!(snapshot.mutation_type, None);
+    assert_eq!(
+        snapshot
+            .types
+            .iter()
+            .map(|ty| ty.name())
+            .collect::<Vec<_>>(),
+        vec!["A", "B", "Boolean", "Color", "Float", "ID", "Int", "Item", "Query", "String"]
+    );
+    let type_snapshot = |name: &str| {
+        serde_json::to_value(snapshot.types.iter().find(|ty| ty.name() == name).unwrap()).unwrap()
+    };
+
+    assert_eq!(
+        type_snapshot("Item"),
+        serde_json::json!({
+            "kind": "UNION",
+            "name": "Item",
+            "description": null,
+            "possibleTypes": ["A", "B"],
+        })
+    );
+
+    assert_eq!(
+        type_snapshot("Query"),
+        serde_json::json!({
+            "kind": "OBJECT",
+            "name": "Query",
+            "description": "The query root",
+            "fields": [
+                {
+                    "name": "item",
+                    "description": null,
+                    "args": [],
+                    "type": "Item!",
+                    "deprecation": null,
+                },
+                {
+                    "name": "color",
+                    "description": null,
+                    "args": [
+                        {
+                            "name": "n",
+                            "description": null,
+                            "type": "Int!",
+                            "defaultValue": "10",
+                        }
+                    ],
+                    "type": "[Color!]!",
+                    "deprecation": "Use item",
+                },
+            ],
+            "interfaces": [],
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_schema_snapshot_default_deprecation_reason() {
+    #[derive(GQLEnum, Copy, Clone, Eq, PartialEq)]
+    enum Color {
+        #[item(deprecation)]
+        Blue,
+        Red,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        #[field(deprecation)]
+        async fn color(&self) -> Color {
+            Color::Red
+        }
+    }
+
+    let deprecations = |snapshot: SchemaSnapshot| {
+        let deprecation = |name: &str, key: &str| {
+            let ty = snapshot.types.iter().find(|ty| ty.name() == name).unwrap();
+            let ty = serde_json::to_value(ty).unwrap();
+            ty[key][0]["deprecation"].clone()
+        };
+        (
+            deprecation("Query", "fields"),
+            deprecation("Color", "enumValues"),
+        )
+    };
+
+    assert_eq!(
+        deprecations(Schema::new(Query, EmptyMutation, EmptySubscription).snapshot()),
+        (
+            serde_json::json!("No longer supported"),
+            serde_json::json!("No longer supported")
+        )
+    );
+    assert_eq!(
+        deprecations(
+            Schema::build(Query, EmptyMutation, EmptySubscription)
+                .default_deprecation_reason("Will be removed")
+                .finish()
+                .snapshot()
+        ),
+        (
+            serde_json::json!("Will be removed"),
+            serde_json::json!("Will be removed")
+        )
+    );
+}
+
+#[async_std::test]
+pub async fn test_schema_snapshot_directives() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let snapshot = Schema::new(Query, EmptyMutation, EmptySubscription).snapshot();
+    assert_eq!(
+        snapshot
+            .directives
+            .iter()
+            .map(|directive| directive.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["ifdef", "include", "oneOf", "skip", "stream"]
+    );
+
+    let include = snapshot
+        .directives
+        .iter()
+        .find(|directive| directive.name == "include")
+        .unwrap();
+    assert_eq!(
+        include.locations,
+        vec!["FIELD", "FRAGMENT_SPREAD", "INLINE_FRAGMENT"]
+    );
+    assert_eq!(
+        include
+            .args
+            .iter()
+            .map(|arg| (arg.name.as_str(), arg.ty.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("if", "Boolean!")]
+    );
+}
+
+#[async_std::test]
+pub async fn test_on_response() {
+    struct Internal;
+
+    impl extensions::Extension for Internal {
+        fn name(&self) -> Option<&'static str> {
+            Some("internal")
+        }
+
+        fn result(&mut self) -> Option<serde_json::Value> {
+            Some(serde_json::json!("secret"))
+        }
+    }
+
+    struct Public;
+
+    impl extensions::Extension for Public {
+        fn name(&self) -> Option<&'static str> {
+            Some("public")
+        }
+
+        fn result(&mut self) -> Option<serde_json::Value> {
+            Some(serde_json::json!(1))
+        }
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(|| Internal)
+        .extension(|| Public)
+        .on_response(|mut response| {
+            if let Some(serde_json::Value::Object(extensions)) = &mut response.extensions {
+                extensions.remove("internal");
+            }
+            response
+        })
+        .finish();
+
+    let response = schema.execute("{ value }").await;
+    assert_eq!(response.data, serde_json::json!({ "value": 10 }));
+    assert_eq!(
+        response.extensions,
+        Some(serde_json::json!({ "public": 1 }))
+    );
+}
+
+#[async_std::test]
+pub async fn test_schema_router() {
+    struct QueryA;
+
+    #[GQLObject]
+    impl QueryA {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct QueryB;
+
+    #[GQLObject]
+    impl QueryB {
+        async fn value(&self) -> String {
+            "abc".to_string()
+        }
+    }
+
+    let router = SchemaRouter::new(|request| {
+        request
+            .variables
+            .0
+            .get("tenant")
+            .and_then(|value| match value {
+                Value::String(s) => Some(s.clone()),
+                _ => None,
+            })
+    })
+    .schema("a", Schema::new(QueryA, EmptyMutation, EmptySubscription))
+    .schema("b", Schema::new(QueryB, EmptyMutation, EmptySubscription));
+
+    let request = |tenant: &str| {
+        Request::new("{ value }").variables(Variables::from_json(
+            serde_json::json!({ "tenant": tenant }),
+        ))
+    };
+
+    assert_eq!(
+        router.execute(request("a")).await.data,
+        serde_json::json!({ "value": 10 })
+    );
+    assert_eq!(
+        router.execute(request("b")).await.data,
+        serde_json::json!({ "value": "abc" })
+    );
+    assert_eq!(
+        router
+            .execute(request("c"))
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Query {
+            pos: Pos::default(),
+            path: None,
+            err: QueryError::UnknownSchema {
+                key: "c".to_string(),
+            },
+        }
+    );
+    assert_eq!(
+        router.execute("{ value }").await.into_result().unwrap_err(),
+        Error::Query {
+            pos: Pos::default(),
+            path: None,
+            err: QueryError::MissingSchemaKey,
+        }
+    );
+}
+
+#[async_std::test]
+pub async fn test_validation_mode_only_parse() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self, input: i32) -> i32 {
+            input
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .validation_mode(ValidationMode::OnlyParse)
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ value(input: 10) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "value": 10 })
+    );
+
+    // Invalid queries are not validated, but still fail during execution.
+    assert!(matches!(
+        schema
+            .execute("{ value(input: 10) unknown }")
+            .await
+            .into_result(),
+        Err(Error::Query {
+            err: QueryError::FieldNotFound { .. },
+            ..
+        })
+    ));
+    assert!(matches!(
+        schema
+            .execute(r#"{ value(input: "abc") }"#)
+            .await
+            .into_result(),
+        Err(Error::Query { .. })
+    ));
+    assert!(matches!(
+        schema.execute("{ value }").await.into_result(),
+        Err(Error::Query { .. })
+    ));
+    assert!(matches!(
+        schema
+            .execute("{ ...A } fragment A on Query { ...UnknownFragment }")
+            .await
+            .into_result(),
+        Err(Error::Query {
+            err: QueryError::UnknownFragment { .. },
+            ..
+        })
+    ));
+
+    // Fragment cycles are still rejected, because they cannot be executed.
+    assert!(matches!(
+        schema
+            .execute("{ ...A } fragment A on Query { ...B } fragment B on Query { ...A }")
+            .await
+            .into_result(),
+        Err(Error::Rule { .. })
+    ));
+}
+
+#[async_std::test]
+pub async fn test_validation_mode_only_parse_limits() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+
+        async fn nested(&self) -> Query {
+            Query
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .validation_mode(ValidationMode::OnlyParse)
+        .limit_complexity(3)
+        .limit_depth(1)
+        .finish();
+
+    assert!(schema
+        .execute("{ nested { value } }")
+        .await
+        .into_result()
+        .is_ok());
+    assert_eq!(
+        schema
+            .execute("{ a: value b: value c: value d: value }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        QueryError::TooComplex.into_error(Pos::default())
+    );
+    assert_eq!(
+        schema
+            .execute("{ nested { nested { value } } }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        QueryError::TooDeep.into_error(Pos::default())
+    );
+}
+
+#[async_std::test]
+pub async fn test_register_type() {
+    #[derive(GQLSimpleObject)]
+    struct Unreferenced {
+        value: i32,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let query = r#"{
+        __schema { types { name } }
+        __type(name: "Unreferenced") { kind fields { name } }
+    }"#;
+    let has_type = |data: &serde_json::Value| {
+        data["__schema"]["types"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|ty| ty["name"] == "Unreferenced")
+    };
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let data = schema.execute(query).await.into_result().unwrap().data;
+    assert!(!has_type(&data));
+    assert_eq!(data["__type"], serde_json::Value::Null);
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_type::<Unreferenced>()
+        .finish();
+    let data = schema.execute(query).await.into_result().unwrap().data;
+    assert!(has_type(&data));
+    assert_eq!(
+        data["__type"],
+        serde_json::json!({
+            "kind": "OBJECT",
+            "fields": [{ "name": "value" }],
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_validation_error_code() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        serde_json::to_value(schema.execute("{ valu }").await).unwrap(),
+        serde_json::json!({
+            "errors": [{
+                "message": r#"Unknown field "valu" on type "Query". Did you mean "value"?"#,
+                "locations": [{ "line": 1, "column": 3 }],
+                "extensions": { "code": "GRAPHQL_VALIDATION_FAILED" },
+            }]
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_on_scalar_serialize() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn small(&self) -> i64 {
+            10
+        }
+
+        async fn large(&self) -> i64 {
+            9_007_199_254_740_993
+        }
+
+        async fn values(&self) -> Vec<i64> {
+            vec![1, -9_007_199_254_740_993]
+        }
+
+        async fn is_large(&self, value: i64) -> bool {
+            value > 9_007_199_254_740_991
+        }
+    }
+
+    const MAX_SAFE_INTEGER: i64 = 9_007_199_254_740_991;
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .on_scalar_serialize(|type_name, value| match (type_name, value.as_i64()) {
+            ("Int", Some(n)) if n.abs() > MAX_SAFE_INTEGER => n.to_string().into(),
+            _ => value,
+        })
+        .finish();
+    assert_eq!(
+        schema
+            .execute("{ small large values isLarge(value: 9007199254740993) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "small": 10,
+            "large": "9007199254740993",
+            "values": [1, "-9007199254740993"],
+            "isLarge": true,
+        })
+    );
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ large }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "large": 9_007_199_254_740_993i64 })
+    );
+}
+
+#[async_std::test]
+pub async fn test_root_type_names() {
+    struct QueryRoot;
+
+    #[GQLObject(name = "Query")]
+    impl QueryRoot {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct MutationRoot;
+
+    #[GQLObject(name = "Mutation")]
+    impl MutationRoot {
+        async fn set_value(&self, _value: i32) -> bool {
+            true
+        }
+    }
+
+    struct SubscriptionRoot;
+
+    #[GQLSubscription(name = "Subscription")]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl futures::Stream<Item = i32> {
+            futures::stream::iter(0..10)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, MutationRoot, SubscriptionRoot);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    __typename
+                    __schema {
+                        queryType { name }
+                        mutationType { name }
+                        subscriptionType { name }
+                    }
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__typename": "Query",
+            "__schema": {
+                "queryType": { "name": "Query" },
+                "mutationType": { "name": "Mutation" },
+                "subscriptionType": { "name": "Subscription" },
+            }
+        })
+    );
+
+    let snapshot = schema.snapshot();
+    assert_eq!(snapshot.query_type, "Query");
+    assert_eq!(snapshot.mutation_type.as_deref(), Some("Mutation"));
+    assert_eq!(snapshot.subscription_type.as_deref(), Some("Subscription"));
+    assert!(snapshot.types.iter().all(|ty| !ty.name().ends_with("Root")));
+}
+
+#[async_std::test]
+pub async fn test_extend_with_sdl() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extend_with_sdl(
+            r#"
+            "A product of the inventory service."
+            type Product implements Node {
+                id: ID!
+                price(currency: Currency = USD): Float
+            }
+
+            interface Node {
+                id: ID!
+            }
+
+            enum Currency {
+                USD
+                EUR @deprecated(reason: "Use USD")
+            }
+
+            extend type Query {
+                product(id: ID!): Product
+            }
+            "#,
+        )
+        .unwrap()
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    product: __type(name: "Product") {
+                        kind
+                        description
+                        interfaces { name }
+                        fields { name type { name } args { name defaultValue } }
+                    }
+                    node: __type(name: "Node") { possibleTypes { name } }
+                    currency: __type(name: "Currency") {
+                        enumValues(includeDeprecated: true) { name deprecationReason }
+                    }
+                    query: __type(name: "Query") { fields { name } }
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "product": {
+                "kind": "OBJECT",
+                "description": "A product of the inventory service.",
+                "interfaces": [{ "name": "Node" }],
+                "fields": [
+                    { "name": "id", "type": { "name": null }, "args": [] },
+                    {
+                        "name": "price",
+                        "type": { "name": "Float" },
+                        "args": [{ "name": "currency", "defaultValue": "USD" }],
+                    },
+                ],
+            },
+            "node": { "possibleTypes": [{ "name": "Product" }] },
+            "currency": {
+                "enumValues": [
+                    { "name": "USD", "deprecationReason": null },
+                    { "name": "EUR", "deprecationReason": "Use USD" },
+                ],
+            },
+            "query": { "fields": [{ "name": "value" }, { "name": "product" }] },
+        })
+    );
+
+    // The imported fields are not resolved locally.
+    assert!(schema
+        .execute(r#"{ product(id: "1") { id } }"#)
+        .await
+        .is_err());
+
+    assert!(Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extend_with_sdl("type Product { category: Category }")
+        .is_err());
+}
+
+#[async_std::test]
+pub async fn test_extend_with_sdl_errors() {
+    #[derive(GQLSimpleObject)]
+    struct MyObj {
+        id: i32,
+    }
+
+    #[derive(GQLInterface)]
+    #[graphql(field(name = "id", type = "&i32"))]
+    enum Node {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn node(&self) -> Node {
+            MyObj { id: 1 }.into()
+        }
+    }
+
+    let extend = |sdl: &str| {
+        Schema::build(Query, EmptyMutation, EmptySubscription)
+            .extend_with_sdl(sdl)
+            .map(|builder| builder.finish())
+    };
+
+    // Existing fields are not replaced.
+    let err = extend("extend type Query { node: Int }").err().unwrap();
+    assert_eq!(err.message, "Field \"Query.node\" already exists");
+    assert!(extend("extend type Query { a: Int a: Int }").is_err());
+
+    // The implementors of an interface must have its new fields.
+    let err = extend("extend interface Node { name: String }")
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.message,
+        "Object \"MyObj\" does not implement the field \"name\" of interface \"Node\"."
+    );
+    assert!(extend("extend type MyObj implements Named interface Named { name: String }").is_err());
+
+    // Input objects reject unknown fields and can be oneOf.
+    let schema = extend(
+        r#"
+        input Filter @oneOf {
+            a: Int
+            b: Int
+        }
+
+        extend type Query {
+            search(filter: Filter!): Int
+        }
+        "#,
+    )
+    .unwrap();
+    for query in &[
+        "{ search(filter: { a: 1, c: 2 }) }",
+        "{ search(filter: { a: 1, b: 2 }) }",
+    ] {
+        assert!(matches!(
+            schema.execute(*query).await.into_result(),
+            Err(Error::Rule { .. })
+        ));
+    }
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Filter") { isOneOf } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "__type": { "isOneOf": true } })
+    );
+}
+
+#[async_std::test]
+pub async fn test_rule_error_kind() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self, input: i32) -> i32 {
+            input
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    for (query, kind) in vec![
+        (
+            "{ other }",
+            Some(RuleErrorKind::UnknownField {
+                object: "Query".to_string(),
+                field_name: "other".to_string(),
+            }),
+        ),
+        (
+            "{ value(input: 1, inpt: 1) }",
+            Some(RuleErrorKind::UnknownArgument {
+                object: "Query".to_string(),
+                field_name: "value".to_string(),
+                arg_name: "inpt".to_string(),
+            }),
+        ),
+        (
+            r#"{ value(input: "1") }"#,
+            Some(RuleErrorKind::InvalidArgumentValue {
+                arg_name: "input".to_string(),
+            }),
+        ),
+        // The other validation errors don't have a kind.
+        ("{ value }", None),
+    ] {
+        match schema.execute(query).await.into_result() {
+            Err(Error::Rule { errors }) => assert_eq!(
+                errors
+                    .into_iter()
+                    .map(|error| error.kind)
+                    .collect::<Vec<_>>(),
+                vec![kind]
+            ),
+            _ => panic!("expected a validation error for {}", query),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "tower")]
+
+use async_graphql::*;
+use tower::ServiceExt;
+
+#[async_std::test]
+pub async fn test_tower_service() {
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn value(&self, ctx: &Context<'_>) -> i32 {
+            *ctx.data_unchecked::<i32>()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let resp = schema
+        .clone()
+        .oneshot(Request::new("{ value }").data(10))
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.into_result().unwrap().data,
+        serde_json::json!({ "value": 10 })
+    );
+
+    let resp = schema.oneshot(Request::new("{ unknown }")).await.unwrap();
+    assert!(resp.is_err());
+}
@@ -0,0 +1,64 @@
+use async_graphql::*;
+use std::marker::PhantomData;
+
+#[async_std::test]
+pub async fn test_simple_object_skip() {
+    struct Internal;
+
+    #[derive(GQLSimpleObject)]
+    struct MyObj {
+        a: i32,
+
+        #[field(skip)]
+        _b: Internal,
+
+        #[graphql(skip)]
+        _c: PhantomData<Internal>,
+    }
+
+    #[derive(GQLSimpleObject)]
+    struct Query {
+        obj: MyObj,
+    }
+
+    let schema = Schema::new(
+        Query {
+            obj: MyObj {
+                a: 10,
+                _b: Internal,
+                _c: PhantomData,
+            },
+        },
+        EmptyMutation,
+        EmptySubscription,
+    );
+
+    let obj = schema
+        .snapshot()
+        .types
+        .into_iter()
+        .find(|ty| ty.name() == "MyObj")
+        .unwrap();
+    match obj {
+        TypeSnapshot::Object { fields, .. } => assert_eq!(
+            fields.iter().map(|field| &field.name).collect::<Vec<_>>(),
+            vec!["a"]
+        ),
+        _ => panic!("MyObj should be an object"),
+    }
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "MyObj") { fields { name } } obj { a } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": {
+                "fields": [{ "name": "a" }],
+            },
+            "obj": { "a": 10 },
+        })
+    );
+}
@@ -0,0 +1,58 @@
+use async_graphql::*;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+#[async_std::test]
+pub async fn test_shared_strings() {
+    struct Query {
+        name: Arc<str>,
+        title: Arc<String>,
+    }
+
+    #[GQLObject]
+    impl Query {
+        async fn name(&self) -> Arc<str> {
+            self.name.clone()
+        }
+
+        async fn title(&self) -> Arc<String> {
+            self.title.clone()
+        }
+
+        async fn borrowed(&self) -> Cow<'_, str> {
+            Cow::Borrowed(&self.name)
+        }
+
+        async fn owned(&self) -> Cow<'_, str> {
+            Cow::Owned(format!("{}!", self.name))
+        }
+
+        async fn names(&self) -> Vec<Arc<str>> {
+            vec![self.name.clone(), "def".into()]
+        }
+    }
+
+    let schema = Schema::new(
+        Query {
+            name: "abc".into(),
+            title: Arc::new("Title".to_string()),
+        },
+        EmptyMutation,
+        EmptySubscription,
+    );
+    assert_eq!(
+        schema
+            .execute("{ name title borrowed owned names }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "name": "abc",
+            "title": "Title",
+            "borrowed": "abc",
+            "owned": "abc!",
+            "names": ["abc", "def"],
+        })
+    );
+}
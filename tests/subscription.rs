@@ -1,5 +1,8 @@
+use async_graphql::extensions::Extension;
 use async_graphql::*;
 use futures::{Stream, StreamExt, TryStreamExt};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 #[async_std::test]
 pub async fn test_subscription() {
@@ -425,3 +428,149 @@ pub async fn test_subscription_fieldresult() {
 
     assert!(stream.next().await.is_none());
 }
+
+#[async_std::test]
+pub async fn test_subscription_async_filter() {
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {}
+
+    async fn is_even(ctx: &Context<'_>, value: &i32) -> FieldResult<bool> {
+        async_std::task::yield_now().await;
+        if *value == *ctx.data_unchecked::<i32>() {
+            return Err("Invalid value".into());
+        }
+        Ok(*value % 2 == 0)
+    }
+
+    struct SubscriptionRoot;
+
+    #[GQLSubscription]
+    impl SubscriptionRoot {
+        #[field(filter = "is_even")]
+        async fn values(&self, start: i32, end: i32) -> impl Stream<Item = i32> {
+            futures::stream::iter(start..end)
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot)
+        .data(100i32)
+        .finish();
+
+    {
+        let mut stream = schema
+            .execute_stream("subscription { values(start: 10, end: 20) }")
+            .map(|resp| resp.into_result().unwrap().data)
+            .boxed();
+        for i in (10..20).filter(|n| n % 2 == 0) {
+            assert_eq!(
+                Some(serde_json::json!({ "values": i })),
+                stream.next().await
+            );
+        }
+        assert!(stream.next().await.is_none());
+    }
+
+    {
+        let mut stream = schema
+            .execute_stream("subscription { values(start: 98, end: 110) }")
+            .map(|resp| resp.into_result())
+            .boxed();
+        assert_eq!(
+            Some(serde_json::json!({ "values": 98 })),
+            stream.next().await.map(|resp| resp.unwrap().data)
+        );
+        assert_eq!(
+            stream.next().await.unwrap().unwrap_err(),
+            Error::Query {
+                pos: Pos {
+                    line: 1,
+                    column: 16
+                },
+                path: Some(serde_json::json!(["values"])),
+                err: QueryError::FieldError {
+                    err: "Invalid value".to_string(),
+                    extended_error: None,
+                },
+            }
+        );
+        assert!(stream.next().await.is_none());
+    }
+}
+
+#[async_std::test]
+pub async fn test_subscription_extension_hooks() {
+    #[derive(Default)]
+    struct Counters {
+        starts: AtomicUsize,
+        nexts: AtomicUsize,
+        ends: AtomicUsize,
+    }
+
+    struct CountSubscriptions(Arc<Counters>);
+
+    impl Extension for CountSubscriptions {
+        fn subscription_start(&mut self) {
+            self.0.starts.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn subscription_next(&mut self, _response: &Response) {
+            self.0.nexts.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn subscription_end(&mut self) {
+            self.0.ends.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[GQLSubscription]
+    impl SubscriptionRoot {
+        async fn values(&self, start: i32, end: i32) -> impl Stream<Item = i32> {
+            futures::stream::iter(start..end)
+        }
+    }
+
+    let counters = Arc::new(Counters::default());
+    let schema = Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot)
+        .extension({
+            let counters = counters.clone();
+            move || CountSubscriptions(counters.clone())
+        })
+        .finish();
+    let count = |counter: &AtomicUsize| counter.load(Ordering::SeqCst);
+
+    let mut stream = schema
+        .execute_stream("subscription { values(start: 0, end: 3) }")
+        .boxed();
+    assert_eq!(count(&counters.starts), 0);
+    for i in 0..3 {
+        assert_eq!(
+            stream.next().await.unwrap().into_result().unwrap().data,
+            serde_json::json!({ "values": i })
+        );
+    }
+    assert!(stream.next().await.is_none());
+    assert_eq!(count(&counters.starts), 1);
+    assert_eq!(count(&counters.nexts), 3);
+    assert_eq!(count(&counters.ends), 1);
+
+    // The subscription also ends when the client drops it.
+    let mut stream = schema
+        .execute_stream("subscription { values(start: 0, end: 3) }")
+        .boxed();
+    assert!(stream.next().await.is_some());
+    assert_eq!(count(&counters.starts), 2);
+    assert_eq!(count(&counters.ends), 1);
+    drop(stream);
+    assert_eq!(count(&counters.starts), 2);
+    assert_eq!(count(&counters.nexts), 4);
+    assert_eq!(count(&counters.ends), 2);
+}
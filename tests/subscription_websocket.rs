@@ -93,7 +93,7 @@ pub async fn test_subscription_ws_transport_with_token() {
     }
 
     let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
-    let mut stream = http::WebSocketStream::new_with_initializer(&schema, |value| {
+    let mut stream = http::WebSocketStream::new_with_initializer(&schema, |value| async move {
         #[derive(serde::Deserialize)]
         struct Payload {
             token: String,
@@ -157,6 +157,61 @@ pub async fn test_subscription_ws_transport_with_token() {
     );
 }
 
+#[async_std::test]
+pub async fn test_subscription_ws_transport_initializer_error() {
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[GQLSubscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..10)
+        }
+    }
+
+    async fn check_token(token: &str) -> bool {
+        async_std::task::yield_now().await;
+        token == "123456"
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let mut stream = http::WebSocketStream::new_with_initializer(&schema, |value| async move {
+        let token = value["token"].as_str().unwrap_or_default();
+        if !check_token(token).await {
+            return Err(FieldError::from("invalid token")
+                .extend_with(|_| serde_json::json!({ "code": "FORBIDDEN" })));
+        }
+        Ok(Data::default())
+    });
+
+    stream
+        .send(
+            serde_json::to_string(&serde_json::json!({
+                "type": "connection_init",
+                "payload": { "token": "abc" }
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+            "type": "connection_error",
+            "payload": {
+                "message": "invalid token",
+                "extensions": { "code": "FORBIDDEN" },
+            },
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+    assert!(stream.next().await.is_none());
+}
+
 #[async_std::test]
 pub async fn test_subscription_ws_transport_error() {
     struct QueryRoot;
@@ -247,6 +302,150 @@ pub async fn test_subscription_ws_transport_error() {
     );
 }
 
+#[async_std::test]
+pub async fn test_subscription_ws_transport_item_errors() {
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[GQLSubscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = FieldResult<i32>> {
+            futures::stream::iter(0..4).map(|n| {
+                if n % 2 == 0 {
+                    Ok(n)
+                } else {
+                    Err(format!("Odd value {}", n).into())
+                }
+            })
+        }
+
+        async fn optional_values(&self) -> impl Stream<Item = FieldResult<Option<i32>>> {
+            futures::stream::iter(0..4).map(|n| {
+                if n % 2 == 0 {
+                    Ok(Some(n))
+                } else {
+                    Err(format!("Odd value {}", n).into())
+                }
+            })
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let mut stream = http::WebSocketStream::new(&schema);
+
+    stream
+        .send(
+            serde_json::to_string(&serde_json::json!({
+                "type": "connection_init",
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "connection_ack",
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+
+    stream
+        .send(
+            serde_json::to_string(&serde_json::json!({
+                "type": "start",
+                "id": "1",
+                "payload": {
+                    "query": "subscription { values }"
+                },
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    // The error of a non-null field makes the data null.
+    for i in 0..4 {
+        let payload = if i % 2 == 0 {
+            serde_json::json!({ "data": { "values": i } })
+        } else {
+            serde_json::json!({
+                "data": null,
+                "errors": [{
+                    "message": format!("Odd value {}", i),
+                    "locations": [{"line": 1, "column": 16}],
+                    "path": ["values"],
+                }],
+            })
+        };
+        assert_eq!(
+            Some(serde_json::json!({
+            "type": "data",
+            "id": "1",
+            "payload": payload,
+            })),
+            serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+        );
+    }
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "complete",
+        "id": "1",
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+
+    stream
+        .send(
+            serde_json::to_string(&serde_json::json!({
+                "type": "start",
+                "id": "2",
+                "payload": {
+                    "query": "subscription { optionalValues }"
+                },
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    for i in 0..4 {
+        let payload = if i % 2 == 0 {
+            serde_json::json!({ "data": { "optionalValues": i } })
+        } else {
+            serde_json::json!({
+                "data": { "optionalValues": null },
+                "errors": [{
+                    "message": format!("Odd value {}", i),
+                    "locations": [{"line": 1, "column": 16}],
+                    "path": ["optionalValues"],
+                }],
+            })
+        };
+        assert_eq!(
+            Some(serde_json::json!({
+            "type": "data",
+            "id": "2",
+            "payload": payload,
+            })),
+            serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+        );
+    }
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "complete",
+        "id": "2",
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+}
+
 #[async_std::test]
 pub async fn test_query_over_websocket() {
     struct QueryRoot;
@@ -309,3 +508,258 @@ pub async fn test_query_over_websocket() {
         serde_json::from_str(&stream.next().await.unwrap()).unwrap()
     );
 }
+
+#[async_std::test]
+pub async fn test_subscription_graphql_transport_ws() {
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[GQLSubscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..3)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let protocol: http::Protocols = "graphql-transport-ws".parse().unwrap();
+    assert_eq!(protocol, http::Protocols::GraphQLTransportWS);
+    assert_eq!(protocol.sec_websocket_protocol(), "graphql-transport-ws");
+    let mut stream = http::WebSocketStream::new_with_protocol(&schema, protocol, |_| async {
+        Ok(Default::default())
+    });
+
+    stream
+        .send(
+            serde_json::to_string(&serde_json::json!({
+                "type": "connection_init",
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "connection_ack",
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+
+    stream
+        .send(
+            serde_json::to_string(&serde_json::json!({
+                "type": "ping",
+                "payload": { "time": 1 },
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "pong",
+        "payload": { "time": 1 },
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+
+    stream
+        .send(
+            serde_json::to_string(&serde_json::json!({
+                "type": "subscribe",
+                "id": "1",
+                "payload": {
+                    "query": "subscription { values }"
+                },
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    for i in 0..3 {
+        assert_eq!(
+            Some(serde_json::json!({
+            "type": "next",
+            "id": "1",
+            "payload": { "data": { "values": i } },
+            })),
+            serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+        );
+    }
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "complete",
+        "id": "1",
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+
+    stream
+        .send(
+            serde_json::to_string(&serde_json::json!({
+                "type": "subscribe",
+                "id": "2",
+                "payload": {
+                    "query": "subscription { valuesAbc }"
+                },
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let msg: serde_json::Value = serde_json::from_str(&stream.next().await.unwrap()).unwrap();
+    assert_eq!(msg["type"], "error");
+    assert_eq!(msg["id"], "2");
+    assert!(msg["payload"].is_array());
+
+    // Messages of the legacy protocol are rejected.
+    stream
+        .send(
+            serde_json::to_string(&serde_json::json!({
+                "type": "start",
+                "id": "3",
+                "payload": {
+                    "query": "subscription { values }"
+                },
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert!(stream.next().await.is_none());
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_max_subscriptions() {
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[GQLSubscription]
+    impl SubscriptionRoot {
+        async fn forever(&self) -> impl Stream<Item = i32> {
+            futures::stream::pending()
+        }
+
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..2)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let mut stream = http::WebSocketStream::new_with_max_subscriptions(
+        &schema,
+        http::Protocols::SubscriptionsTransportWS,
+        1,
+        |_| async { Ok(Default::default()) },
+    );
+
+    stream
+        .send(
+            serde_json::to_string(&serde_json::json!({
+                "type": "connection_init",
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "connection_ack",
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+
+    for id in &["1", "2"] {
+        stream
+            .send(
+                serde_json::to_string(&serde_json::json!({
+                    "type": "start",
+                    "id": id,
+                    "payload": {
+                        "query": "subscription { forever }"
+                    },
+                }))
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+    }
+
+    // The second subscription is over the limit, but the connection stays open.
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "error",
+        "id": "2",
+        "payload": [{
+            "message": "Too many subscriptions, the limit is 1",
+        }],
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+
+    stream
+        .send(
+            serde_json::to_string(&serde_json::json!({
+                "type": "stop",
+                "id": "1",
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "complete",
+        "id": "1",
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+
+    stream
+        .send(
+            serde_json::to_string(&serde_json::json!({
+                "type": "start",
+                "id": "3",
+                "payload": {
+                    "query": "subscription { values }"
+                },
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    for i in 0..2 {
+        assert_eq!(
+            Some(serde_json::json!({
+            "type": "data",
+            "id": "3",
+            "payload": { "data": { "values": i } },
+            })),
+            serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+        );
+    }
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "complete",
+        "id": "3",
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "tracing")]
+
+use async_graphql::extensions::Tracing;
+use async_graphql::*;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Records the fields of the query events.
+#[derive(Clone, Default)]
+struct QueryEvents(Arc<Mutex<Vec<String>>>);
+
+struct FieldsVisitor<'a>(&'a mut Vec<String>);
+
+impl Visit for FieldsVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push(format!("{}: {:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for QueryEvents {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        if event.metadata().target() == "async_graphql::query" {
+            let mut fields = Vec::new();
+            event.record(&mut FieldsVisitor(&mut fields));
+            self.0.lock().unwrap().push(fields.join(", "));
+        }
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[async_std::test]
+pub async fn test_tracing_secret_argument() {
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {
+        async fn login(&self, #[arg(secret)] _password: String) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .extension(Tracing::default)
+        .finish();
+    let events = QueryEvents::default();
+    let _guard = tracing::subscriber::set_default(events.clone());
+
+    assert!(schema
+        .execute(r#"{ login(password: "abcdef") }"#)
+        .await
+        .is_ok());
+    assert!(schema
+        .execute(
+            Request::new(r#"query($password: String!) { login(password: $password) }"#).variables(
+                Variables::from_json(serde_json::json!({ "password": "abcdef" })),
+            ),
+        )
+        .await
+        .is_ok());
+    assert_eq!(
+        *events.0.lock().unwrap(),
+        vec![
+            r#"variables: {}, query: { login(password: ***) }"#.to_string(),
+            r#"variables: {password: "***"}, query: query($password: String!) { login(password: $password) }"#.to_string(),
+        ]
+    );
+
+    // The query is not logged if it can't be parsed, since its secret values are unknown.
+    events.0.lock().unwrap().clear();
+    assert!(schema
+        .execute(r#"{ login(password: "abcdef" }"#)
+        .await
+        .is_err());
+    assert!(events.0.lock().unwrap().is_empty());
+}
@@ -284,3 +284,128 @@ pub async fn test_union_field_result() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_union_item_typename() {
+    struct Spanned<T>(T);
+
+    impl<T: Type> Type for Spanned<T> {
+        fn type_name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Spanned")
+        }
+
+        fn create_type_info(registry: &mut registry::Registry) -> String {
+            T::create_type_info(registry)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<T: resolver_utils::ObjectType + Send + Sync> OutputValueType for Spanned<T> {
+        async fn resolve(
+            &self,
+            ctx: &ContextSelectionSet<'_>,
+            field: &Positioned<parser::types::Field>,
+        ) -> Result<serde_json::Value> {
+            self.0.resolve(ctx, field).await
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<T: resolver_utils::ObjectType + Send + Sync> resolver_utils::ObjectType for Spanned<T> {
+        async fn resolve_field(&self, ctx: &Context<'_>) -> Result<serde_json::Value> {
+            self.0.resolve_field(ctx).await
+        }
+
+        fn collect_all_fields<'a>(
+            &'a self,
+            ctx: &ContextSelectionSet<'a>,
+            fields: &mut resolver_utils::Fields<'a>,
+        ) -> Result<()> {
+            self.0.collect_all_fields(ctx, fields)
+        }
+    }
+
+    #[derive(GQLSimpleObject)]
+    struct MyObj {
+        id: i32,
+    }
+
+    #[derive(GQLSimpleObject)]
+    struct MyObj2 {
+        value: i32,
+    }
+
+    #[derive(GQLUnion)]
+    enum Node {
+        #[item(typename = "MyObj")]
+        MyObj(Spanned<MyObj>),
+        MyObj2(MyObj2),
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn nodes(&self) -> Vec<Node> {
+            vec![
+                Node::MyObj(Spanned(MyObj { id: 33 })),
+                Node::MyObj2(MyObj2 { value: 44 }),
+            ]
+        }
+    }
+
+    let query = r#"{
+            nodes {
+                __typename
+                ... on MyObj {
+                    id
+                }
+                ... on MyObj2 {
+                    value
+                }
+            }
+        }"#;
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "nodes": [
+                { "__typename": "MyObj", "id": 33 },
+                { "__typename": "MyObj2", "value": 44 },
+            ]
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_union_from() {
+    #[derive(GQLSimpleObject)]
+    struct MyObjOne {
+        value: i32,
+    }
+
+    #[derive(GQLSimpleObject)]
+    struct MyObjTwo {
+        name: String,
+    }
+
+    #[derive(GQLUnion)]
+    enum MyUnion {
+        One(MyObjOne),
+        Two(MyObjTwo),
+    }
+
+    match MyUnion::from(MyObjOne { value: 10 }) {
+        MyUnion::One(obj) => assert_eq!(obj.value, 10),
+        MyUnion::Two(_) => panic!("converted into the wrong variant"),
+    }
+
+    let value: MyUnion = MyObjTwo {
+        name: "abc".to_string(),
+    }
+    .into();
+    match value {
+        MyUnion::Two(obj) => assert_eq!(obj.name, "abc"),
+        MyUnion::One(_) => panic!("converted into the wrong variant"),
+    }
+}
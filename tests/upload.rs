@@ -0,0 +1,66 @@
+use async_graphql::futures::io::Cursor;
+use async_graphql::http::{receive_multipart, MultipartOptions};
+use async_graphql::*;
+
+async fn receive_parts(parts: &[(&str, &str)]) -> Result<Request, ParseRequestError> {
+    let mut body = String::new();
+    for (name, content) in parts {
+        body.push_str("--boundary\r\n");
+        if name.starts_with(char::is_numeric) {
+            body.push_str(&format!(
+                "Content-Disposition: form-data; name=\"{}\"; filename=\"a.txt\"\r\n\r\n",
+                name
+            ));
+        } else {
+            body.push_str(&format!(
+                "Content-Disposition: form-data; name=\"{}\"\r\n\r\n",
+                name
+            ));
+        }
+        body.push_str(content);
+        body.push_str("\r\n");
+    }
+    body.push_str("--boundary--\r\n");
+    receive_multipart(Cursor::new(body), "boundary", MultipartOptions::default()).await
+}
+
+#[async_std::test]
+pub async fn test_upload_invalid_multipart() {
+    let operations = r#"{ "query": "mutation ($files: [Upload!]!) { upload(files: $files) }", "variables": { "files": [null] } }"#;
+
+    assert!(matches!(
+        receive_parts(&[("map", r#"{ "0": ["variables.files.0"] }"#), ("0", "abc")]).await,
+        Err(ParseRequestError::MissingOperationsPart)
+    ));
+
+    assert!(matches!(
+        receive_parts(&[("operations", operations), ("0", "abc")]).await,
+        Err(ParseRequestError::MissingMapPart)
+    ));
+
+    assert!(matches!(
+        receive_parts(&[("operations", operations), ("map", "{ 0: }"), ("0", "abc")]).await,
+        Err(ParseRequestError::InvalidFilesMap(_))
+    ));
+
+    match receive_parts(&[
+        ("operations", operations),
+        ("map", r#"{ "0": ["variables.files.1"] }"#),
+        ("0", "abc"),
+    ])
+    .await
+    {
+        Err(ParseRequestError::InvalidFilesMapPath(path)) => {
+            assert_eq!(path, "variables.files.1")
+        }
+        _ => panic!("expected an invalid files map path"),
+    }
+
+    assert!(receive_parts(&[
+        ("operations", operations),
+        ("map", r#"{ "0": ["variables.files.0"] }"#),
+        ("0", "abc"),
+    ])
+    .await
+    .is_ok());
+}
@@ -0,0 +1,73 @@
+use async_graphql::parser::types::{Field, Selection};
+use async_graphql::*;
+
+/// A made-up policy: every selection of a `User` must request its `id`.
+struct UserMustSelectId;
+
+impl<'a> Visitor<'a> for UserMustSelectId {
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        if ctx.current_type().map(|ty| ty.name()) != Some("User") {
+            return;
+        }
+        let selects_id = field.node.selection_set.node.items.iter().any(|selection| {
+            matches!(&selection.node, Selection::Field(selected) if selected.node.name.node == "id")
+        });
+        if !selects_id {
+            ctx.report_error(
+                vec![field.pos],
+                format!("Field \"{}\" must select \"id\"", field.node.name.node),
+            );
+        }
+    }
+}
+
+#[async_std::test]
+pub async fn test_custom_validation_rule() {
+    #[derive(GQLSimpleObject)]
+    struct User {
+        id: i32,
+        name: String,
+    }
+
+    struct Query;
+
+    #[GQLObject]
+    impl Query {
+        async fn user(&self) -> User {
+            User {
+                id: 1,
+                name: "abc".to_string(),
+            }
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .add_validation_rule(|| UserMustSelectId)
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ user { id name } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "user": { "id": 1, "name": "abc" } })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ user { name } }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        Error::Rule {
+            errors: vec![RuleError {
+                locations: vec![Pos { line: 1, column: 3 }],
+                message: "Field \"user\" must select \"id\"".to_string(),
+                extensions: Some(serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" })),
+                kind: None,
+            }]
+        }
+    );
+}
@@ -1,3 +1,4 @@
+use async_graphql::extensions::Extension;
 use async_graphql::*;
 
 #[async_std::test]
@@ -223,3 +224,136 @@ pub async fn test_variable_in_input_object() {
         );
     }
 }
+
+#[async_std::test]
+pub async fn test_variable_enum_from_string() {
+    #[derive(GQLEnum, Copy, Clone, Eq, PartialEq)]
+    enum MyEnum {
+        A,
+        B,
+    }
+
+    #[derive(GQLInputObject)]
+    struct MyInput {
+        value: MyEnum,
+    }
+
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {
+        pub async fn enum_val(&self, value: MyEnum) -> MyEnum {
+            value
+        }
+
+        pub async fn enum_list_val(&self, value: Vec<MyEnum>) -> Vec<MyEnum> {
+            value
+        }
+
+        pub async fn input_val(&self, input: MyInput) -> MyEnum {
+            input.value
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let query = Request::new(
+        r#"
+            query QueryWithVariables($enumVal: MyEnum!, $enumListVal: [MyEnum!]!, $input: MyInput!) {
+                enumVal(value: $enumVal)
+                enumListVal(value: $enumListVal)
+                inputVal(input: $input)
+            }
+        "#,
+    )
+    .variables(Variables::from_json(serde_json::json!({
+        "enumVal": "A",
+        "enumListVal": ["B", "A"],
+        "input": { "value": "B" },
+    })));
+
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({
+            "enumVal": "A",
+            "enumListVal": ["B", "A"],
+            "inputVal": "B",
+        })
+    );
+
+    let query = Request::new(
+        r#"
+            query QueryWithVariables($enumVal: MyEnum!) {
+                enumVal(value: $enumVal)
+            }
+        "#,
+    )
+    .variables(Variables::from_json(serde_json::json!({ "enumVal": "C" })));
+    assert!(schema.execute(query).await.is_err());
+}
+
+#[async_std::test]
+pub async fn test_variables_accessors() {
+    let mut variables = Variables::from_json(serde_json::json!({ "a": 10 }));
+    assert_eq!(variables.get("a"), Some(&Value::Number(10.into())));
+    assert_eq!(variables.get("b"), None);
+
+    assert_eq!(variables.set("b", Value::String("abc".to_string())), None);
+    assert_eq!(variables.get("b"), Some(&Value::String("abc".to_string())));
+    assert_eq!(
+        variables.set("b", Value::Boolean(true)),
+        Some(Value::String("abc".to_string()))
+    );
+
+    assert_eq!(variables.remove("a"), Some(Value::Number(10.into())));
+    assert_eq!(variables.remove("a"), None);
+    assert_eq!(
+        variables.into_value(),
+        Value::from_json(serde_json::json!({ "b": true })).unwrap()
+    );
+}
+
+#[async_std::test]
+pub async fn test_variables_from_extension() {
+    struct DefaultLanguage;
+
+    impl Extension for DefaultLanguage {
+        fn prepare_variables(&mut self, variables: &mut Variables) {
+            if variables.get("language").is_none() {
+                variables.set("language", Value::String("en".to_string()));
+            }
+        }
+    }
+
+    struct QueryRoot;
+
+    #[GQLObject]
+    impl QueryRoot {
+        pub async fn greeting(&self, language: String) -> String {
+            match language.as_str() {
+                "fr" => "Bonjour".to_string(),
+                _ => "Hello".to_string(),
+            }
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .extension(|| DefaultLanguage)
+        .finish();
+    let query = r#"query($language: String!) { greeting(language: $language) }"#;
+
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        serde_json::json!({ "greeting": "Hello" })
+    );
+    assert_eq!(
+        schema
+            .execute(Request::new(query).variables(Variables::from_json(
+                serde_json::json!({ "language": "fr" })
+            )))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        serde_json::json!({ "greeting": "Bonjour" })
+    );
+}